@@ -1,9 +1,8 @@
-use std::fs::read_to_string;
-use std::path::Path;
 use std::io::Error;
+use locator::Input;
 
 fn main() {
-    let input = parse_input("input.txt").unwrap();
+    let input = parse_input(locator::input!()).unwrap();
 
     // Part 1
     let mut sum = 0;
@@ -24,8 +23,8 @@ fn main() {
     println!("Part 2: Sum = {}", sum);
 }
 
-fn parse_input<P: AsRef<Path>>(path: P) -> Result<Vec<u32>, Error> {
-    let input = read_to_string(path)?;
+fn parse_input(input: impl Into<Input>) -> Result<Vec<u32>, Error> {
+    let input = input.into().read_to_string()?;
 
     Ok(input.chars().filter_map(|c| c.to_digit(10)).collect())
 }
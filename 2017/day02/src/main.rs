@@ -1,19 +1,18 @@
-use std::fs::read_to_string;
 use std::io::Error;
-use std::path::Path;
+use locator::Input;
 
 fn main() {
-    let input = parse_input("input.txt").unwrap();
+    let input = parse_input(locator::input!()).unwrap();
 
     // Part 1
-    println!("Checksum: {}", checksum(&input));
+    println!("Part 1: Checksum: {}", checksum(&input));
 
     // Part 2
-    println!("Sum of evenly divisible values: {}", sum_even_divisible(&input));
+    println!("Part 2: Sum of evenly divisible values: {}", sum_even_divisible(&input));
 }
 
-fn parse_input<P: AsRef<Path>>(path: P) -> Result<Vec<Vec<i32>>, Error> {
-    let input = read_to_string(path)?;
+fn parse_input(input: impl Into<Input>) -> Result<Vec<Vec<i32>>, Error> {
+    let input = input.into().read_to_string()?;
 
     let mut rows = Vec::new();
     for line in input.lines() {
@@ -1,17 +1,16 @@
 use std::collections::HashSet;
-use std::fs::read_to_string;
-use std::path::Path;
 use std::io::Result;
+use locator::Input;
 
 fn main() {
-    let input = read_input("input.txt").expect("Failed to read input");
+    let input = read_input(locator::input!()).expect("Failed to read input");
 
-    println!("[Part 1] Number of valid passphrases: {}", input.iter().filter(|p| valid(&p)).count());
-    println!("[Part 2] Number of valid passphrases: {}", input.iter().filter(|p| no_anagrams(&p)).count());
+    println!("Part 1: Number of valid passphrases: {}", input.iter().filter(|p| valid(&p)).count());
+    println!("Part 2: Number of valid passphrases: {}", input.iter().filter(|p| no_anagrams(&p)).count());
 }
 
-fn read_input<P: AsRef<Path>>(path: P) -> Result<Vec<String>> {
-    Ok(read_to_string(path)?.lines().map(|l| l.to_string()).collect())
+fn read_input(input: impl Into<Input>) -> Result<Vec<String>> {
+    Ok(input.into().read_to_string()?.lines().map(|l| l.to_string()).collect())
 }
 
 fn valid(passphrase: &str) -> bool {
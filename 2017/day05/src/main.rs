@@ -1,15 +1,14 @@
-use std::fs::read_to_string;
-use std::path::Path;
 use std::io;
+use locator::Input;
 
 fn main() {
-    let input = read_input("input.txt").expect("Failed to read input");
-    println!("[Part 1] Exits in {} steps", run1(&input));
-    println!("[Part 2] Exits in {} steps", run2(&input));
+    let input = read_input(locator::input!()).expect("Failed to read input");
+    println!("Part 1: Exits in {} steps", run1(&input));
+    println!("Part 2: Exits in {} steps", run2(&input));
 }
 
-fn read_input<P: AsRef<Path>>(path: P) -> io::Result<Vec<isize>> {
-    Ok(read_to_string(path)?.lines().filter_map(|v| v.parse().ok()).collect())
+fn read_input(input: impl Into<Input>) -> io::Result<Vec<isize>> {
+    Ok(input.into().read_to_string()?.lines().filter_map(|v| v.parse().ok()).collect())
 }
 
 fn run1(instructions: &Vec<isize>) -> u32 {
@@ -1,10 +1,9 @@
 use std::collections::HashSet;
-use std::fs::read_to_string;
-use std::path::Path;
 use std::io;
+use locator::Input;
 
 fn main() {
-    let input = read_input("input.txt").expect("Failed to read input");
+    let input = read_input(locator::input!()).expect("Failed to read input");
 
     // Part 1
     let mut banks = input.clone();
@@ -13,7 +12,7 @@ fn main() {
     for n in 1.. {
         redistribute(&mut banks);
         if seen.contains(&banks) {
-            println!("[Part 1] Redistribution cycles {}", n);
+            println!("Part 1: Redistribution cycles {}", n);
             break;
         }
         seen.insert(banks.clone());
@@ -24,14 +23,14 @@ fn main() {
     for n in 1.. {
         redistribute(&mut banks);
         if banks == seen_state {
-            println!("[Part 2] Redistribution cycles {}", n);
+            println!("Part 2: Redistribution cycles {}", n);
             break;
         }
     }
 }
 
-fn read_input<P: AsRef<Path>>(path: P) -> io::Result<Vec<i32>> {
-    Ok(read_to_string(path)?.split_whitespace().filter_map(|v| v.parse().ok()).collect())
+fn read_input(input: impl Into<Input>) -> io::Result<Vec<i32>> {
+    Ok(input.into().read_to_string()?.split_whitespace().filter_map(|v| v.parse().ok()).collect())
 }
 
 fn redistribute(banks: &mut Vec<i32>) {
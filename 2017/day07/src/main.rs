@@ -3,27 +3,34 @@ extern crate regex;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::fmt;
-use std::fs::read_to_string;
-use std::path::Path;
-use std::io;
 use regex::Regex;
+use parsing::{ParseError, Span};
 
 fn main() {
-    let tower = read_input("input.txt").expect("Failed to read input");
+    let tower = parsing::read_input(locator::input!(), parse_input);
 
     // Part 1
     let &bottom = tower.topological_sort().last().unwrap();
-    println!("The bottom program's name is {}", tower.node(bottom).unwrap());
+    println!("Part 1: The bottom program's name is {}", tower.node(bottom).unwrap());
 
     // Part 2
     for node_idx in tower.topological_sort() {
         let adjacent = tower.adjacent(node_idx).unwrap();
         if !adjacent.iter().all(|&n| total_weight(&tower, n) == total_weight(&tower, *adjacent.iter().next().unwrap())) {
             println!("The unbalanced disk is held by {}", tower.node(node_idx).unwrap());
-            for adj_idx in adjacent {
+            for &adj_idx in &adjacent {
                 let total_weight = total_weight(&tower, adj_idx);
                 println!("- [{}] {}", total_weight, tower.node(adj_idx).unwrap());
             }
+
+            // The odd one out weighs differently to all the others
+            let weights: Vec<_> = adjacent.iter().map(|&n| (n, total_weight(&tower, n))).collect();
+            let count = |weight| weights.iter().filter(|&&(_, w)| w == weight).count();
+            if let (Some(&(odd, odd_weight)), Some(&(_, weight))) =
+                (weights.iter().find(|&&(_, w)| count(w) == 1), weights.iter().find(|&&(_, w)| count(w) > 1)) {
+                let program = tower.node(odd).unwrap();
+                println!("Part 2: {} should weigh {}", program.name, program.weight + weight - odd_weight);
+            }
             break;
         }
     }
@@ -35,32 +42,36 @@ fn total_weight(tower: &Graph<Program>, node: NodeIndex) -> i32 {
     adjacent.iter().fold(prog.weight, |w, &i| w + total_weight(tower, i))
 }
 
-fn read_input<P: AsRef<Path>>(path: P) -> io::Result<Graph<Program>> {
+fn parse_input(input: &str) -> Result<Graph<Program>, ParseError> {
     let re = Regex::new(r"^(\w+) \((\d+)\)(?: -> (.*))?$").unwrap();
 
     let mut node_weights = HashMap::new();
     let mut edges = Vec::new();
-    for line in read_to_string(path)?.lines() {
-        if let Some(cap) = re.captures(line) {
-            let name = &cap[1];
-            let weight: i32 = cap[2].parse().unwrap();
-            node_weights.insert(name.to_string(), weight);
-            if let Some(m) = cap.get(3) {
-                for child in m.as_str().split(", ") {
-                    edges.push((name.to_string(), child.to_string()));
-                }
-            };
-        }
+    for line in Span::new(input).lines() {
+        let cap = re.captures(line.as_str()).ok_or_else(|| line.error("Expected `name (weight) -> children`"))?;
+        let name = &cap[1];
+        let weight: i32 = line.slice(cap.get(2).unwrap().range()).parse("weight")?;
+        node_weights.insert(name.to_string(), weight);
+        if let Some(m) = cap.get(3) {
+            for child in line.slice(m.range()).split(",") {
+                edges.push((name.to_string(), child));
+            }
+        };
+    }
+
+    if node_weights.is_empty() {
+        return Err(Span::new(input).end().error("No programs"));
     }
 
     let mut graph = Graph::new();
     let mut indexes = HashMap::new();
     for (name, &weight) in &node_weights {
-        indexes.insert(name, graph.add_node(Program::new(name, weight)));
+        indexes.insert(name.as_str(), graph.add_node(Program::new(name, weight)));
     }
 
     for (from, to) in &edges {
-        graph.add_edge(indexes[from], indexes[to]);
+        let &to_index = indexes.get(to.as_str()).ok_or_else(|| to.error("Unknown program"))?;
+        graph.add_edge(indexes[from.as_str()], to_index);
     }
 
     Ok(graph)
@@ -1,39 +1,57 @@
 use std::collections::HashMap;
-use std::fs::read_to_string;
-use std::io;
+use parsing::{ParseError, Span};
 
 fn main() {
-    let input = run("input.txt").expect("Failed to read input");
+    let instructions = parsing::read_input(locator::input!(), parse_input);
+    run(&instructions);
 }
 
-fn run(path: &str) -> io::Result<()> {
-    let mut running_max = 0;
-    let mut regs = HashMap::new();
-    for line in read_to_string(path)?.lines() {
-        let mut tokens = line.split_whitespace();
-        let reg = tokens.next().unwrap().to_string();
-        let op = match tokens.next().unwrap() {
+fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    Span::new(input).lines().map(|line| {
+        let mut fields = line.fields();
+        let reg = fields.next("register")?.as_str().to_string();
+        let op = fields.next("op")?;
+        let op = match op.as_str() {
             "inc" => inc,
             "dec" => dec,
-            name => panic!("Unknown op: {}", name),
+            _ => return Err(op.error("Unknown op")),
         };
-        let val: i32 = tokens.next().unwrap().parse().unwrap();
-        assert_eq!(tokens.next().unwrap(), "if");
-        let cond_reg = tokens.next().unwrap().to_string();
-        let cond = match tokens.next().unwrap() {
+        let val: i32 = fields.parse("amount")?;
+        fields.expect("if")?;
+        let cond_reg = fields.next("condition register")?.as_str().to_string();
+        let cond = fields.next("comparison")?;
+        let cond = match cond.as_str() {
             ">" => i32::gt,
             "<" => i32::lt,
             ">=" => i32::ge,
             "<=" => i32::le,
             "==" => i32::eq,
             "!=" => i32::ne,
-            name => panic!("Unknown cmp: {}", name),
+            _ => return Err(cond.error("Unknown cmp")),
         };
-        let cond_val: i32 = tokens.next().unwrap().parse().unwrap();
+        let cond_val: i32 = fields.parse("condition value")?;
+        fields.end()?;
+
+        Ok(Instruction { reg, op, val, cond_reg, cond, cond_val })
+    }).collect()
+}
 
-        if cond(regs.get(&cond_reg).unwrap_or(&0), &cond_val) {
-            let n = regs.entry(reg).or_default();
-            op(n, val);
+struct Instruction {
+    reg: String,
+    op: fn(&mut i32, i32),
+    val: i32,
+    cond_reg: String,
+    cond: fn(&i32, &i32) -> bool,
+    cond_val: i32,
+}
+
+fn run(instructions: &[Instruction]) {
+    let mut running_max = 0;
+    let mut regs = HashMap::new();
+    for instruction in instructions {
+        if (instruction.cond)(regs.get(&instruction.cond_reg).unwrap_or(&0), &instruction.cond_val) {
+            let n = regs.entry(instruction.reg.clone()).or_default();
+            (instruction.op)(n, instruction.val);
             if *n > running_max {
                 running_max = *n;
             }
@@ -41,9 +59,8 @@ fn run(path: &str) -> io::Result<()> {
     }
 
     let max = regs.values().fold(0, |a, &b| if a > b { a } else { b });
-    println!("Largest value in any register: {}", max);
-    println!("Largest value held at any time: {}", running_max);
-    Ok(())
+    println!("Part 1: Largest value in any register: {}", max);
+    println!("Part 2: Largest value held at any time: {}", running_max);
 }
 
 fn inc(val: &mut i32, amount: i32) {
@@ -1,11 +1,9 @@
-use std::path::Path;
-use std::fs::read_to_string;
 use std::io::Result;
 use std::str::Chars;
 
 fn main() {
-    let s = read_to_string("input.txt").expect("Failed to read string");
-    println!("{}", score(&s));
+    let s = locator::input!().read_to_string().expect("Failed to read string");
+    println!("Part 1: {}", score(&s));
 }
 
 fn score(s: &str) -> u32
@@ -20,7 +18,7 @@ fn score(s: &str) -> u32
             _ => (),
         }
     }
-    println!("Filtered {}", iter.count);
+    println!("Part 2: Filtered {}", iter.count);
     score
 }
 
@@ -1,11 +1,10 @@
-use std::fs::read_to_string;
 
 const SIZE: usize = 256;
 const SUFFIX: [usize; 5] = [17, 31, 73, 47, 23];
 const N_ROUNDS: usize = 64;
 
 fn main() {
-    let input = read_to_string("input.txt")
+    let input = locator::input!().read_to_string()
         .expect("Failed reading input");
 
     // Part 1
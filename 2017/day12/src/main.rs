@@ -1,10 +1,9 @@
-use std::path::Path;
 use std::io::Error;
-use std::fs::read_to_string;
 use std::collections::{HashSet, HashMap};
+use locator::Input;
 
 fn main() {
-    let edges = read_input("input.txt").expect("Failed to read input");
+    let edges = read_input(locator::input!()).expect("Failed to read input");
 
     // Part 1
     let mut visited = HashSet::new();
@@ -22,7 +21,7 @@ fn main() {
         }
     }
 
-    println!("Number of programs reachable from PID 0: {}", visited.len());
+    println!("Part 1: Number of programs reachable from PID 0: {}", visited.len());
 
     // Part 2
     let mut ngroups = 0;
@@ -49,12 +48,12 @@ fn main() {
         }
     }
 
-    println!("Number of groups: {}", ngroups);
+    println!("Part 2: Number of groups: {}", ngroups);
 }
 
-fn read_input<P: AsRef<Path>>(path: P) -> Result<HashMap<usize, HashSet<usize>>, Error> {
+fn read_input(input: impl Into<Input>) -> Result<HashMap<usize, HashSet<usize>>, Error> {
     let mut edges = HashMap::new();
-    for line in read_to_string(path)?.lines() {
+    for line in input.into().read_to_string()?.lines() {
         let parts: Vec<_> = line.split(" <-> ").collect();
         let a: usize = parts[0].parse().unwrap();
         let bs: HashSet<usize> = parts[1].split(", ").map(|v| v.parse().unwrap()).collect();
@@ -1,9 +1,8 @@
-use std::path::Path;
-use std::fs::read_to_string;
 use std::io::Error;
+use locator::Input;
 
 fn main() {
-    let layers = read_input("input.txt").expect("failed to read input");
+    let layers = read_input(locator::input!()).expect("failed to read input");
 
     // Part 1
     let severity: u32 = layers.iter()
@@ -24,10 +23,10 @@ fn main() {
 
 }
 
-fn read_input<P: AsRef<Path>>(path: P) -> Result<Vec<Layer>, Error> {
+fn read_input(input: impl Into<Input>) -> Result<Vec<Layer>, Error> {
     let mut result = Vec::new();
 
-    for line in read_to_string(path)?.lines() {
+    for line in input.into().read_to_string()?.lines() {
         let mut split = line.split(": ");
         let depth: u32 = split.next().unwrap().parse().expect("Failed to parse int");
         let range: u32 = split.next().unwrap().parse().expect("Failed to parse int");
@@ -1,4 +1,3 @@
-use std::fs;
 use std::collections;
 
 fn main() {
@@ -6,7 +5,7 @@ fn main() {
 
     // Part 1
     let freq: i32 = values.iter().sum();
-    println!("Summed frequency: {}Hz", freq);
+    println!("Part 1: Summed frequency: {} Hz", freq);
 
     // Part 2
     let mut freq = 0;
@@ -19,11 +18,11 @@ fn main() {
         seen.insert(freq);
     }
 
-    println!("First repeated frequency: {}Hz", freq);
+    println!("Part 2: First repeated frequency: {} Hz", freq);
 }
 
 fn parse_input() -> Vec<i32> {
-    let contents = fs::read_to_string("input.txt")
+    let contents = locator::input!().read_to_string()
         .expect("Something went wrong reading the file");
 
     let mut values = Vec::new();
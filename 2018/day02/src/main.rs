@@ -1,16 +1,15 @@
-use std::fs;
 use std::collections::HashMap;
 
 fn main() {
-    let input = fs::read_to_string("input.txt")
+    let input = locator::input!().read_to_string()
         .expect("Failed to read input");
-    println!("Checksum: {}", checksum(&input));
+    println!("Part 1: Checksum: {}", checksum(&input));
 
     for line1 in input.lines() {
         for line2 in input.lines() {
             let common = common(line1, line2);
             if common.len() == line1.len() - 1 {
-                println!("Close match! {}", common)
+                println!("Part 2: Close match! {}", common)
             }
         }
     }
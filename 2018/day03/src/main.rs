@@ -1,4 +1,3 @@
-use std::fs;
 use regex::Regex;
 
 fn main() {
@@ -18,7 +17,7 @@ fn main() {
         }
     }
 
-    println!("Overlap: {}", total_overlap);
+    println!("Part 1: Overlap: {}", total_overlap);
 
     for claim in claims.iter() {
         let mut overlap = 0;
@@ -30,13 +29,13 @@ fn main() {
             }
         }
         if overlap == 0 {
-            println!("Claim {} has no overlap", claim.id);
+            println!("Part 2: Claim {} has no overlap", claim.id);
         }
     }
 }
 
 fn read_claims() -> Vec<Claim> {
-    let input = fs::read_to_string("input.txt")
+    let input = locator::input!().read_to_string()
         .expect("Failed to read input");
 
     let mut claims = Vec::new();
@@ -1,8 +1,7 @@
-use std::fs;
 use std::collections::HashMap;
 
 fn main() {
-    let input = fs::read_to_string("input.txt")
+    let input = locator::input!().read_to_string()
         .expect("Failed to read input");
 
     let mut input: Vec<_> = input.lines().collect();
@@ -28,6 +27,7 @@ fn main() {
 
     let (&max_minute, _) = minutes_days.iter().max_by_key(|(_k, v)| **v).unwrap();
     println!("Max minute: {}", max_minute);
+    println!("Part 1: {}", max_guard * max_minute);
 
     // Part 2
     let mut max_guard = 0;
@@ -42,9 +42,8 @@ fn main() {
             }
         }
     }
-    println!("Guard {} is most frequently asleep at 00:{:02}", max_guard, max_minute)
-
-
+    println!("Guard {} is most frequently asleep at 00:{:02}", max_guard, max_minute);
+    println!("Part 2: {}", max_guard * max_minute as u32);
 }
 
 fn parse_schedule(input: Vec<&str>) -> HashMap<u32, [u32; 60]> {
@@ -1,18 +1,19 @@
-use std::fs;
 
 fn main() {
-    let input = fs::read_to_string("input.txt")
+    let input = locator::input!().read_to_string()
        .expect("Failed to read input");
     let input = input.trim();
 
     let result = reduce(&input, None).len();
-    println!("Number of units: {}", result);
-    assert_eq!(result, 10450);
+    println!("Part 1: Number of units: {}", result);
 
+    let mut shortest = result;
     for c in (b'a'..= b'z').map(|c| c as char) {
         let result = reduce(&input, Some(c));
         println!("{}: {}", c, result.len());
+        shortest = shortest.min(result.len());
     }
+    println!("Part 2: Shortest polymer: {}", shortest);
 }
 
 fn reduce(input: &str, ignore: Option<char>) -> String {
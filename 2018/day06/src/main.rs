@@ -1,43 +1,43 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::fs;
+use grid::{Bounds, Grid};
+use terminal::{Colour, Style, Terminal};
 
-const WIDTH: usize = 350;
-const HEIGHT: usize= 350;
 const MAX_SUM_DISTANCE: i32 = 10_000;
 
 fn main() {
-    let mut grid = Grid::new(WIDTH, HEIGHT);
     let input= read_input();
+    let bounds = Bounds::from_positions(input.iter().map(|c| (c.0 as i64, c.1 as i64)))
+        .expect("No coordinates");
+    let mut grid = Grid::new(bounds, Value::None);
 
     // Part 1
 
     let mut infinite_points = HashSet::new();
     let mut point_area: HashMap<u8, u32> = HashMap::new();
 
-    for y in 0..grid.height {
-        for x in 0..grid.width {
-            let c1 = Coord(x as i32, y as i32);
-            let mut distance: Vec<(i32, u8)> = Vec::new();
-            for (n, c2) in (1u8..).zip(&input) {
-                distance.push((c1.distance(c2), n));
-            }
-            distance.sort();
-
-            let n = distance[0].1;
-            if distance[0].0 != distance[1].0 {
-                grid.set(&c1, Value::Some(n));
-                *point_area.entry(n).or_default() += 1;
-                if x == 0 || y == 0 || x == grid.width - 1 || y == grid.height - 1 {
-                    infinite_points.insert(n);
-                }
-            } else {
-                grid.set(&c1, Value::Conflict);
+    for pos in bounds.positions() {
+        let c1 = Coord(pos.0 as i32, pos.1 as i32);
+        let mut distance: Vec<(i32, u8)> = Vec::new();
+        for (n, c2) in (1u8..).zip(&input) {
+            distance.push((c1.distance(c2), n));
+        }
+        distance.sort();
+
+        let n = distance[0].1;
+        if distance[0].0 != distance[1].0 {
+            grid.set(pos, Value::Some(n));
+            *point_area.entry(n).or_default() += 1;
+            if bounds.on_edge(pos) {
+                // Areas that reach the edge continue on forever
+                infinite_points.insert(n);
             }
+        } else {
+            grid.set(pos, Value::Conflict);
         }
     }
 
-    grid.print();
+    print(&grid);
 
     for k in &infinite_points {
         point_area.remove(k);
@@ -47,29 +47,29 @@ fn main() {
     area_point.sort();
 
     let (area, point) = area_point.last().unwrap();
-    println!("Point #{} has the largest non-infinite area: {}", point, area);
+    println!("Part 1: Point #{} has the largest non-infinite area: {}", point, area);
 
     // Part 2
 
     let mut region_size = 0;
 
-    for y in 0..grid.height {
-        for x in 0..grid.width {
-            let c1 = Coord(x as i32, y as i32);
-            if input.iter().map(|c2| c1.distance(c2)).sum::<i32>()
-                    < MAX_SUM_DISTANCE {
-                region_size += 1;
-            }
+    // Every coordinate is at least this far away from a location this far outside the bounds
+    let margin = (MAX_SUM_DISTANCE / input.len() as i32) as i64;
+    for pos in bounds.grow(margin).positions() {
+        let c1 = Coord(pos.0 as i32, pos.1 as i32);
+        if input.iter().map(|c2| c1.distance(c2)).sum::<i32>()
+                < MAX_SUM_DISTANCE {
+            region_size += 1;
         }
     }
 
-    println!("The largest region is sized {} units", region_size);
+    println!("Part 2: The largest region is sized {} units", region_size);
 }
 
 fn read_input() -> Vec<Coord> {
     let mut result = Vec::new();
 
-    let input = fs::read_to_string("input.txt")
+    let input = locator::input!().read_to_string()
         .expect("Failed to read file");
 
     for line in input.lines() {
@@ -99,40 +99,26 @@ enum Value {
 }
 
 impl Value {
-    fn format(&self) -> String {
+    /// Two characters showing the value, and their style
+    fn format(&self) -> (String, Style) {
         match self {
-            Value::None => String::from(" ."),
-            Value::Some(v) => sgr(31 + v%5) + &format!("{:2}", v) + &sgr(0),
-            Value::Conflict => sgr(37) + " *" + &sgr(0),
+            Value::None => (String::from(" ."), Style::PLAIN),
+            Value::Some(v) => (format!("{:2}", v), Style::fg(Colour::ALL[1 + (v % 5) as usize])),
+            Value::Conflict => (String::from(" *"), Style::fg(Colour::White)),
         }
     }
 }
 
-struct Grid {
-    width: usize,
-    height: usize,
-    values: Vec<Vec<Value>>,
-}
-
-impl Grid {
-    fn new(width: usize, height: usize) -> Grid {
-        Grid {width, height, values: vec![vec![Value::None; width]; height] }
-    }
-
-    fn print(&self) {
-        for row in &self.values {
-            println!("{}", row.iter()
-                .map(|v| v.format())
-                .collect::<Vec<String>>()
-                .join(" "))
+fn print(grid: &Grid<Value>) {
+    let mut terminal = Terminal::new(grid.width() * 3 - 1, grid.height());
+    let frame = terminal.frame();
+    for (y, row) in grid.rows().enumerate() {
+        for (x, v) in row.iter().enumerate() {
+            let (text, style) = v.format();
+            frame.print(x * 3, y, &text, Some(style));
         }
     }
 
-    fn set(&mut self, coord: &Coord, value: Value) {
-        self.values[coord.1 as usize][coord.0 as usize] = value;
-    }
-}
-
-fn sgr(n: u8) -> String {
-    format!("\x1b[{}m", n)
+    terminal.present().expect("Failed to draw grid");
+    terminal.finish().expect("Failed to draw grid");
 }
@@ -2,7 +2,6 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::BinaryHeap;
 use std::cmp::Ordering;
-use std::fs;
 
 fn main() {
     let graph = read_input();
@@ -11,16 +10,17 @@ fn main() {
         println!("{} -> {:?}", step, node);
     }
 
-    let steps: String = graph.walk(1).into_iter().collect();
-    println!("Steps with one worker: {}", steps);
+    let (steps, _) = graph.walk(1);
+    println!("Part 1: Steps with one worker: {}", steps.into_iter().collect::<String>());
 
     let n = 5;
-    let steps: String = graph.walk(n).into_iter().collect();
-    println!("Steps with {} workers: {}", n, steps);
+    let (steps, time) = graph.walk(n);
+    println!("Steps with {} workers: {}", n, steps.into_iter().collect::<String>());
+    println!("Part 2: Time with {} workers: {}", n, time);
 }
 
 fn read_input() -> Graph {
-    let input = fs::read_to_string("input.txt")
+    let input = locator::input!().read_to_string()
         .expect("Failed to read input");
 
     let mut graph = Graph::new();
@@ -52,7 +52,8 @@ impl Graph {
         self.nodes.entry(s1).or_default().push(s2);
     }
 
-    fn walk(&self, workers: u32) -> Vec<char> {
+    /// The order steps are finished in, and how long they take
+    fn walk(&self, workers: u32) -> (Vec<char>, u32) {
         assert_ne!(workers, 0);
 
         let mut result: Vec<char> = Vec::new();
@@ -60,10 +61,12 @@ impl Graph {
         let mut finished = HashSet::new();
         let mut workers: Vec<u32> = (1..=workers).collect();
         let mut queue: BinaryHeap<Entry> = BinaryHeap::new();
+        let mut time = 0;
 
         todo.extend(self.nodes.keys());
 
         for t in 0.. {
+            time = t;
             while !queue.is_empty() && queue.peek().unwrap().t <= t {
                 let Entry { t: _, worker, step } = queue.pop().unwrap();
                 workers.push(worker);
@@ -95,7 +98,7 @@ impl Graph {
             println!("t={} todo={:?} queue={:?}", t, todo, queue);
         }
 
-        result
+        (result, time)
     }
 }
 
@@ -1,15 +1,14 @@
-use std::fs;
 
 fn main() {
     let input = read_input();
     let root = parse(&mut input.into_iter());
 
-    println!("Metadata sum: {}", root.metadata_sum());
-    println!("Value: {}", root.value());
+    println!("Part 1: Metadata sum: {}", root.metadata_sum());
+    println!("Part 2: Value: {}", root.value());
 }
 
 fn read_input() -> Vec<u32> {
-    let input = fs::read_to_string("input.txt")
+    let input = locator::input!().read_to_string()
         .expect("Failed to read input");
 
     input.split_whitespace()
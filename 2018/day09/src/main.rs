@@ -1,37 +1,32 @@
-use std::collections::HashMap;
+use std::collections::VecDeque;
 
 const N_PLAYERS: u32 = 429;
 const TOP_MARBLE: u32 = 70901;
 
 fn main() {
-    let mut circle: Vec<u32> = Vec::new();
-    circle.push(0);
+    println!("Part 1: Top Score: {}", play(N_PLAYERS, TOP_MARBLE));
+    println!("Part 2: Top Score: {}", play(N_PLAYERS, 100 * TOP_MARBLE));
+}
 
-    let mut score: HashMap<u32, u32> = HashMap::new();
+/// The winning score, keeping the current marble at the back of the circle
+fn play(n_players: u32, top_marble: u32) -> u64 {
+    let mut circle: VecDeque<u32> = VecDeque::new();
+    circle.push_back(0);
 
-    let mut idx: usize = 0;
-    for n in 1..=TOP_MARBLE {
-        let player = ((n - 1) % N_PLAYERS) + 1;
+    let mut scores = vec![0; n_players as usize];
+    for n in 1..=top_marble {
         if n % 23 == 0 {
-            idx = (idx + circle.len() - 7) % circle.len();
-            *score.entry(player).or_default() += n + circle.remove(idx);
-            idx = idx % circle.len();
+            // Take the marble 7 counter-clockwise, then the one clockwise of it is current
+            circle.rotate_right(7);
+            let removed = circle.pop_back().unwrap();
+            scores[((n - 1) % n_players) as usize] += u64::from(n + removed);
+            circle.rotate_left(1);
         } else {
-            idx = (idx + 2) % circle.len();
-            if idx == 0 {
-                idx = circle.len();
-            }
-            circle.insert(idx, n);
-        }
-
-        if n % (TOP_MARBLE/100) == 0 {
-            println!("{}%", 100*n/TOP_MARBLE);
+            // Place between the marbles 1 and 2 clockwise of the current one
+            circle.rotate_left(1);
+            circle.push_back(n);
         }
-
-        //println!("{}: {:?}  (current: {})", player, circle, &circle[idx]);
     }
 
-    let mut top_scores: Vec<_> = score.values().collect();
-    top_scores.sort();
-    println!("Top Score: {:#?}", top_scores.pop().unwrap());
+    scores.into_iter().max().unwrap_or(0)
 }
@@ -1,11 +1,29 @@
-use std::fs;
+use std::time::Duration;
+use grid::{Bounds, Grid};
+use locator::Input;
+use parsing::{ParseError, Span};
+use picture::{Animation, Image, Palette};
 
 const WIDTH: usize = 200;
 const HEIGHT: usize = 200;
 
+// Images show the message with a border, and a pixel is this many pixels square
+const IMAGE_MARGIN: i64 = 4;
+const IMAGE_SCALE: usize = 4;
+
+// The animation shows the points converging over this many ticks, then holds on the message
+const GIF_TICKS: i32 = 40;
+const GIF_DELAY: Duration = Duration::from_millis(100);
+const GIF_HOLD: Duration = Duration::from_secs(3);
+
 fn main() {
-    let mut input = read_input();
+    // The --png FILE and --gif FILE flags also save the message as an image and an animation
+    let (input, mut args) = locator::locator!().split_args();
+    let png = picture::output_arg(&mut args, "--png");
+    let gif = picture::output_arg(&mut args, "--gif");
+    let start = read_input(input);
 
+    let mut input = start.clone();
     let mut t = 0;
     while input[0].distance(&input[1]) > 1 {
         for point in &mut input {
@@ -14,28 +32,56 @@ fn main() {
         t += 1;
     }
 
+    let bounds = Bounds::from_positions(input.iter().map(|p| (p.position.0 as i64, p.position.1 as i64)))
+        .expect("No points")
+        .grow(IMAGE_MARGIN);
+    let message = ocr::read(&sky(&input, bounds), |&point| point);
+
     println!("{:?}", input);
-    println!("After {} ticks:", t);
+    println!("Part 1: Message: {}", message.as_deref().unwrap_or("(unrecognised)"));
+    println!("Part 2: After {} ticks:", t);
     draw(&input);
+    let palette = Palette::new(0x0f0f23).with(true, 0xffff66);
+
+    if let Some(path) = png {
+        Image::from_grid(&sky(&input, bounds), &palette).scale(IMAGE_SCALE).save_png(path)
+            .expect("Failed to save PNG");
+    }
+
+    if let Some(path) = gif {
+        let mut animation = Animation::create(path).expect("Failed to create GIF");
+        for s in (t - GIF_TICKS).max(0)..=t {
+            let points: Vec<Point> = start.iter().map(|p| p.after(s)).collect();
+            let delay = if s == t { GIF_HOLD } else { GIF_DELAY };
+            animation.frame(&Image::from_grid(&sky(&points, bounds), &palette).scale(IMAGE_SCALE), delay)
+                .expect("Failed to write GIF");
+        }
+        animation.finish().expect("Failed to write GIF");
+    }
+}
+
+fn read_input(input: Input) -> Vec<Point> {
+    parsing::read_input(input, parse_input)
 }
 
-fn read_input() -> Vec<Point> {
-    let mut result = Vec::new();
-    let input = fs::read_to_string("input.txt")
-        .expect("Failed to read input");
-
-    for line in input.lines() {
-        let p1: i32 = (&line[10..16]).trim().parse().expect("Failed to parse");
-        let p2: i32 = (&line[18..24]).trim().parse().expect("Failed to parse");
-        let v1: i32 = (&line[36..38]).trim().parse().expect("Failed to parse");
-        let v2: i32 = (&line[40..42]).trim().parse().expect("Failed to parses");
-        result.push(Point { position: (p1, p2), velocity: (v1, v2)})
+/// Parse lines like `position=< 9,  1> velocity=< 0,  2>`
+///
+/// There must be at least two points, as the message is found by how close together they get.
+fn parse_input(input: &str) -> Result<Vec<Point>, ParseError> {
+    let points: Vec<Point> = Span::new(input).lines().map(|line| {
+        let [p1, p2, v1, v2] = line.numbers_exact()?;
+
+        Ok(Point { position: (p1, p2), velocity: (v1, v2) })
+    }).collect::<Result<_, ParseError>>()?;
+
+    if points.len() < 2 {
+        return Err(Span::new(input).end().error(format!("Expected at least 2 points, not {}", points.len())));
     }
 
-    result
+    Ok(points)
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct Point {
     position: (i32, i32),
     velocity: (i32, i32),
@@ -46,6 +92,12 @@ impl Point {
         self.position = (self.position.0 + self.velocity.0, self.position.1 + self.velocity.1);
     }
 
+    /// The point after `t` ticks
+    fn after(&self, t: i32) -> Point {
+        let position = (self.position.0 + t * self.velocity.0, self.position.1 + t * self.velocity.1);
+        Point { position, velocity: self.velocity }
+    }
+
     fn distance(&self, other: &Point) -> i32 {
         (self.position.0 - other.position.0)^2 + (self.position.1 - other.position.1)^2
     }
@@ -67,3 +119,15 @@ fn draw(input: &Vec<Point>) {
         println!("{}", line);
     }
 }
+
+/// Which parts of the sky within `bounds` have a point
+fn sky(points: &[Point], bounds: Bounds) -> Grid<bool> {
+    let mut grid = Grid::new(bounds, false);
+    for point in points {
+        if let Some(cell) = grid.get_mut((point.position.0 as i64, point.position.1 as i64)) {
+            *cell = true;
+        }
+    }
+
+    grid
+}
@@ -35,7 +35,7 @@ fn main() {
         }
     }
 
-    println!("Max power for 3x3 is {}JW at {:?}", max_power, max_coord);
+    println!("Part 1: Max power for 3x3 is {}JW at {:?}", max_power, max_coord);
 
     // Part 2
 
@@ -62,7 +62,7 @@ fn main() {
         }
     }
 
-    println!("Max power {}JW at {:?} (size: {})", max_power, max_coord, max_size);
+    println!("Part 2: Max power {}JW at {:?} (size: {})", max_power, max_coord, max_size);
 }
 
 fn power(coord: (i32, i32), serial: i32) -> i32 {
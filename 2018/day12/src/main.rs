@@ -1,92 +1,110 @@
-use std::collections::HashMap;
-use std::fs;
+use std::collections::{HashMap, HashSet};
+use automaton::{Automaton, Sparse};
 
-const N_POTS: usize = 250;
-const OFFSET: usize = 10;
 const WINDOW: usize = 5;
-const GENERATIONS: u64 = 135;
+const PART1_GENERATIONS: u64 = 20;
+const PART2_GENERATIONS: u64 = 50_000_000_000;
+
+type Rules = HashMap<[bool; WINDOW], bool>;
+type World<R> = Sparse<i64, fn(i64) -> [i64; WINDOW - 1], R>;
 
 fn main() {
-    let mut world = read_input();
+    let (initial, rules) = read_input();
 
     // Part 1
-    println!("{:>3} [{:>5}] {:>width$}", "GEN", "SUM", "0", width=OFFSET+1);
-    while world.generation < GENERATIONS {
-        world.print();
-        world.tick();
+    let mut world = new_world(&initial, &rules);
+    let mut history = vec![world.live().clone()];
+    while world.generation() < PART1_GENERATIONS {
+        world.step();
+        history.push(world.live().clone());
     }
-    world.print();
+    print(&history);
+    println!("Part 1: After {} generations the sum is {}", PART1_GENERATIONS, sum(world.live()));
 
     // Part 2
-    // Empirically we can see that by generation 129 that the plants have reached a steady-state
-    // where the pattern shifts right by one each generation.
-    //
-    // We can use this to get the formula:
-    //   sum = (gen + 36) * 52
-    //
-    // Thus at gen 5,000,000,000 the sum of pots with plants is 2,600,000,001,872.
+    // Eventually the plants reach a steady-state where the pattern repeats,
+    // but shifted along, so the sum changes by the same amount each cycle.
+    let mut world = new_world(&initial, &rules);
+    let cycle = automaton::find_cycle(&mut world, PART2_GENERATIONS, |w| pattern(w.live()))
+        .expect("Plants never reached a steady-state");
+    while (PART2_GENERATIONS - world.generation()) % cycle.period != 0 {
+        world.step();
+    }
+
+    let start_sum = sum(world.live());
+    for _ in 0..cycle.period {
+        world.step();
+    }
+    let delta = sum(world.live()) - start_sum;
+    let remaining_cycles = (PART2_GENERATIONS - world.generation()) / cycle.period;
+    let total = sum(world.live()) + remaining_cycles as i64 * delta;
+
+    println!("Steady-state from generation {} (changing by {} every {} generations)", cycle.start, delta, cycle.period);
+    println!("Part 2: After {} generations the sum is {}", PART2_GENERATIONS, total);
 }
 
-fn read_input() -> World {
-    let input = fs::read_to_string("input.txt")
+fn read_input() -> (Vec<i64>, Rules) {
+    let input = locator::input!().read_to_string()
         .expect("Failed to read input");
 
-    let mut state = [false; N_POTS];
+    let mut initial = Vec::new();
     let mut rules = HashMap::new();
 
     for (n, line) in input.lines().enumerate() {
         if n == 0 {
-            let input = parse(&line[15..]);
-            state[OFFSET..OFFSET+input.len()].copy_from_slice(&input);
+            initial = parse(&line[15..]).into_iter()
+                .zip(0..)
+                .filter(|&(plant, _)| plant)
+                .map(|(_, pot)| pot)
+                .collect();
         } else if n >= 2 {
             let mut input = [false; WINDOW];
             input.copy_from_slice(&parse(&line[..5]));
             let output = &line[9..10] == "#";
             rules.insert(input, output);
-            //println!("{} -> {}", &line[..5], &line[9..10]);
         }
     }
 
-    World { state, rules, generation: 0 }
+    (initial, rules)
 }
 
 fn parse(input: &str) -> Vec<bool> {
     input.trim().chars().map(|c| c == '#').collect()
 }
 
-struct World {
-    state: [bool; N_POTS],
-    rules: HashMap<[bool; WINDOW], bool>,
-    generation: u64,
+fn new_world<'a>(initial: &[i64], rules: &'a Rules) -> World<impl Fn(bool, &[bool]) -> bool + 'a> {
+    fn neighbours(pot: i64) -> [i64; WINDOW - 1] {
+        [pot - 2, pot - 1, pot + 1, pot + 2]
+    }
+
+    let rule = move |plant, n: &[bool]| {
+        rules.get(&[n[0], n[1], plant, n[2], n[3]]).copied().unwrap_or(false)
+    };
+
+    Sparse::new(initial.iter().copied(), neighbours as fn(i64) -> [i64; WINDOW - 1], rule)
+}
+
+/// Sum of the numbers of all pots with plants
+fn sum(plants: &HashSet<i64>) -> i64 {
+    plants.iter().sum()
 }
 
-impl World {
-    fn tick(&mut self) {
-        let mut state = [false; N_POTS];
-        for idx in 0..=N_POTS-WINDOW {
-            let mut last_window = [false; WINDOW];
-            last_window.copy_from_slice( &self.state[idx..idx+WINDOW]);
-            let window = &mut state[idx..idx+WINDOW];
-            if let Some(result) = self.rules.get(&last_window) {
-                window[2] = *result;
-            }
-        }
-        self.state = state;
-        self.generation += 1;
-    }
+/// Arrangement of plants, regardless of where they are
+fn pattern(plants: &HashSet<i64>) -> Vec<i64> {
+    let min = plants.iter().copied().min().unwrap_or(0);
+    let mut pattern: Vec<i64> = plants.iter().map(|&pot| pot - min).collect();
+    pattern.sort();
 
-    fn print(&self) {
-        let line: Vec<&str> = self.state.iter().map(|&b| if b { "#" } else { "." }).collect();
-        println!("{:3} [{:5}] {}", self.generation, self.sum(), line.join(""))
-    }
+    pattern
+}
 
-    fn sum(&self) -> i32 {
-        let mut sum: i32 = 0;
-        for idx in 0..N_POTS {
-            if self.state[idx] {
-                sum += idx as i32 - OFFSET as i32;
-            }
-        }
-        sum
+fn print(history: &[HashSet<i64>]) {
+    let min = history.iter().flatten().copied().min().unwrap_or(0).min(0);
+    let max = history.iter().flatten().copied().max().unwrap_or(0);
+
+    println!("{:>3} [{:>5}] {:>width$}", "GEN", "SUM", "0", width=(1 - min) as usize);
+    for (generation, plants) in history.iter().enumerate() {
+        let line: String = (min..=max).map(|pot| if plants.contains(&pot) { '#' } else { '.' }).collect();
+        println!("{:3} [{:5}] {}", generation, sum(plants), line)
     }
 }
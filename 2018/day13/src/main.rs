@@ -1,5 +1,5 @@
-use std::collections::HashMap;
-use std::fs;
+use parsing::{ParseError, Span};
+use terminal::{Colour, Palette, Style};
 
 const UP: char = '^';
 const DOWN: char = 'v';
@@ -12,20 +12,24 @@ const HTRACK: char = '-';
 const XSECT: char = '+';
 
 fn main() {
+    let world = parsing::read_input(locator::input!(), World::parse);
+
     // Part 1
-    run_until_first_crash(World::from_file("input.txt"));
+    run_until_first_crash(world.clone());
 
     // Part 2
-    run_until_all_but_one_crashed(World::from_file("input.txt"));
+    run_until_all_but_one_crashed(world);
 }
 
 fn run_until_first_crash(mut world: World) {
     println!("PART 1");
     println!("Initial state");
     world.print();
-    while world.num_cart_crashed() == 0 && world.t < 16 {
-        world.tick();
-    }
+    let (x, y) = loop {
+        if let Some(&crash) = world.tick().first() {
+            break crash;
+        }
+    };
     println!();
 
     println!("Final state (first crash)");
@@ -34,6 +38,7 @@ fn run_until_first_crash(mut world: World) {
         println!("Cart{} at {},{}", if cart.crashed { " [crashed]" } else { "" },
                  cart.position.0, cart.position.1)
     }
+    println!("Part 1: First crash at {},{}", x, y);
 }
 
 fn run_until_all_but_one_crashed(mut world: World) {
@@ -51,36 +56,40 @@ fn run_until_all_but_one_crashed(mut world: World) {
         println!("Cart{} at {},{}", if cart.crashed { " [crashed]" } else { "" },
                  cart.position.0, cart.position.1)
     }
+    if let Some(cart) = world.carts.iter().find(|c| !c.crashed) {
+        println!("Part 2: Last cart at {},{}", cart.position.0, cart.position.1);
+    }
 }
 
+#[derive(Clone)]
 struct World {
     map: Vec<Vec<char>>,
     carts: Vec<Cart>,
-    t: u32,
 }
 
 impl World {
-    fn from_file(path: &str) -> World {
+    fn parse(input: &str) -> Result<World, ParseError> {
         let mut map = Vec::new();
         let mut carts = Vec::new();
 
-        let input = fs::read_to_string(path)
-            .expect("Failed to read input");
-
-        for (y, line) in input.lines().enumerate() {
+        for (y, line) in Span::new(input).lines().enumerate() {
             let mut line_map = Vec::new();
-            for (x, val) in line.chars().enumerate() {
+            for (x, (i, val)) in line.as_str().char_indices().enumerate() {
+                let track = World::track(val)
+                    .ok_or_else(|| line.slice(i..i + val.len_utf8()).error("Unknown track"))?;
                 if World::is_cart(val) {
-                    line_map.push(World::cart_track(val));
                     carts.push(Cart::new((x, y), val));
-                } else {
-                    line_map.push(val);
                 }
+                line_map.push(track);
             }
             map.push(line_map);
         }
 
-        World { map, carts, t: 0 }
+        if carts.is_empty() {
+            return Err(Span::new(input).end().error("No carts"));
+        }
+
+        Ok(World { map, carts })
     }
 
     fn print(&self) {
@@ -94,58 +103,52 @@ impl World {
         }
 
         // Show map
+        let palette = [UP, DOWN, LEFT, RIGHT].iter()
+            .fold(Palette::new(), |palette, &cart| palette.with(cart, Style::fg(Colour::Red)));
         for (y, row) in map.iter().enumerate() {
-            print!("{:3} ", y);
-            for &val in row {
-                if World::is_cart(val) {
-                    print!("\x1b[31m{}\x1b[0m", val)  // RED
-                } else {
-                    print!("{}", val)
-                }
-            }
-            println!()
+            println!("{:3} {}", y, palette.paint(&row.iter().collect::<String>()));
         }
     }
 
-    fn tick(&mut self)  {
+    /// Move every cart, returning where any crashes happened (in order)
+    fn tick(&mut self) -> Vec<(usize, usize)> {
         // Sort by row, then column
         self.carts.sort_by_key(|c| (c.position.1, c.position.0));
 
-        let mut positions: HashMap<(usize, usize), &mut Cart> = HashMap::new();
-        for cart in self.carts.iter_mut().filter(|c| !c.crashed) {
-            // Has anyone crashed into us?
-            if let Some(other_cart) = positions.get_mut(&cart.position) {
-                cart.crashed = true;
-                other_cart.crashed = true;
+        let mut crashes = Vec::new();
+        for i in 0..self.carts.len() {
+            if self.carts[i].crashed {
                 continue;
             }
 
-            let track = self.map[cart.position.1][cart.position.0];
-            cart.tick(track);
-
-            // Have we just crashed into anyone?
-            if let Some(other_cart) = positions.get_mut(&cart.position) {
-                cart.crashed = true;
-                other_cart.crashed = true;
-                continue;
+            let (x, y) = self.carts[i].position;
+            self.carts[i].tick(self.map[y][x]);
+
+            // Have we just crashed into anyone (whether or not they've moved yet)?
+            let position = self.carts[i].position;
+            let other = (0..self.carts.len())
+                .find(|&j| j != i && !self.carts[j].crashed && self.carts[j].position == position);
+            if let Some(j) = other {
+                self.carts[i].crashed = true;
+                self.carts[j].crashed = true;
+                crashes.push(position);
             }
-
-            positions.insert(cart.position, cart);
         }
-        self.t += 1;
+
+        crashes
     }
 
     fn is_cart(c: char) -> bool {
         c == UP || c == DOWN || c == LEFT || c == RIGHT
     }
 
-    fn cart_track(c: char) -> char {
-        if c == UP || c == DOWN {
-            VTRACK
-        } else if c == LEFT || c == RIGHT {
-            HTRACK
-        } else {
-            panic!("Unknown value {:?}", c);
+    /// The track at a position on the map, including under a cart
+    fn track(c: char) -> Option<char> {
+        match c {
+            UP | DOWN => Some(VTRACK),
+            LEFT | RIGHT => Some(HTRACK),
+            FCURVE | BCURVE | VTRACK | HTRACK | XSECT | ' ' => Some(c),
+            _ => None,
         }
     }
 
@@ -154,6 +157,7 @@ impl World {
     }
 }
 
+#[derive(Clone)]
 struct Cart {
     position: (usize, usize),
     direction: char,
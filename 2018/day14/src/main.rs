@@ -5,13 +5,13 @@ fn main() {
     assert_eq!(part1(5), "0124515891");
     assert_eq!(part1(18), "9251071085");
     assert_eq!(part1(2018), "5941429882");
-    println!("After {} recipes, the scores of the next ten would be {}.", INPUT, part1(INPUT as usize));
+    println!("Part 1: After {} recipes, the scores of the next ten would be {}.", INPUT, part1(INPUT as usize));
 
     assert_eq!(part2("51589"), 9);
     assert_eq!(part2("01245"), 5);
     assert_eq!(part2("92510"), 18);
     assert_eq!(part2("59414"), 2018);
-    println!("{} first appears after {} recipes.", INPUT, part2(&INPUT.to_string()));
+    println!("Part 2: {} first appears after {} recipes.", INPUT, part2(&INPUT.to_string()));
 }
 
 fn part1(input: usize) -> String{
@@ -1,9 +1,8 @@
 use std::collections::HashSet;
 use std::fmt;
-use std::fs;
+use locator::Input;
+use terminal::{Colour, Palette, Style};
 
-const INF: i32 = std::i32::MAX;
-const UNSET: i32 = -1;
 const DEBUG: bool = false;
 
 fn main() {
@@ -14,7 +13,7 @@ fn main() {
 
 fn part1() {
     println!("PART 1");
-    let mut world = World::from_file("input.txt");
+    let mut world = World::from_file(locator::input!());
     world.print();
 
     while world.round() {
@@ -27,13 +26,14 @@ fn part1() {
     let elf_total_hp: i32 = world.chars.iter().filter(|c| c.race == 'E').map(|c| c.hp).sum();
     println!("Goblins have {} total hit points left", goblin_total_hp);
     println!("Elves have {} total hit points left", elf_total_hp);
+    println!("Part 1: Outcome: {}", world.n_rounds as i32 * (goblin_total_hp + elf_total_hp));
 }
 
 fn part2() {
     println!("PART 2");
     for ap in 4.. {
         println!("Trial with Elves {} attack power...", ap);
-        let mut world = World::from_file("input.txt");
+        let mut world = World::from_file(locator::input!());
         for c in &mut world.chars {
             if c.race == 'E' {
                 c.ap = ap;
@@ -52,14 +52,13 @@ fn part2() {
         println!("  Elves have {} total hit points left ({} deaths)", elf_total_hp, elf_deaths);
 
         if elf_deaths == 0 {
+            println!("Part 2: Outcome: {}", world.n_rounds as i32 * (goblin_total_hp + elf_total_hp));
             break;
         }
     }
-
 }
 
 type Map = Vec<Vec<char>>;
-type DistanceMap = Vec<Vec<i32>>;
 
 struct World {
     map: Map,
@@ -68,11 +67,11 @@ struct World {
 }
 
 impl World {
-    fn from_file(filename: &str) -> World {
+    fn from_file(input: impl Into<Input>) -> World {
         let mut map = Vec::new();
         let mut chars = Vec::new();
 
-        let input = fs::read_to_string(filename)
+        let input = input.into().read_to_string()
             .expect("Failed to read input");
         for (y, line) in input.lines().enumerate() {
             let mut line_map = Vec::new();
@@ -104,16 +103,12 @@ impl World {
             println!("- {}", c);
         }
 
+        let palette = Palette::new()
+            .with('E', Style::fg(Colour::Green))
+            .with('G', Style::fg(Colour::Red));
         for (y, line) in map.iter().enumerate() {
-            print!("{:2} ", y);
-            for &c in line {
-                match c {
-                    'E' => print!("\x1b[32mE\x1b[0m "),  // Green
-                    'G' => print!("\x1b[31mG\x1b[0m "),  // Red
-                    x => print!("{} ", x),
-                }
-            }
-            println!();
+            let line: String = line.iter().map(|c| format!("{} ", c)).collect();
+            println!("{:2} {}", y, palette.paint(&line));
         }
         println!();
     }
@@ -246,39 +241,33 @@ impl Character {
             return None;
         }
 
-        let reachable = reachable_distance(targets, self.position, &map);
-        if reachable.is_empty() {
-            if DEBUG { println!("{} has no reachable targets!", self) };
-            return None;
-        }
-
-        // Take a single step towards closest reachable target
-        let &(target, _distance) = reachable.first().unwrap();
-        let distance_map = build_distance_map(target, &map);
-
-        if DEBUG { print_distance_map(&distance_map) };
-
-        let mut adjacent: Vec<Pos> = self.adjacent().into_iter().filter(|&p| distance_map[p.1][p.0] >= 0).collect();
-        adjacent.sort_by_key(|&p| (distance_map[p.1][p.0], p.1, p.0));
+        // Find the closest reachable target (first in reading order)
+        let neighbours = |&pos: &Pos| {
+            World::adjacent(pos).into_iter()
+                .filter(|&p| map[p.1][p.0] == '.')
+                .map(|p| (p, 1))
+                .collect::<Vec<_>>()
+        };
+        let search = pathfinding::bfs(&neighbours, vec![self.position], |p| targets.contains(p));
+        let distance = match search.goal_cost() {
+            Some(distance) => distance,
+            None => {
+                if DEBUG { println!("{} has no reachable targets!", self) };
+                return None;
+            }
+        };
+        let &target = targets.iter()
+            .filter(|&t| search.cost(t) == Some(distance))
+            .min_by_key(|&&(x, y)| (y, x))
+            .unwrap();
 
-        Some(adjacent[0])
-    }
-}
+        // Take a single step towards it, preferring steps first in reading order
+        let path = search.path_to_by_key(&target, |&(x, y)| (y, x)).unwrap();
 
-fn reachable_distance(positions: &HashSet<Pos>, origin: Pos, map: &Map) -> Vec<(Pos, i32)> {
-    let mut result = Vec::new();
-    let distance_map = build_distance_map(origin, map);
+        if DEBUG { println!("{} planned path {:?}", self, path) };
 
-    for &pos in positions {
-        let distance = distance_map[pos.1][pos.0];
-        if 0 <= distance && distance < INF {
-            result.push((pos, distance));
-        }
+        Some(path[1])
     }
-
-    result.sort_by_key(|&(p, d)| (d, p.1, p.0));
-
-    result
 }
 
 fn map_with_characters(map: &Map, characters: &Vec<Character>) -> Map {
@@ -293,47 +282,3 @@ fn map_with_characters(map: &Map, characters: &Vec<Character>) -> Map {
 
     map
 }
-
-fn build_distance_map(origin: Pos, map: &Map) -> DistanceMap {
-    let mut distance_map: DistanceMap = Vec::new();
-
-    for line in map {
-        distance_map.push(line.iter().map(|&c| if c != '.' { INF } else { UNSET }).collect());
-    }
-
-    let mut edge: HashSet<Pos> = HashSet::new();
-    let mut new_edge: HashSet<Pos> = HashSet::new();
-    edge.insert(origin);
-    distance_map[origin.1][origin.0] = 0;
-    while !edge.is_empty() {
-        for &pos in &edge {
-            let distance: i32 = distance_map[pos.1][pos.0];
-            for &adj_pos in &World::adjacent(pos) {
-                if distance_map[adj_pos.1][adj_pos.0] == UNSET {
-                    distance_map[adj_pos.1][adj_pos.0] = distance + 1;
-                    new_edge.insert(adj_pos);
-                }
-            }
-        }
-
-        edge = new_edge;
-        new_edge = HashSet::new();
-    }
-
-    distance_map
-}
-
-fn print_distance_map(distance_map: &DistanceMap) {
-    for (y, row) in distance_map.iter().enumerate() {
-        print!("{:2}", y);
-        for &val in row {
-            match val {
-                INF => print!(" X"),
-                UNSET => print!(" ?"),
-                x => print!("{:2}", x),
-            }
-        }
-        println!();
-    }
-    println!();
-}
@@ -1,8 +1,8 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::fs;
 use std::slice::Iter;
 use std::str;
+use locator::Input;
 
 const N_OPCODES: usize = 16;
 
@@ -12,7 +12,7 @@ fn main() {
 }
 
 fn part1() {
-    let (samples, _) = read_input("input.txt");
+    let (samples, _) = read_input(locator::input!());
 
     let mut count = 0;
     for sample in &samples {
@@ -26,11 +26,11 @@ fn part1() {
         }
     }
 
-    println!("{} samples match three or more opcodes", count);
+    println!("Part 1: {} samples match three or more opcodes", count);
 }
 
 fn part2() {
-    let (samples, program) = read_input("input.txt");
+    let (samples, program) = read_input(locator::input!());
 
     // Find possible matches from samples
     let mut opcodes: HashMap<usize, HashSet<Opcode>> = HashMap::new();
@@ -76,11 +76,11 @@ fn part2() {
         opcode.call(&mut reg, instr[1], instr[2], instr[3]);
     }
 
-    println!("Value in register 0: {}", reg[0]);
+    println!("Part 2: Value in register 0: {}", reg[0]);
 }
 
-fn read_input(filename: &str) -> (Vec<Sample>, Vec<Instruction>) {
-    let input = fs::read_to_string(filename)
+fn read_input(input: impl Into<Input>) -> (Vec<Sample>, Vec<Instruction>) {
+    let input = input.into().read_to_string()
         .expect("Failed to read input");
 
     let (input_samples, input_program) = input.split_at(
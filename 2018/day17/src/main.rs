@@ -1,42 +1,75 @@
 use std::collections::HashSet;
 use std::collections::VecDeque;
-use std::fs;
+use std::time::Duration;
+use grid::{Bounds, SparseGrid};
+use locator::Input;
+use picture::{Animation, Image, Palette};
+use terminal::{Colour, Style};
 
-const WIDTH: usize = 300;
-const OFFSET: usize = 300;
-const SPRING: (usize, usize) = (500, 0);
+const SPRING: Pos = (500, 0);
 const N_ITERATIONS: usize = 1000;
 
+// Each tick that changes the map is a frame of the animation
+const GIF_DELAY: Duration = Duration::from_millis(50);
+const GIF_HOLD: Duration = Duration::from_secs(3);
+
 fn main() {
-    let mut map = read_input("input.txt");
+    // The --png FILE and --gif FILE flags also save the final map and the water flowing
+    let (input, mut args) = locator::locator!().split_args();
+    let png = picture::output_arg(&mut args, "--png");
+    let gif = picture::output_arg(&mut args, "--gif");
+
+    let mut map = read_input(input);
+    // Water never spreads beyond the bounds of the clay
+    let bounds = map.view_bounds();
+    let palette = Palette::new(0xc2b280)
+        .with('#', 0x6b4423)
+        .with('~', 0x1f4fd0)
+        .with('|', 0x7fbfff)
+        .with('+', 0xff0000);
+
+    let mut animation = gif.map(|path| Animation::create(path).expect("Failed to create GIF"));
+    let mut changed = true;
     for _ in 0..N_ITERATIONS {
-        map.tick();
+        if let (Some(animation), true) = (&mut animation, changed) {
+            animation.frame(&map.image(bounds, &palette), GIF_DELAY).expect("Failed to write GIF");
+        }
+        changed = map.tick();
     }
     map.print();
 
+    if let Some(mut animation) = animation {
+        animation.frame(&map.image(bounds, &palette), GIF_HOLD).expect("Failed to write GIF");
+        animation.finish().expect("Failed to write GIF");
+    }
+    if let Some(path) = png {
+        map.image(bounds, &palette).save_png(path).expect("Failed to save PNG");
+    }
+
     let mut count_at_rest = 0;
     let mut count_hypothetical = 0;
-    for y in map.ymin..=map.ymax {
-        for x in 0..WIDTH {
-            let tile = map.cells[y][x];
-            if tile == '~' {
-                count_at_rest += 1;
-            } else if tile == '|' {
-                count_hypothetical += 1;
-            }
+    for (pos, &tile) in map.cells.iter() {
+        if pos.1 < map.ymin || pos.1 > map.ymax {
+            continue;
+        }
+
+        if tile == '~' {
+            count_at_rest += 1;
+        } else if tile == '|' {
+            count_hypothetical += 1;
         }
     }
-    println!("Water can reach {} tiles", count_at_rest + count_hypothetical);
-    println!("There are {} water tiles at rest", count_at_rest);
+    println!("Part 1: Water can reach {} tiles", count_at_rest + count_hypothetical);
+    println!("Part 2: There are {} water tiles at rest", count_at_rest);
 
 }
 
-fn read_input(filename: &str) -> Map {
-    let input = fs::read_to_string(filename)
+fn read_input(input: impl Into<Input>) -> Map {
+    let input = input.into().read_to_string()
         .expect("Failed to read input");
 
     let mut clay_ranges = Vec::new();
-    let mut ymin = std::usize::MAX;
+    let mut ymin = i64::MAX;
     let mut ymax = 0;
     for line in input.lines() {
         let mut iter = line.splitn(2, ",");
@@ -45,15 +78,15 @@ fn read_input(filename: &str) -> Map {
 
         let mut iter = first.splitn(2, "=");
         let first_var = iter.next().unwrap().trim();
-        let first_value: usize = iter.next().unwrap().trim().parse().unwrap();
+        let first_value: i64 = iter.next().unwrap().trim().parse().unwrap();
 
         let mut iter = second.splitn(2, "=");
         let _second_var = iter.next().unwrap().trim();
         let second_value = iter.next().unwrap().trim();
 
         let mut iter = second_value.splitn(2, "..");
-        let second_value_start: usize = iter.next().unwrap().trim().parse().unwrap();
-        let second_value_end: usize = iter.next().unwrap().trim().parse().unwrap();
+        let second_value_start: i64 = iter.next().unwrap().trim().parse().unwrap();
+        let second_value_end: i64 = iter.next().unwrap().trim().parse().unwrap();
 
         let first_range = first_value..=first_value;
         let second_range = second_value_start..=second_value_end;
@@ -68,51 +101,56 @@ fn read_input(filename: &str) -> Map {
         }
     }
 
-    let mut cells: Vec<[char; WIDTH]> = Vec::new();
-    for _y in 0..=ymax {
-        cells.push(['.'; WIDTH]);
-    }
-
+    let mut cells = SparseGrid::new();
     for (rx, ry) in &clay_ranges {
         for y in ry.clone() {
             for x in rx.clone() {
-                cells[y][x-OFFSET] = '#';
+                cells.insert((x, y), '#');
             }
         }
     }
 
     // Water spring
-    cells[SPRING.1][SPRING.0-OFFSET] = '+';
+    cells.insert(SPRING, '+');
 
     Map { cells: cells, ymin, ymax }
 }
 
 struct Map {
-    cells: Vec<[char; WIDTH]>,
-    ymin: usize,
-    ymax: usize,
+    cells: SparseGrid<char>,
+    ymin: i64,
+    ymax: i64,
 }
 
-type Pos = (usize, usize);
+type Pos = (i64, i64);
 
 impl Map {
+    /// The part of the map worth showing
+    fn view_bounds(&self) -> Bounds {
+        // Leave a column either side so that water spilling over the edge is visible
+        let bounds = self.cells.bounds().expect("Map is empty");
+        Bounds::new((bounds.min.0 - 1, bounds.min.1), (bounds.max.0 + 1, bounds.max.1))
+    }
+
+    fn image(&self, bounds: Bounds, palette: &Palette<char>) -> Image {
+        Image::from_grid(&self.cells.crop(bounds, '.'), palette)
+    }
+
     fn print(&self) {
-        for (y, row) in self.cells.iter().enumerate() {
-            print!("{:4}: ", y);
-            for &cell in &row[..] {
-                match cell {
-                    '+' => print!("\x1b[31m{}\x1b[0m", cell),
-                    '~' => print!("\x1b[34m{}\x1b[0m", cell),
-                    '|' => print!("\x1b[36m{}\x1b[0m", cell),
-                    _ => print!("{}", cell),
-                }
-            }
-            println!();
+        let palette = terminal::Palette::new()
+            .with('+', Style::fg(Colour::Red))
+            .with('~', Style::fg(Colour::Blue))
+            .with('|', Style::fg(Colour::Cyan));
+        let bounds = self.view_bounds();
+        for (y, row) in (bounds.min.1..).zip(self.cells.crop(bounds, '.').rows()) {
+            println!("{:4}: {}", y, palette.paint(&row.iter().collect::<String>()));
         }
         println!();
     }
 
-    fn tick(&mut self) {
+    /// Let the water flow a little further, returning whether anything changed
+    fn tick(&mut self) -> bool {
+        let mut changed = false;
         let mut seen = HashSet::new();
         let mut edge = VecDeque::new();
 
@@ -121,7 +159,7 @@ impl Map {
         while let Some(pos) = edge.pop_front() {
             seen.insert(pos);
             if self.get(pos) == '.' {
-                self.set(pos, '|');
+                changed |= self.set(pos, '|');
             }
 
             let new_tiles: Vec<Pos> = self.open_tiles(pos).into_iter().filter(|&p| ! seen.contains(&p)).collect();
@@ -137,20 +175,23 @@ impl Map {
                 }
                 if self.get((left, pos.1)) == '#' && self.get((right, pos.1)) == '#' {
                     for x in left+1..right {
-                        self.set((x, pos.1), '~')
+                        changed |= self.set((x, pos.1), '~');
                     }
                 }
             }
             edge.extend(new_tiles);
         }
+
+        changed
     }
 
-    fn set(&mut self, pos: Pos, tile: char) {
-        self.cells[pos.1][pos.0 - OFFSET] = tile;
+    /// Set a tile, returning whether it changed
+    fn set(&mut self, pos: Pos, tile: char) -> bool {
+        self.cells.insert(pos, tile) != Some(tile)
     }
 
     fn get(&self, pos: Pos) -> char {
-        self.cells[pos.1][pos.0 - OFFSET]
+        self.cells.get_or(pos, '.')
     }
 
     fn open_tiles(&self, pos: Pos) -> Vec<Pos> {
@@ -158,7 +199,7 @@ impl Map {
         let below = below(pos);
         if self.valid(below) && ! occupied(self.get(below)) {
             result.push(below);
-        } else if pos.1 < self.cells.len() - 1 {
+        } else if pos.1 < self.ymax {
             let left = left(pos);
             if self.valid(left) && ! occupied(self.get(left)) {
                 result.push(left);
@@ -173,7 +214,7 @@ impl Map {
     }
 
     fn valid(&self, pos: Pos) -> bool {
-        OFFSET <= pos.0 && pos.0 < WIDTH + OFFSET && pos.1 < self.cells.len()
+        pos.1 <= self.ymax
     }
 }
 
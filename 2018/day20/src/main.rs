@@ -1,10 +1,10 @@
-use std::fs;
+use locator::Input;
 
 const WIDTH: usize = 105;
 const HEIGHT: usize = 105;
 
 fn main() {
-    let input = read_input("input.txt");
+    let input = read_input(locator::input!());
     let mut map = Map::new();
 
     let mut stack = Vec::new();
@@ -39,7 +39,7 @@ fn main() {
         }
     }
     map.print();
-    println!("Largest number of doors required to reach a room is {}", n_max);
+    println!("Part 1: Largest number of doors required to reach a room is {}", n_max);
 
     let mut count = 0;
     for row in &map.tiles[..] {
@@ -49,11 +49,11 @@ fn main() {
             }
         }
     }
-    println!("{} rooms have a shortest path that passes through at least 1000 doors", count);
+    println!("Part 2: {} rooms have a shortest path that passes through at least 1000 doors", count);
 }
 
-fn read_input(filename: &str) -> Vec<char> {
-    let input = fs::read_to_string(filename)
+fn read_input(input: impl Into<Input>) -> Vec<char> {
+    let input = input.into().read_to_string()
         .expect("Failed to read input");
 
     input.trim().chars().collect()
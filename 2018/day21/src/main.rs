@@ -1,10 +1,11 @@
 use std::collections::HashSet;
-use std::fs;
+use parsing::{Fields, ParseError, Span};
 
 const DEBUG: bool = false;
+const NUM_REGISTERS: usize = 6;
 
 fn main() {
-    let input = read_input("input.txt");
+    let input = parsing::read_input(locator::input!(), parse_input);
 
     // Part 1
     let mut executor = Executor::new();
@@ -13,7 +14,7 @@ fn main() {
     let mut executor = Executor::new();
     executor.reg[0] = key;
     executor.run(&input, |_,_| false);
-    println!("The lowest non-negative integer value for register 0 that causes a halt is {}", key);
+    println!("Part 1: The lowest non-negative integer value for register 0 that causes a halt is {}", key);
 
     // Part 2
     let mut last = 0;
@@ -29,30 +30,49 @@ fn main() {
         }
         false
     });  // Break at halt condition
-    println!("The lowest non-negative integer value for register 0 that causes a halt after the most instructions is {}", last);
+    println!("Part 2: The lowest non-negative integer value for register 0 that causes a halt after the most instructions is {}", last);
 }
 
-fn read_input(filename: &str) -> Program {
-    let input = fs::read_to_string(filename)
-        .expect("Failed to read input");
-
+fn parse_input(input: &str) -> Result<Program, ParseError> {
     let mut ip_reg = None;
     let mut instructions = Vec::new();
-    for line in input.lines() {
-        if line.starts_with("#ip") {
-            let reg: usize = line[3..].trim().parse().unwrap();
-            ip_reg = Some(reg);
+    for line in Span::new(input).lines() {
+        let mut fields = line.fields();
+        if line.as_str().starts_with("#ip") {
+            fields.expect("#ip")?;
+            ip_reg = Some(register(&mut fields, "instruction pointer register")?);
+            fields.end()?;
         } else {
-            let mut iter = line.split_whitespace();
-            let opcode = Opcode::from_str(&iter.next().unwrap());
-            let a: usize = iter.next().unwrap().parse().unwrap();
-            let b: usize = iter.next().unwrap().parse().unwrap();
-            let c: usize = iter.next().unwrap().parse().unwrap();
+            let opcode = fields.next("opcode")?;
+            let opcode = Opcode::from_str(opcode.as_str()).ok_or_else(|| opcode.error("Unknown opcode"))?;
+            let (a_register, b_register) = opcode.register_operands();
+            let a = operand(&mut fields, "A", a_register)?;
+            let b = operand(&mut fields, "B", b_register)?;
+            let c = register(&mut fields, "C")?;
+            fields.end()?;
             instructions.push((opcode, a, b, c));
         }
     }
 
-    Program { ip_reg, instructions }
+    Ok(Program { ip_reg, instructions })
+}
+
+/// Parse the next field as a register number
+fn register(fields: &mut Fields, what: &str) -> Result<usize, ParseError> {
+    let field = fields.next(what)?;
+    match field.parse(what)? {
+        reg if reg < NUM_REGISTERS => Ok(reg),
+        _ => Err(field.error(format!("Invalid {} (there are {} registers)", what, NUM_REGISTERS))),
+    }
+}
+
+/// Parse the next field as a register number, or as a value if it isn't used as a register
+fn operand(fields: &mut Fields, what: &str, is_register: bool) -> Result<usize, ParseError> {
+    if is_register {
+        register(fields, what)
+    } else {
+        fields.parse(what)
+    }
 }
 
 struct Program {
@@ -62,7 +82,7 @@ struct Program {
 
 struct Executor {
     ip: usize,
-    reg: [usize; 6]
+    reg: Registers,
 }
 
 impl Executor {
@@ -113,13 +133,13 @@ pub enum Opcode {
     EQRR,
 }
 
-type Registers = [usize; 6];
+type Registers = [usize; NUM_REGISTERS];
 type Instruction = (Opcode, usize, usize, usize);
 
 impl Opcode {
-    fn from_str(opcode: &str) -> Opcode {
+    fn from_str(opcode: &str) -> Option<Opcode> {
         use self::Opcode::*;
-        match opcode {
+        Some(match opcode {
             "addr" => ADDR,
             "addi" => ADDI,
             "mulr" => MULR,
@@ -136,7 +156,18 @@ impl Opcode {
             "eqir" => EQIR,
             "eqri" => EQRI,
             "eqrr" => EQRR,
-            _ => panic!("Unknown opcode {:?}", opcode),
+            _ => return None,
+        })
+    }
+
+    /// Whether the A and B operands are registers (rather than values, or unused)
+    fn register_operands(&self) -> (bool, bool) {
+        use self::Opcode::*;
+        match self {
+            ADDR | MULR | BANR | BORR | GTRR | EQRR => (true, true),
+            ADDI | MULI | BANI | BORI | GTRI | EQRI | SETR => (true, false),
+            GTIR | EQIR => (false, true),
+            SETI => (false, false),
         }
     }
 }
@@ -1,5 +1,4 @@
 use std::collections::HashMap;
-use std::collections::HashSet;
 
 const WIDTH: usize = 30;
 const ORIGIN: Pos = (0, 0);
@@ -15,48 +14,25 @@ const TARGET: Pos = (12, 763);
 
 fn main() {
     let map = Map::new();
-    println!("Risk level: {}", map.risk_level(ORIGIN, TARGET));
+    println!("Part 1: Risk level: {}", map.risk_level(ORIGIN, TARGET));
 
     // Part 2
     plan(&map);
 }
 
 fn plan(map: &Map) {
-    let mut edge: HashSet<(Pos, Equip)> = HashSet::new();
-    let mut came_from: HashMap<(Pos, Equip), (Pos, Equip)> = HashMap::new();
-    let mut cost_so_far: HashMap<(Pos, Equip), u32> = HashMap::new();
-
     let start = (ORIGIN, Equip::Torch);
     let goal = (TARGET, Equip::Torch);
-    cost_so_far.insert(start, 0);
-
-    let mut estimated_cost: HashMap<(Pos, Equip), u32> = HashMap::new();
-    estimated_cost.insert(start, distance_heuristic(start, goal));
-
-    edge.insert(start);
-    while ! edge.is_empty() {
-        let mut open: Vec<_> = edge.iter().collect();
-        open.sort_by_key(|pe| estimated_cost.get(pe).unwrap());
-        let &&current = open.first().unwrap();
-
-        if current == goal {
-            // Found!
-            println!("Total cost: {}", *cost_so_far.get(&current).unwrap());
-            return;
-        }
-
-        edge.remove(&current);
 
-        for adj in map.adjacent(current.0, current.1) {
-            let new_cost = *cost_so_far.get(&current).unwrap() + distance_heuristic(current, adj);
+    let neighbours = |&current: &(Pos, Equip)| {
+        map.adjacent(current.0, current.1).into_iter()
+            .map(move |adj| (adj, distance_heuristic(current, adj)))
+    };
+    let search = pathfinding::astar(&neighbours, vec![start], |&s| s == goal, |&s| distance_heuristic(s, goal));
 
-            if ! cost_so_far.contains_key(&adj) || new_cost < *cost_so_far.get(&adj).unwrap() {
-                cost_so_far.insert(adj, new_cost);
-                estimated_cost.insert(adj, new_cost + distance_heuristic(adj, goal));
-                edge.insert(adj);
-                came_from.insert(adj, current);
-            }
-        }
+    if let Some(cost) = search.goal_cost() {
+        // Found!
+        println!("Part 2: Total cost: {}", cost);
     }
 }
 
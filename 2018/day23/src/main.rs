@@ -1,20 +1,21 @@
 use std::collections::HashSet;
-use std::fs;
+use geometry::Point;
+use locator::Input;
 
-const ORIGIN: Pos = [0; 3];
+const ORIGIN: Pos = Point::origin();
 
 fn main() {
-    let mut nanobots = read_input("input.txt");
+    let mut nanobots = read_input(locator::input!());
     nanobots.sort_by_key(|n| n.r);
 
     // Part 1
     let strongest = nanobots.last().unwrap();
     let in_range = nanobots.iter().filter(|n| strongest.in_range(n)).count();
-    println!("Strongest is {:?} with {} in range", strongest, in_range);
+    println!("Part 1: Strongest is {:?} with {} in range", strongest, in_range);
 
     // Part 2
-    let n = i32::pow(2, 30);
-    let cube = Cube::new([-n, -n, -n], u32::pow(2, 31));
+    let n = i64::pow(2, 30);
+    let cube = Cube::new(Point::new([-n, -n, -n]), u32::pow(2, 31));
     search(cube, &nanobots);
 }
 
@@ -51,18 +52,18 @@ fn search(cube: Cube, nanobots: &Vec<Nanobot>) {
         }
     }
 
-    let result: Vec<_> = result.into_iter().map(|cube| (distance(ORIGIN, cube), cube)).collect();
+    let result: Vec<_> = result.into_iter().map(|cube| (ORIGIN.manhattan_distance(cube), cube)).collect();
     let &(best_distance, best_cube) = result.first().unwrap();
 
-    println!("Best position is {:?} with {} nanobots in range (distance-from-origin: {})", best_cube, max_count, best_distance);
+    println!("Part 2: Best position is {:?} with {} nanobots in range (distance-from-origin: {})", best_cube, max_count, best_distance);
 }
 
 fn octants(cube: Cube) -> Vec<Cube> {
     let mut result = Vec::new();
-    for &x in &[cube.pos[0], cube.pos[0] + (cube.width / 2) as i32] {
-        for &y in &[cube.pos[1], cube.pos[1] + (cube.width / 2) as i32] {
-            for &z in &[cube.pos[2], cube.pos[2] + (cube.width / 2) as i32] {
-                result.push(Cube::new([x, y, z], cube.width / 2));
+    for &x in &[cube.pos[0], cube.pos[0] + (cube.width / 2) as i64] {
+        for &y in &[cube.pos[1], cube.pos[1] + (cube.width / 2) as i64] {
+            for &z in &[cube.pos[2], cube.pos[2] + (cube.width / 2) as i64] {
+                result.push(Cube::new(Point::new([x, y, z]), cube.width / 2));
             }
         }
     }
@@ -73,9 +74,9 @@ fn octants(cube: Cube) -> Vec<Cube> {
 fn in_range_within_interval(nanobot: &Nanobot, cube: Cube) -> bool {
     let mut distance = 0;
     for d in 0..3 {
-        let min = cube.pos[d] as i64;
+        let min = cube.pos[d];
         let max = min + cube.width as i64;
-        let pos = nanobot.pos[d] as i64;
+        let pos = nanobot.pos[d];
         if pos < min {
             distance += min - pos;
         } else if pos >= max {
@@ -83,16 +84,16 @@ fn in_range_within_interval(nanobot: &Nanobot, cube: Cube) -> bool {
         }
     }
 
-    distance <= nanobot.r as i64
+    distance <= nanobot.r
 }
 
-fn read_input(filename: &str) -> Vec<Nanobot> {
-    let input = fs::read_to_string(filename)
+fn read_input(input: impl Into<Input>) -> Vec<Nanobot> {
+    let input = input.into().read_to_string()
         .expect("Failed to read input");
 
     let mut result = Vec::new();
     for line in input.lines() {
-        let mut pos = [0; 3];
+        let mut pos = Point::origin();
         let mut r = 0;
         for col in line.split_whitespace() {
             let mut iter = col.split("=");
@@ -102,11 +103,11 @@ fn read_input(filename: &str) -> Vec<Nanobot> {
             match key {
                 "pos" => {
                     let mut iter = value[1..value.len()-2].split(",");
-                    pos = [
+                    pos = Point::new([
                         parse_int(iter.next().unwrap()),
                         parse_int(iter.next().unwrap()),
                         parse_int(iter.next().unwrap()),
-                    ];
+                    ]);
                 },
                 "r" => r = parse_int(value),
                 _ => (),
@@ -119,21 +120,21 @@ fn read_input(filename: &str) -> Vec<Nanobot> {
     result
 }
 
-fn parse_int(s: &str) -> i32 {
+fn parse_int(s: &str) -> i64 {
     s.parse().expect("Failed to parse int")
 }
 
-type Pos = [i32; 3];
+type Pos = Point<3>;
 
 #[derive(Debug, Copy, Clone)]
 struct Nanobot {
     pos: Pos,
-    r: i32,
+    r: i64,
 }
 
 impl Nanobot {
-    fn distance(&self, pos: Pos) -> i32 {
-        distance(self.pos, pos)
+    fn distance(&self, pos: Pos) -> i64 {
+        self.pos.manhattan_distance(pos)
     }
 
     fn in_range(&self, other: &Nanobot) -> bool {
@@ -152,12 +153,3 @@ impl Cube {
         Cube { pos, width }
     }
 }
-
-fn distance(pos1: Pos, pos2: Pos) -> i32 {
-    let mut result = 0;
-    for n in 0..pos1.len() {
-        result += (pos1[n] - pos2[n]).abs()
-    }
-
-    result
-}
@@ -1,16 +1,17 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::fs;
 use regex::Regex;
+use parsing::{ParseError, Span};
 
 fn main() {
-    part1();
-    part2();
+    let world = parsing::read_input(locator::input!(), World::parse);
+    part1(&world);
+    part2(&world);
 }
 
-fn part1() {
+fn part1(world: &World) {
     println!("PART 1");
-    let mut world = World::from_file("input.txt");
+    let mut world = world.clone();
 
     loop {
         if world.n_immune() == 0 || world.n_infection() == 0 {
@@ -19,13 +20,14 @@ fn part1() {
         world.fight(true);
     }
     world.print_summary();
+
+    let units = world.n_units(Team::ImmuneSystem) + world.n_units(Team::Infection);
+    println!("Part 1: The winning army has {} units left", units);
 }
 
-fn part2() {
+fn part2(original_world: &World) {
     println!("PART 2");
 
-    let original_world = World::from_file("input.txt");
-
     for boost in 1.. {
         let mut world = original_world.clone();
 
@@ -47,35 +49,38 @@ fn part2() {
 
         if world.everyone_alive().iter().filter(|g| g.team == Team::Infection).count() == 0 {
             // Immune system wins
+            println!("Part 2: The immune system has {} units left", world.n_units(Team::ImmuneSystem));
             break;
         }
     }
 }
 
-fn parse_group(team: Team, id: u32, group: &str) -> Group {
+fn parse_group(team: Team, id: u32, group: Span) -> Result<Group, ParseError> {
     let re = Regex::new(r"^(?P<n>\d+) units each with (?P<hp>\d+) hit points (?:\((?P<w1>\S+) to (?P<t1>[^);]+)(?:; (?P<w2>\S+) to (?P<t2>[^)]+))?\) )?with an attack that does (?P<ap>\d+) (?P<type>\S+) damage at initiative (?P<init>\d+)$").unwrap();
-    let caps = re.captures(group).unwrap();
+    let caps = re.captures(group.as_str()).ok_or_else(|| group.error("Expected a group of units"))?;
+    let capture = |name: &str| caps.name(name).map(|m| group.slice(m.range()));
+    let required = |name: &str| capture(name).expect("Required by the pattern");
 
-    let n: i32 = caps["n"].parse().unwrap();
-    let hp: i32 = caps["hp"].parse().unwrap();
-    let ap: i32 = caps["ap"].parse().unwrap();
-    let initiative: i32 = caps["init"].parse().unwrap();
-    let attack_type = Type::from_str(&caps["type"]).unwrap();
+    let n = required("n").parse("number of units")?;
+    let hp = required("hp").parse("hit points")?;
+    let ap = required("ap").parse("attack damage")?;
+    let initiative = required("init").parse("initiative")?;
+    let attack_type = Type::parse(required("type"))?;
 
     let mut weakness= HashSet::new();
     let mut immunity = HashSet::new();
     for &(wn, tn) in &[("w1", "t1"), ("w2", "t2")] {
-        if let Some(w) = caps.name(wn) {
-            let types = caps[tn].split(", ").map(|s| Type::from_str(s).unwrap());
+        if let (Some(w), Some(types)) = (capture(wn), capture(tn)) {
+            let types = types.split(",").map(Type::parse).collect::<Result<Vec<_>, _>>()?;
             match w.as_str() {
                 "weak" => weakness.extend(types),
                 "immune" => immunity.extend(types),
-                adj => panic!("Unknown adjective: {}", adj)
+                _ => return Err(w.error("Expected \"weak\" or \"immune\"")),
             }
         }
     }
 
-    Group { team, id, n, hp, ap, initiative, attack_type, weakness, immunity }
+    Ok(Group { team, id, n, hp, ap, initiative, attack_type, weakness, immunity })
 }
 
 #[derive(Clone, Debug)]
@@ -84,30 +89,21 @@ struct World {
 }
 
 impl World {
-    fn from_file(filename: &str) -> World {
-        let input = fs::read_to_string(filename)
-            .expect("Failed to read input");
-
+    fn parse(input: &str) -> Result<World, ParseError> {
         let mut groups = HashMap::new();
         let mut id_for_teams = HashMap::new();
-        for team in input.split("\n\n") {
-            let mut name = "";
-            for line in team.lines() {
-                if name.is_empty() {
-                    name = line.trim_end_matches(":");
-                    continue
-                }
-
-                let team = Team::from_str(name);
+        for army in Span::new(input).records() {
+            let mut lines = army.lines();
+            let team = Team::parse(lines.next().unwrap())?;
+            for line in lines {
                 let id = id_for_teams.entry(team).or_insert(1);
 
-                groups.insert((team, *id), parse_group(team, *id, line));
+                groups.insert((team, *id), parse_group(team, *id, line)?);
                 *id += 1;
             }
-
         }
 
-        World { groups }
+        Ok(World { groups })
     }
 
     fn everyone_alive(&self) -> Vec<&Group> {
@@ -119,7 +115,12 @@ impl World {
     }
 
     fn n_infection(&self) -> usize {
-        self.everyone_alive().iter().filter(|g| g.team == Team::ImmuneSystem).count()
+        self.everyone_alive().iter().filter(|g| g.team == Team::Infection).count()
+    }
+
+    /// Total units left in a team's groups
+    fn n_units(&self, team: Team) -> i32 {
+        self.everyone_alive().iter().filter(|g| g.team == team).map(|g| g.n).sum()
     }
 
     fn print_summary(&self) {
@@ -223,11 +224,12 @@ enum Team {
 }
 
 impl Team {
-    fn from_str(name: &str) -> Team {
-        match name {
-            "Immune System" => Team::ImmuneSystem,
-            "Infection" => Team::Infection,
-            _ => panic!("Unknown team: {}", name),
+    /// A team from the heading of its army (e.g. `Infection:`)
+    fn parse(heading: Span) -> Result<Team, ParseError> {
+        match heading.as_str() {
+            "Immune System:" => Ok(Team::ImmuneSystem),
+            "Infection:" => Ok(Team::Infection),
+            _ => Err(heading.error("Unknown team")),
         }
     }
 }
@@ -242,14 +244,14 @@ enum Type {
 }
 
 impl Type {
-    fn from_str(name: &str) -> Option<Type> {
-        match name {
-            "slashing" => Some(Type::Slashing),
-            "bludgeoning" => Some(Type::Bludgeoning),
-            "cold" => Some(Type::Cold),
-            "fire" => Some(Type::Fire),
-            "radiation" => Some(Type::Radiation),
-            _ => panic!("Unknown type: {}", name)
+    fn parse(name: Span) -> Result<Type, ParseError> {
+        match name.as_str() {
+            "slashing" => Ok(Type::Slashing),
+            "bludgeoning" => Ok(Type::Bludgeoning),
+            "cold" => Ok(Type::Cold),
+            "fire" => Ok(Type::Fire),
+            "radiation" => Ok(Type::Radiation),
+            _ => Err(name.error("Unknown attack type")),
         }
     }
 }
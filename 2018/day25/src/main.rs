@@ -1,11 +1,12 @@
 use std::collections::HashSet;
-use std::fs;
+use geometry::Point;
+use locator::Input;
 
-type Pos = [i32; 4];
+type Pos = Point<4>;
 type Constellation = HashSet<Pos>;
 
 fn main() {
-    let input = parse_input("input.txt");
+    let input = parse_input(locator::input!());
 
     let mut constellations: Vec<Constellation> = Vec::new();
     for &point in &input {
@@ -35,36 +36,27 @@ fn main() {
         constellations.push(constellation);
     }
 
-    println!("Number of constellations: {:?}", constellations.len());
+    println!("Part 1: Number of constellations: {:?}", constellations.len());
 }
 
-fn parse_input(filename: &str) -> Vec<Pos> {
-    let input = fs::read_to_string(filename)
+fn parse_input(input: impl Into<Input>) -> Vec<Pos> {
+    let input = input.into().read_to_string()
         .expect("Failed to read file");
 
     let mut result = Vec::new();
     for line in input.lines() {
         let mut iter = line.split(",");
-        result.push([
+        result.push(Point::new([
             iter.next().unwrap().parse().unwrap(),
             iter.next().unwrap().parse().unwrap(),
             iter.next().unwrap().parse().unwrap(),
             iter.next().unwrap().parse().unwrap(),
-        ]);
-    }
-
-    result
-}
-
-fn distance(pos1: Pos, pos2: Pos) -> i32{
-    let mut result = 0;
-    for d in 0..pos1.len() {
-        result += (pos1[d] - pos2[d]).abs();
+        ]));
     }
 
     result
 }
 
 fn all_within_range(points: &Vec<Pos>, pos: Pos) -> Vec<Pos> {
-    points.iter().map(|&p| p).filter(|&p| distance(pos, p) <= 3).collect()
+    points.iter().map(|&p| p).filter(|&p| pos.manhattan_distance(p) <= 3).collect()
 }
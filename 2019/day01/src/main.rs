@@ -1,8 +1,7 @@
-use std::path::Path;
-use std::fs;
+use locator::Input;
 
 fn main() {
-    let input = read_input("input.txt");
+    let input = read_input(locator::input!());
 
     // Part 1
     assert_eq!(2, fuel1(12));
@@ -22,8 +21,8 @@ fn main() {
     println!("Part 2: Sum of fuel requirements: {}", total_fuel2);
 }
 
-fn read_input<T: AsRef<Path>>(path: T) -> Vec<u32> {
-    let contents = fs::read_to_string(path).expect("Failed to read input");
+fn read_input(input: impl Into<Input>) -> Vec<u32> {
+    let contents = input.into().read_to_string().expect("Failed to read input");
     contents.lines().map(|line| line.parse::<u32>().expect("Failed to parse input")).collect()
 }
 
@@ -1,12 +1,11 @@
-use std::fs;
-use std::path::Path;
+use locator::Input;
 
 const OP_ADD: usize = 1;  // r[x3] = r[x1] + r[x2]
 const OP_MUL: usize = 2;  // r[x3] = r[x1] * r[x2]
 const OP_HALT: usize = 99;
 
 fn main() {
-    let input = read_input("input.txt");
+    let input = read_input(locator::input!());
 
     // Part 1
     assert_eq!(3500, test(&vec![1,9,10,3,2,3,11,0,99,30,40,50]));
@@ -35,8 +34,8 @@ fn main() {
     }
 }
 
-fn read_input<T: AsRef<Path>>(path: T) -> Vec<usize> {
-    let contents = fs::read_to_string(path).expect("Failed to read input");
+fn read_input(input: impl Into<Input>) -> Vec<usize> {
+    let contents = input.into().read_to_string().expect("Failed to read input");
     contents.trim().split(",").map(|line| line.parse::<usize>().expect("Failed to parse input")).collect()
 }
 
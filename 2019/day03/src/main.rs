@@ -1,11 +1,12 @@
-use std::path::Path;
-use std::{fs, ops, fmt};
+use std::fmt;
 use std::collections::HashSet;
+use geometry::{Point, Vector};
+use locator::Input;
 
-const ORIGIN: Pos = Pos::new(0, 0);
+const ORIGIN: Pos = Point::origin();
 
 fn main() {
-    let input = read_input("input.txt");
+    let input = read_input(locator::input!());
     for wire in &input {
         println!("{}", wire.iter().map(|m| m.to_string()).collect::<Vec<_>>().join(","));
     }
@@ -31,7 +32,7 @@ fn main() {
     }
 
     let closest_intersection = intersections.iter()
-        .map(|&p| (p, p.distance(ORIGIN)))
+        .map(|&p| (p, p.manhattan_distance(ORIGIN)))
         .min_by_key(|&(_, distance)| distance)
         .expect("Expected non-zero intersections");
     println!("Part 1: Closest intersection: {} (distance: {})", closest_intersection.0, closest_intersection.1);
@@ -55,8 +56,8 @@ fn main() {
 }
 
 /// Read a list of move-lists from a file.
-fn read_input<T: AsRef<Path>>(path: T) -> Vec<Vec<Move>> {
-    let input = fs::read_to_string(path).expect("Failed to read input");
+fn read_input(input: impl Into<Input>) -> Vec<Vec<Move>> {
+    let input = input.into().read_to_string().expect("Failed to read input");
     let mut result = Vec::new();
     for line in input.lines() {
         let moves: Vec<Move> = line.trim().split(',').filter_map(Move::parse).collect();
@@ -82,41 +83,7 @@ fn walk(movelist: &[Move]) -> Vec<Pos> {
     path
 }
 
-#[derive(Hash,Eq,PartialEq,Copy,Clone)]
-struct Pos {
-    x: i32,
-    y: i32,
-}
-
-impl Pos {
-    const fn new(x: i32, y: i32) -> Pos {
-        Pos { x, y }
-    }
-
-    fn distance(self, p: Pos) -> i32 {
-        (self.x - p.x).abs() + (self.y - p.y).abs()
-    }
-}
-
-impl ops::Add<Pos> for Pos {
-    type Output = Pos;
-
-    fn add(self, rhs: Pos) -> Self::Output {
-        Pos { x: self.x + rhs.x, y: self.y + rhs.y }
-    }
-}
-
-impl ops::AddAssign for Pos {
-    fn add_assign(&mut self, rhs: Self) {
-        *self = *self + rhs;
-    }
-}
-
-impl fmt::Display for Pos {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}, {}", self.x, self.y)
-    }
-}
+type Pos = Point<2>;
 
 #[derive(Eq,PartialEq,Copy,Clone)]
 enum Direction {
@@ -137,12 +104,12 @@ impl Direction {
         }
     }
 
-    fn unit(self) -> Pos {
+    fn unit(self) -> Vector<2> {
         match self {
-            Direction::Up => Pos::new(0, 1),
-            Direction::Down => Pos::new(0, -1),
-            Direction::Left => Pos::new(-1, 0),
-            Direction::Right => Pos::new(1, 0),
+            Direction::Up => Vector::new([0, 1]),
+            Direction::Down => Vector::new([0, -1]),
+            Direction::Left => Vector::new([-1, 0]),
+            Direction::Right => Vector::new([1, 0]),
         }
     }
 
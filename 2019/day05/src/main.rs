@@ -1,5 +1,4 @@
-use std::fs;
-use std::path::Path;
+use locator::Input;
 
 type Word = i32;
 
@@ -19,7 +18,7 @@ const OP_HALT: Word = 99;  // ...but don't catch fire
 type Program = Vec<Word>;
 
 fn main() {
-    let input = read_input("input.txt");
+    let input = read_input(locator::input!());
 
     // Testing
     println!("== Testing ==");
@@ -29,15 +28,15 @@ fn main() {
 
     // Part 1
     println!("== Part 1 ==");
-    println!("STDOUT: {:?}", run(&input, vec![1]));
+    println!("Part 1: {:?}", run(&input, vec![1]));
 
     // Part 2
     println!("== Part 2 ==");
-    println!("STDOUT: {:?}", run(&input, vec![5]));
+    println!("Part 2: {:?}", run(&input, vec![5]));
 }
 
-fn read_input<T: AsRef<Path>>(path: T) -> Program {
-    let contents = fs::read_to_string(path).expect("Failed to read input");
+fn read_input(input: impl Into<Input>) -> Program {
+    let contents = input.into().read_to_string().expect("Failed to read input");
     contents.trim().split(",").map(|line| line.parse::<Word>().expect("Failed to parse input")).collect()
 }
 
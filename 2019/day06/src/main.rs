@@ -1,6 +1,5 @@
-use std::fs;
-use std::path::Path;
 use std::collections::HashMap;
+use locator::Input;
 
 type Obj = String;
 type ObjRef<'a> = &'a str;
@@ -12,7 +11,7 @@ const SANTA: ObjRef = "SAN";
 
 fn main() {
     // Vector of (obj, orbiting_obj)
-    let input = read_input("input.txt");
+    let input = read_input(locator::input!());
 
     // Part 1
     // Maps object to the object it's orbiting (so we need to flip the ordering)
@@ -48,9 +47,9 @@ fn main() {
     println!("Part 2: Orbital transfers required {}", transfers);
 }
 
-fn read_input<T: AsRef<Path>>(path: T) -> Vec<(Obj, Obj)> {
+fn read_input(input: impl Into<Input>) -> Vec<(Obj, Obj)> {
     let mut orbits = Vec::new();
-    let contents = fs::read_to_string(path).expect("Failed to read input");
+    let contents = input.into().read_to_string().expect("Failed to read input");
     for line in contents.lines() {
         let mut vals = line.split(')');
         let obj = Obj::from(vals.next().expect("Missing value"));
@@ -1,9 +1,8 @@
 #![allow(clippy::unreadable_literal)]
 
-use std::fs;
-use std::path::Path;
 use std::collections::VecDeque;
 use std::convert::TryInto;
+use locator::Input;
 
 type Word = i32;
 
@@ -31,7 +30,7 @@ impl Program {
 }
 
 fn main() {
-    let input = read_input("input.txt");
+    let input = read_input(locator::input!());
 
     // Part 1
     assert_eq!(43210,
@@ -66,8 +65,8 @@ fn main() {
     println!("Part 2: Max thrust is {} ({:?})", max_thrust, phase);
 }
 
-fn read_input<T: AsRef<Path>>(path: T) -> Program {
-    let contents = fs::read_to_string(path).expect("Failed to read input");
+fn read_input(input: impl Into<Input>) -> Program {
+    let contents = input.into().read_to_string().expect("Failed to read input");
     let instructions = contents.trim().split(',').map(|line| line.parse::<Word>().expect("Failed to parse input")).collect();
 
     Program(instructions)
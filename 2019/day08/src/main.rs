@@ -1,11 +1,22 @@
-use std::fs;
-use std::path::Path;
 use std::collections::HashMap;
+use grid::Grid;
+use locator::Input;
+use picture::{Image, Palette};
 
 type Pixel = u8;
 
+const BLACK: Pixel = 0;
+const WHITE: Pixel = 1;
+const TRANSPARENT: Pixel = 2;
+
+// Pixels in a PNG for each pixel of the image
+const PNG_SCALE: usize = 10;
+
 fn main() {
-    let input = read_input("input.txt");
+    // The --png FILE flag also saves the decoded image
+    let (input, mut args) = locator::locator!().split_args();
+    let png = picture::output_arg(&mut args, "--png");
+    let input = read_input(input);
     let layers = split_layers(&input, 25, 6);
     let mut counts = count_digits(&layers);
 
@@ -16,12 +27,19 @@ fn main() {
     println!("Part 1: Number of 1 digits multiplied by number of 2 digits: {}", checksum);
 
     // Part 2
-    println!("Part 2:");
-    draw(&layers);
+    let image = decode(&layers);
+    let message = ocr::read(&image, |&pixel| pixel == WHITE);
+    println!("Part 2: Message: {}", message.as_deref().unwrap_or("(unrecognised)"));
+    draw(&image);
+
+    if let Some(path) = png {
+        let palette = Palette::new(0x000000).with(WHITE, 0xffffff);
+        Image::from_grid(&image, &palette).scale(PNG_SCALE).save_png(path).expect("Failed to save PNG");
+    }
 }
 
-fn read_input<T: AsRef<Path>>(path: T) -> Vec<Pixel> {
-    let contents = fs::read_to_string(path).expect("Failed to read input");
+fn read_input(input: impl Into<Input>) -> Vec<Pixel> {
+    let contents = input.into().read_to_string().expect("Failed to read input");
     contents.trim().chars().map(|c| c.to_digit(10).expect("Not a digit") as Pixel).collect()
 }
 
@@ -60,25 +78,34 @@ fn count_digits(layers: &[Pixmap]) -> Vec<HashMap<Pixel, u32>> {
     }).collect()
 }
 
-fn draw(layers: &[Pixmap]) {
+/// Stack the layers, with the first layer on top
+fn decode(layers: &[Pixmap]) -> Grid<Pixel> {
     let nlayers = layers.len();
     let height = layers[0].height();
     let width = layers[0].width();
 
-    for y in 0..height {
-        let mut line = String::new();
-        for x in 0..width {
-            let mut pixel = 'X';
+    Grid::from_rows((0..height).map(|y| {
+        (0..width).map(|x| {
+            let mut pixel = TRANSPARENT;
             for l in (0..nlayers).rev() {
                 match layers[l].pixel(x, y) {
-                    0 => pixel = ' ',  // Black
-                    1 => pixel = '█',  // White
-                    2 => (),  // Transparent
-                    pixel => panic!("Unknown pixel {}", pixel),
+                    TRANSPARENT => (),
+                    p @ (BLACK | WHITE) => pixel = p,
+                    p => panic!("Unknown pixel {}", p),
                 }
             }
-            line.push(pixel);
-        }
+            pixel
+        }).collect()
+    }))
+}
+
+fn draw(image: &Grid<Pixel>) {
+    for row in image.rows() {
+        let line: String = row.iter().map(|&pixel| match pixel {
+            BLACK => ' ',
+            WHITE => '█',
+            _ => 'X',
+        }).collect();
         println!("{}", line);
     }
 }
@@ -3,13 +3,13 @@ set -e
 
 cd "$(dirname "$0")"
 BASEDIR="${PWD}"
-PROGRAM="${BASEDIR}/input.txt"
+# Inputs may be kept outside the repository (see lib/locator)
+INPUT_DIR="${AOC_INPUT_DIR:+${AOC_INPUT_DIR}/2019/day09}"
+PROGRAM="${INPUT_DIR:-${BASEDIR}}/input.txt"
 
 function intcode() {
-    cargo run -q --release -- "$@"
+    cargo run -q --release -p intcode -- "$@"
 }
 
-cd "${BASEDIR}"/../intcode
-
 echo "Part 1: BOOST keycode is $(intcode "${PROGRAM}" <<< 1)"
 echo "Part 2: Coordinates of distress signal are $(intcode "${PROGRAM}" <<< 2)"
@@ -1,6 +1,6 @@
-use std::path::Path;
-use std::{fs, fmt};
+use std::fmt;
 use std::collections::{HashSet, HashMap};
+use locator::Input;
 
 const ASTEROID: char = '#';
 
@@ -12,7 +12,7 @@ fn main() {
     assert_eq!((Pos::new(6, 3), 41), best_position(&Map::from_file("sample3.txt").find_asteroids()));
     assert_eq!((Pos::new(11, 13), 210), best_position(&Map::from_file("sample4.txt").find_asteroids()));
 
-    let map = Map::from_file("input.txt");
+    let map = Map::from_file(locator::input!());
     let asteroids = map.find_asteroids();
     let (pos, count) = best_position(&asteroids);
 
@@ -110,8 +110,8 @@ struct Map {
 }
 
 impl Map {
-    fn from_file<T: AsRef<Path>>(path: T) -> Map {
-        let contents = fs::read_to_string(path).expect("Failed to read input");
+    fn from_file(input: impl Into<Input>) -> Map {
+        let contents = input.into().read_to_string().expect("Failed to read input");
         let mut width = None;
         let mut data = Vec::new();
         for line in contents.lines() {
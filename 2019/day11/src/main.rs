@@ -1,7 +1,10 @@
 use intcode::emulator;
 use intcode::emulator::{Word, Program, Context};
-use std::collections::{VecDeque, HashSet};
+use grid::SparseGrid;
+use picture::{Image, Palette};
+use std::collections::VecDeque;
 use std::fmt;
+use std::path::Path;
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -11,25 +14,36 @@ const WHITE: Word = 1;
 const LEFT: Word = 0;
 const RIGHT: Word = 1;
 
+// Pixels in a PNG for each panel
+const PNG_SCALE: usize = 10;
+
 fn main() {
-    let program = emulator::Program::from_file("input.txt").expect("Failed to read input");
+    // The --png FILE flag also saves the registration identifier
+    let (input, mut args) = locator::locator!().split_args();
+    let png = picture::output_arg(&mut args, "--png");
+    let program = emulator::Program::from_file(input).expect("Failed to read input");
 
     // Part 1
     println!("Part 1");
     println!("══════");
-    let map = run(&program, Pos::new(45, 75), (80, 90), false);
-    println!("Number of panels painted at least once: {}", map.painted.len());
+    let map = run(&program, Pos::new(0, 0), false);
+    println!("Part 1: Number of panels painted at least once: {}", map.panels.len());
     println!();
 
     // Part 2
     println!("Part 2");
     println!("══════");
-    let map = run(&program, Pos::new(1, 1), (80, 8), true);
+    let map = run(&program, Pos::new(0, 0), true);
     map.draw();
+    println!("Part 2: Registration identifier: {}", map.read().as_deref().unwrap_or("(unrecognised)"));
+
+    if let Some(path) = png {
+        map.save_png(path);
+    }
 }
 
-fn run(program: &Program, pos: Pos, map_size: (usize, usize), paint_white: bool) -> Map {
-    let map = Rc::new(RefCell::new(Map::new(map_size.0, map_size.1)));
+fn run(program: &Program, pos: Pos, paint_white: bool) -> Map {
+    let map = Rc::new(RefCell::new(Map::new()));
     let robot = Rc::new(RefCell::new(Robot::new(pos)));
 
     if paint_white {
@@ -67,35 +81,31 @@ fn run(program: &Program, pos: Pos, map_size: (usize, usize), paint_white: bool)
 
 #[derive(Debug)]
 struct Map {
-    width: usize,
-    height: usize,
-    grid: Vec<Word>,
-    painted: HashSet<Pos>,
+    panels: SparseGrid<Word>,
 }
 
 impl Map {
-    fn new(width: usize, height: usize) -> Map {
-        Map { width, height, grid: vec![BLACK; width * height], painted: HashSet::new() }
+    fn new() -> Map {
+        Map { panels: SparseGrid::new() }
     }
 
     fn at(&self, pos: Pos) -> Word {
-        assert!(pos.x < self.width);
-        assert!(pos.y < self.height);
-        self.grid[pos.y * self.width + pos.x]
+        self.panels.get_or((pos.x, pos.y), BLACK)
     }
 
     fn paint(&mut self, pos: Pos, color: Word) {
-        assert!(pos.x < self.width);
-        assert!(pos.y < self.height);
-        self.grid[pos.y * self.width + pos.x] = color;
-        self.painted.insert(pos);
+        self.panels.insert((pos.x, pos.y), color);
     }
 
     fn draw(&self) {
-        for y in 0..self.height {
-            for x in 0..self.width {
-            let pos = Pos::new(x, y);
-                match self.at(pos) {
+        let grid = match self.panels.to_dense(BLACK) {
+            Some(grid) => grid,
+            None => return,
+        };
+
+        for row in grid.rows() {
+            for &color in row {
+                match color {
                     BLACK => print!("░"),
                     WHITE => print!("█"),
                     _ => print!("?"),
@@ -104,6 +114,17 @@ impl Map {
             println!();
         }
     }
+
+    /// The letters painted on the hull
+    fn read(&self) -> Option<String> {
+        ocr::read(&self.panels.to_dense(BLACK)?, |&color| color == WHITE)
+    }
+
+    fn save_png(&self, path: impl AsRef<Path>) {
+        let grid = self.panels.to_dense(BLACK).expect("Nothing was painted");
+        let palette = Palette::new(0x000000).with(WHITE, 0xffffff);
+        Image::from_grid(&grid, &palette).scale(PNG_SCALE).save_png(path).expect("Failed to save PNG");
+    }
 }
 
 struct Robot {
@@ -178,12 +199,12 @@ impl Direction {
 
 #[derive(Hash, Eq, PartialEq, Copy, Clone, Debug)]
 struct Pos {
-    pub x: usize,
-    pub y: usize,
+    pub x: i64,
+    pub y: i64,
 }
 
 impl Pos {
-    fn new(x: usize, y: usize) -> Pos {
+    fn new(x: i64, y: i64) -> Pos {
         Pos { x, y }
     }
 
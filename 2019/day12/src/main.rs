@@ -1,7 +1,6 @@
-use std::path::Path;
-use std::{fs, ops};
-use std::fmt;
+use geometry::{Point, Vector};
 use std::collections::HashSet;
+use locator::Input;
 
 fn main() {
     // Tests
@@ -18,33 +17,33 @@ fn main() {
     assert_eq!(4686774924, s3.t);
 
     // Part 1
-    let mut sim1 = Simulation::new(read_input("input.txt"));
+    let mut sim1 = Simulation::new(read_input(locator::input!()));
     sim1.simulate_n_steps(1000);
     println!("Part 1: Total energy of system after 1000 steps: {}", sim1.total_energy());
 
     // Part 2
-    let mut sim2 = Simulation::new(read_input("input.txt"));
+    let mut sim2 = Simulation::new(read_input(locator::input!()));
     sim2.simulate_until_repeat();
     println!("Part 2: {}", sim2.t);
 }
 
-fn read_input<T: AsRef<Path>>(path: T) -> Vec<Moon> {
+fn read_input(input: impl Into<Input>) -> Vec<Moon> {
     let mut coords = Vec::new();
-    let contents = fs::read_to_string(path).expect("Failed to read input");
+    let contents = input.into().read_to_string().expect("Failed to read input");
     for line in contents.lines() {
         let line = line.trim_start_matches('<').trim_end_matches('>');
         let mut split = line.split(',');
         let x = split.next().expect("Expected x field")
             .split('=').nth(1).expect("Expected value")
-            .parse::<i32>().expect("Failed to parse value");
+            .parse::<i64>().expect("Failed to parse value");
         let y = split.next().expect("Expected y field")
             .split('=').nth(1).expect("Expected value")
-            .parse::<i32>().expect("Failed to parse value");
+            .parse::<i64>().expect("Failed to parse value");
         let z = split.next().expect("Expected z field")
             .split('=').nth(1).expect("Expected value")
-            .parse::<i32>().expect("Failed to parse value");
+            .parse::<i64>().expect("Failed to parse value");
 
-        coords.push(Moon::new(Triple::new(x, y, z)));
+        coords.push(Moon::new(Point::new([x, y, z])));
     }
 
     coords
@@ -75,17 +74,17 @@ impl Simulation {
         let mut t_y = None;
         let mut t_z = None;
         for _ in 0.. {
-            let pos_vel_x: Vec<_> = self.current_state.iter().map(|m| ('x', m.pos.x, m.vel.x)).collect();
+            let pos_vel_x: Vec<_> = self.current_state.iter().map(|m| ('x', m.pos[0], m.vel[0])).collect();
             if t_x.is_none() && !seen.insert(pos_vel_x.clone()) {
                 t_x = Some(self.t);
             }
 
-            let pos_vel_y: Vec<_> = self.current_state.iter().map(|m| ('y', m.pos.y, m.vel.y)).collect();
+            let pos_vel_y: Vec<_> = self.current_state.iter().map(|m| ('y', m.pos[1], m.vel[1])).collect();
             if t_y.is_none() && !seen.insert(pos_vel_y.clone()) {
                 t_y = Some(self.t);
             }
 
-            let pos_vel_z: Vec<_> = self.current_state.iter().map(|m| ('z', m.pos.z, m.vel.z)).collect();
+            let pos_vel_z: Vec<_> = self.current_state.iter().map(|m| ('z', m.pos[2], m.vel[2])).collect();
             if t_z.is_none() && !seen.insert(pos_vel_z.clone()) {
                 t_z = Some(self.t);
             }
@@ -130,7 +129,7 @@ impl Simulation {
         self.t += 1;
     }
 
-    fn total_energy(&self) -> i32 {
+    fn total_energy(&self) -> i64 {
         let mut total_energy = 0;
         for moon in &self.current_state {
             total_energy += moon.energy();
@@ -150,82 +149,27 @@ impl Simulation {
 
 #[derive(Clone)]
 struct Moon {
-    pos: Triple,
-    vel: Triple,
+    pos: Point<3>,
+    vel: Vector<3>,
 }
 
 impl Moon {
-    fn new(position: Triple) -> Moon {
-        Moon { pos: position, vel: Triple::new(0, 0, 0) }
+    fn new(position: Point<3>) -> Moon {
+        Moon { pos: position, vel: Vector::zero() }
     }
 
-    fn force(&self, other: &Moon) -> Triple {
-        let dx = if self.pos.x > other.pos.x { -1 } else if self.pos.x < other.pos.x { 1 } else { 0 };
-        let dy = if self.pos.y > other.pos.y { -1 } else if self.pos.y < other.pos.y { 1 } else { 0 };
-        let dz = if self.pos.z > other.pos.z { -1 } else if self.pos.z < other.pos.z { 1 } else { 0 };
-
-        Triple::new(dx, dy, dz)
+    fn force(&self, other: &Moon) -> Vector<3> {
+        (other.pos - self.pos).signum()
     }
 
     fn tick(&mut self) {
         self.pos += self.vel;
     }
 
-    fn energy(&self) -> i32 {
-        let potential = self.pos.x.abs() + self.pos.y.abs() + self.pos.z.abs();
-        let kinetic = self.vel.x.abs() + self.vel.y.abs() + self.vel.z.abs();
+    fn energy(&self) -> i64 {
+        let potential = self.pos.to_vector().manhattan_length();
+        let kinetic = self.vel.manhattan_length();
 
         potential * kinetic
     }
 }
-
-#[derive(Hash, Copy, Clone, Eq, PartialEq, Debug)]
-struct Triple {
-    x: i32,
-    y: i32,
-    z: i32,
-}
-
-impl Triple {
-    const fn new(x: i32, y: i32, z: i32) -> Triple {
-        Triple { x, y, z }
-    }
-}
-
-impl fmt::Display for Triple {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "<{:3}, {:3}, {:3}>", self.x, self.y, self.z)
-    }
-}
-
-impl ops::Add for Triple {
-    type Output = Triple;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        Triple { x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z }
-    }
-}
-
-impl ops::AddAssign for Triple {
-    fn add_assign(&mut self, rhs: Self) {
-        self.x += rhs.x;
-        self.y += rhs.y;
-        self.z += rhs.z;
-    }
-}
-
-impl ops::SubAssign for Triple {
-    fn sub_assign(&mut self, rhs: Self) {
-        self.x -= rhs.x;
-        self.y -= rhs.y;
-        self.z -= rhs.z;
-    }
-}
-
-impl ops::Neg for Triple {
-    type Output = Triple;
-
-    fn neg(self) -> Self::Output {
-        Triple { x: -self.x, y: -self.y, z: -self.y }
-    }
-}
@@ -0,0 +1,290 @@
+//! Running the game and tracking what's on screen.
+
+use intcode::emulator::{Context, Exception, IntcodeEmulator, Program, Word};
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::convert::TryFrom;
+use std::rc::Rc;
+use std::{cmp, fmt, io};
+
+/// Size of the screen, in tiles
+pub const WIDTH: usize = 44;
+pub const HEIGHT: usize = 20;
+
+/// A position on screen (`x`, `y`)
+pub type Pos = (Word, Word);
+
+/// An arcade cabinet with a game loaded
+pub struct ArcadeCabinet {
+    cpu: IntcodeEmulator,
+    state: Rc<RefCell<GameState>>,
+}
+
+impl ArcadeCabinet {
+    /// Load a game, optionally inserting quarters so it can be played
+    pub fn new(program: &Program, freeplay: bool) -> Self {
+        let state = Rc::new(RefCell::new(GameState::new()));
+
+        let mut cpu = IntcodeEmulator::default();
+        cpu.load_program(program);
+        let input_state = Rc::clone(&state);
+        cpu.set_input_handler(Box::new(move |_| Ok(input_state.borrow_mut().joystick())));
+        let output_state = Rc::clone(&state);
+        cpu.set_output_handler(Box::new(move |ctx, word| output_state.borrow_mut().handle_output(ctx, word)));
+
+        if freeplay {
+            cpu.mem_mut()[0] = 2;
+        }
+
+        ArcadeCabinet { cpu, state }
+    }
+
+    /// The state of the game, shared with the cabinet
+    pub fn state(&self) -> Rc<RefCell<GameState>> {
+        Rc::clone(&self.state)
+    }
+
+    /// The CPU running the game
+    pub fn cpu(&self) -> &IntcodeEmulator {
+        &self.cpu
+    }
+
+    /// Run until the ball moves, returning whether the game is still going
+    pub fn frame(&mut self) -> Result<bool, Exception> {
+        match self.cpu.run() {
+            Ok(()) => Ok(false),
+            Err(Exception::Yield) => Ok(true),
+            Err(exception) => Err(exception),
+        }
+    }
+
+    /// Run until the game is over
+    pub fn run(&mut self) -> Result<(), Exception> {
+        while self.frame()? {}
+        Ok(())
+    }
+}
+
+/// Who moves the joystick
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum Controller {
+    /// Follow the ball with the paddle
+    Autopilot,
+    /// Hold the joystick wherever the player last put it
+    Player,
+    /// Play back recorded joystick moves, then switch to autopilot
+    Replay(VecDeque<Word>),
+}
+
+/// A change to what's displayed
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Update {
+    Score(Word),
+    Tile(Pos, Tile),
+}
+
+/// Everything the game has displayed, and how it's being played
+pub struct GameState {
+    score: Word,
+    tiles: HashMap<Pos, Tile>,
+    output_queue: VecDeque<Word>,
+    updates: Vec<Update>,
+    ball_pos: Pos,
+    paddle_pos: Pos,
+    n_blocks: u32,
+    controller: Controller,
+    stick: Word,
+    moves: Vec<Word>,
+}
+
+impl GameState {
+    fn new() -> Self {
+        GameState {
+            score: 0,
+            tiles: HashMap::new(),
+            output_queue: VecDeque::new(),
+            updates: Vec::new(),
+            ball_pos: (0, 0),
+            paddle_pos: (0, 0),
+            n_blocks: 0,
+            controller: Controller::Autopilot,
+            stick: 0,
+            moves: Vec::new(),
+        }
+    }
+
+    /// The current score
+    pub fn score(&self) -> Word {
+        self.score
+    }
+
+    /// Number of blocks drawn so far
+    pub fn n_blocks(&self) -> u32 {
+        self.n_blocks
+    }
+
+    /// The tile on screen at a position
+    pub fn tile(&self, pos: Pos) -> Tile {
+        self.tiles.get(&pos).copied().unwrap_or(Tile::Empty)
+    }
+
+    /// Changes to the display since this was last called
+    pub fn updates(&mut self) -> Vec<Update> {
+        self.updates.drain(..).collect()
+    }
+
+    pub fn set_controller(&mut self, controller: Controller) {
+        self.controller = controller;
+    }
+
+    /// Move the joystick left (`-1`), right (`1`) or back to neutral (`0`) for the player
+    pub fn set_stick(&mut self, stick: Word) {
+        self.stick = stick.signum();
+    }
+
+    /// Every joystick move made so far
+    pub fn moves(&self) -> &[Word] {
+        &self.moves
+    }
+
+    fn joystick(&mut self) -> Word {
+        let replayed = match &mut self.controller {
+            Controller::Replay(moves) => moves.pop_front(),
+            _ => None,
+        };
+
+        let stick = match (&self.controller, replayed) {
+            (_, Some(stick)) => stick,
+            (Controller::Player, _) => self.stick,
+            _ => {
+                self.controller = Controller::Autopilot;
+                match self.ball_pos.0.cmp(&self.paddle_pos.0) {
+                    cmp::Ordering::Less => -1,  // Left
+                    cmp::Ordering::Greater => 1,  // Right
+                    cmp::Ordering::Equal => 0,  // Hold
+                }
+            },
+        };
+        self.moves.push(stick);
+
+        stick
+    }
+
+    fn handle_output(&mut self, ctx: &mut Context, word: Word) -> io::Result<()> {
+        self.output_queue.push_back(word);
+        if self.output_queue.len() < 3 {
+            // Need more input
+            return Ok(());
+        }
+
+        let x = self.output_queue.pop_front().unwrap();
+        let y = self.output_queue.pop_front().unwrap();
+        let tile_id = self.output_queue.pop_front().unwrap();
+
+        if x == -1 && y == 0 {
+            self.score = tile_id;
+            self.updates.push(Update::Score(self.score));
+            return Ok(());
+        }
+
+        let tile = Tile::try_from(tile_id)?;
+        match tile {
+            Tile::Ball => {
+                self.ball_pos = (x, y);
+                // Each move of the ball is a new frame
+                ctx.set_yield(true);
+            },
+            Tile::Paddle => self.paddle_pos = (x, y),
+            Tile::Block => self.n_blocks += 1,
+            _ => (),
+        }
+        self.tiles.insert((x, y), tile);
+        self.updates.push(Update::Tile((x, y), tile));
+
+        Ok(())
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Tile {
+    Empty,
+    Wall,
+    Block,
+    Paddle,
+    Ball,
+}
+
+impl Tile {
+    pub fn to_char(self) -> char {
+        use Tile::*;
+        match self {
+            Empty => ' ',
+            Wall => '█',
+            Block => '■',
+            Paddle => '═',
+            Ball => '●',
+        }
+    }
+}
+
+impl TryFrom<Word> for Tile {
+    type Error = io::Error;
+
+    fn try_from(word: Word) -> Result<Self, Self::Error> {
+        use Tile::*;
+        Ok(match word {
+            0 => Empty,
+            1 => Wall,
+            2 => Block,
+            3 => Paddle,
+            4 => Ball,
+            _ => return Err(io::Error::new(io::ErrorKind::InvalidData, format!("Unknown tile {}", word))),
+        })
+    }
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use locator::locator;
+
+    fn program() -> Program {
+        Program::from_file(locator!().input()).expect("Failed to read input")
+    }
+
+    #[test]
+    fn test_replay() {
+        let program = program();
+        let mut arcade = ArcadeCabinet::new(&program, true);
+        arcade.run().unwrap();
+        let score = arcade.state().borrow().score();
+        let moves = arcade.state().borrow().moves().to_vec();
+
+        // Replaying the moves gives the same game
+        let mut replay = ArcadeCabinet::new(&program, true);
+        replay.state().borrow_mut().set_controller(Controller::Replay(moves.iter().copied().collect()));
+        replay.run().unwrap();
+        assert_eq!(replay.state().borrow().score(), score);
+        assert_eq!(replay.state().borrow().moves(), &moves[..]);
+    }
+
+    #[test]
+    fn test_player() {
+        let mut arcade = ArcadeCabinet::new(&program(), true);
+        arcade.state().borrow_mut().set_controller(Controller::Player);
+        arcade.state().borrow_mut().set_stick(-5);
+        arcade.run().unwrap();
+
+        // Holding the stick left doesn't clear the screen
+        let state = arcade.state();
+        let state = state.borrow();
+        assert!(state.moves().iter().all(|&stick| stick == -1));
+        assert!(state.tiles.values().any(|&tile| tile == Tile::Block));
+    }
+}
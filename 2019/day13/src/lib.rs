@@ -0,0 +1,5 @@
+//! The arcade cabinet of day 13.
+//!
+//! The game itself doesn't know how it's displayed, so it can be played in a terminal or a browser.
+
+pub mod game;
@@ -1,6 +1,6 @@
-use std::{fs, fmt};
-use std::path::Path;
+use std::fmt;
 use std::collections::{HashMap, VecDeque};
+use locator::Input;
 
 type Chemical = String;
 
@@ -9,7 +9,7 @@ const FUEL: &str = "FUEL";
 const TRILLION: u64 = 1_000_000_000_000;
 
 fn main() {
-    let input = read_input("input.txt");
+    let input = read_input(locator::input!());
 
     // Part 1
     assert_eq!(31, required_ore(&read_input("sample1.txt"), 1));
@@ -98,8 +98,8 @@ fn maximum_fuel(reactions: &[Reaction], ore: u64) -> u64 {
     fuel
 }
 
-fn read_input<T: AsRef<Path>>(path: T) -> Vec<Reaction> {
-    let contents = fs::read_to_string(path).expect("Failed to read input");
+fn read_input(input: impl Into<Input>) -> Vec<Reaction> {
+    let contents = input.into().read_to_string().expect("Failed to read input");
 
     let mut reactions = Vec::new();
     for line in contents.lines() {
@@ -1,8 +1,10 @@
 use intcode::emulator::{IntcodeEmulator, Context, Program, Exception, Word};
+use grid::SparseGrid;
+use terminal::{Colour, Palette, Style, Terminal};
 use std::convert::{TryFrom, TryInto};
 use std::collections::{HashMap, HashSet};
-use std::{ops, thread, env, io};
-use std::time::Duration;
+use std::{ops, io};
+use std::path::PathBuf;
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -15,113 +17,128 @@ const DEAD: char = '+';
 const OXYGEN: char = 'O';
 const DEAD_END: u32 = std::u32::MAX;  // Cost of tiles leading to a dead-end
 const ORIGIN: Pos = Pos::new(0, 0);
-const WIDTH: u32 = 80;
-const HEIGHT: u32 = 60;
-const SCREEN_OFFSET_X: u32 = 40;
-const SCREEN_OFFSET_Y: u32 = 30;
 const FPS: u64 = 12;
-
-// Helper macro for printing the status line
-macro_rules! print_status {
-    ( $($e:expr),* ) => { print_status(&format!($($e),*)) };
+const PLAIN_EVERY: usize = 100;  // Only write every so many frames when not on a terminal
+
+/// What has been drawn, shown on the terminal
+///
+/// The frame grows to fit the map.
+struct Screen {
+    tiles: SparseGrid<char>,
+    terminal: Terminal,
 }
 
-fn draw(tile: char, pos: Pos) {
-    let color = match tile {
-        TARGET => "\x1B[31m",  // Red
-        DROID => "\x1B[32m",  // Green
-        OPEN => "\x1B[34m",  // Blue
-        DEAD => "\x1B[33m",  // Yellow
-        OXYGEN => "\x1B[36m",  // Cyan
-        _ => "",
-    };
-    print!("\x1B[{};{}H{}{}\x1B[m", SCREEN_OFFSET_Y as i32 - pos.y + 1, SCREEN_OFFSET_X as i32 + pos.x + 1, color, tile);
-}
+impl Screen {
+    fn new(turbo: bool, record: Option<PathBuf>) -> Self {
+        let palette = Palette::new()
+            .with(TARGET, Style::fg(Colour::Red))
+            .with(DROID, Style::fg(Colour::Green))
+            .with(OPEN, Style::fg(Colour::Blue))
+            .with(DEAD, Style::fg(Colour::Yellow))
+            .with(OXYGEN, Style::fg(Colour::Cyan));
+        let terminal = Terminal::new(0, 0)
+            .palette(palette)
+            .fps(if turbo { None } else { Some(FPS) })
+            .plain_every(PLAIN_EVERY)
+            .record(record);
+
+        Screen { tiles: SparseGrid::new(), terminal }
+    }
+
+    fn draw(&mut self, tile: char, pos: Pos) {
+        self.tiles.insert(pos.into(), tile);
+    }
 
-fn print_status(message: &str) {
-    // Move to status line (second last line), clear it and print message
-    println!("\x1B[{}H\x1B[K{}", HEIGHT - 1, message);
+    /// Show what's been drawn, with a status line below the map
+    fn present(&mut self, status: &str) {
+        let bounds = match self.tiles.bounds() {
+            Some(bounds) => bounds,
+            None => return,
+        };
+
+        self.terminal.resize(bounds.width(), bounds.height());
+        let frame = self.terminal.frame();
+        for (pos, &tile) in self.tiles.iter() {
+            frame.put((pos.0 - bounds.min.0) as usize, (bounds.max.1 - pos.1) as usize, tile);
+        }
+        self.terminal.set_status(status);
+        self.terminal.present().expect("Failed to draw frame");
+    }
+
+    fn finish(&mut self) {
+        self.terminal.finish().expect("Failed to draw frame");
+    }
 }
 
 fn main() {
-    // The --turbo flag skips all animation delays
-    let turbo = env::args().skip(1).any(|arg| &arg == "--turbo");
+    // The --turbo flag skips all animation delays and --record FILE saves the animation
+    let (input, mut args) = locator::locator!().split_args();
+    let record = terminal::record_arg(&mut args);
+    let turbo = args.iter().any(|arg| arg == "--turbo");
 
     // Part 1
-    let program = Program::from_file("input.txt").expect("Failed to read input");
+    let program = Program::from_file(input).expect("Failed to read input");
 
     let mut droid = Droid::new(&program, ORIGIN);
     let mut planner = Planner::new();
 
-    print!("\x1B[2J");  // Clear screen
-    print!("\x1B[?25l");  // Hide cursor
-    print!("\x1B[8;{};{}t", HEIGHT, WIDTH);  // Resize console
+    let mut screen = Screen::new(turbo, record);
 
     // Part 1: Find the broken O₂ system
     let mut o2_system = None;
     while let Some(command) = planner.plan(droid.pos) {
         // Clear the droid from the map
-        draw( planner.get_tile(droid.pos), droid.pos);
+        screen.draw(planner.get_tile(droid.pos), droid.pos);
 
         let target = droid.pos + command.direction();
         match droid.execute(command) {
             Status::Wall => {
                 planner.update_map(target, WALL);
-                draw( WALL, target);
+                screen.draw(WALL, target);
             },
             Status::Moved => {
                 planner.update_map(target, OPEN);
-                draw( OPEN, target);
+                screen.draw(OPEN, target);
             },
             Status::MovedAndFoundTarget => {
                 o2_system = Some(target);
                 planner.update_map(target, TARGET);
-                draw( TARGET, target);
+                screen.draw(TARGET, target);
             },
         }
 
-        draw( DROID, droid.pos);
-        print_status!("{:?} (distance: {})", droid.pos, planner.distance_from_origin(droid.pos));
-
-        if !turbo {
-            thread::sleep(Duration::from_millis(1000 / FPS));
-        }
+        screen.draw(DROID, droid.pos);
+        screen.present(&format!("{:?} (distance: {})", droid.pos, planner.distance_from_origin(droid.pos)));
     }
 
     // Found the leak!
     let o2_system = o2_system.expect("Failed to find the O₂ system");
-    let distance = planner.distance_from_origin(o2_system);
-    draw(planner.get_tile(droid.pos), droid.pos); // Hide the droid, it's work is done
+    screen.draw(planner.get_tile(droid.pos), droid.pos); // Hide the droid, it's work is done
+
+    // Now the map is explored, find the shortest route to the leak
+    let neighbours = |&pos: &Pos| planner.adjacent(pos).into_iter().map(|adj| (adj, 1));
+    let distance = pathfinding::bfs(&neighbours, vec![ORIGIN], |&pos| pos == o2_system)
+        .goal_cost()
+        .expect("Failed to find a route to the O₂ system");
 
     // Part 2: Fill the map with O₂
-    let mut oxygenated: HashSet<Pos> = [o2_system].iter().copied().collect();
-    let mut edge: HashSet<Pos> = planner.adjacent(o2_system).into_iter().collect();
-    let mut t = 0;
-    while !edge.is_empty() {
-        let mut new_edge = HashSet::new();
-        for pos in edge.drain() {
-            oxygenated.insert(pos);
-            draw(OXYGEN, pos);
-            new_edge.extend(planner.adjacent(pos).into_iter().filter(|&pos| !oxygenated.contains(&pos)));
+    let oxygen = pathfinding::bfs(&neighbours, vec![o2_system], |_| false);
+    let t = oxygen.costs().map(|(_, t)| t).max().unwrap_or(0);
+    for minute in 1..=t {
+        for (&pos, _) in oxygen.costs().filter(|&(_, t)| t == minute) {
+            screen.draw(OXYGEN, pos);
         }
 
-        edge = new_edge;
-        t += 1;
-
-        print_status!("t: {} min", t);
-
-        if !turbo {
-            thread::sleep(Duration::from_millis(1000 / FPS));
-        }
+        screen.present(&format!("t: {} min", minute));
     }
 
-    print!("\x1Bc");  // Reset the terminal
+    screen.finish();
     println!("Part 1: Found the O₂ system at {:?} (distance: {})", o2_system, distance);
     println!("Part 2: Time required to fill map with O₂: {} min", t);
 }
 
 struct Planner {
-    map: HashMap<Pos, char>,
+    map: SparseGrid<char>,
     cost: HashMap<Pos, u32>,
     distance: HashMap<Pos, u32>,
     unexplored: HashSet<Pos>,
@@ -129,7 +146,7 @@ struct Planner {
 
 impl Planner {
     fn new() -> Self {
-        let map = [(ORIGIN, OPEN)].iter().copied().collect();  // We start in the open
+        let map = [(ORIGIN.into(), OPEN)].iter().copied().collect();  // We start in the open
         let cost = [(ORIGIN, 1)].iter().copied().collect();  // Already visited origin
         let distance = [(ORIGIN, 0)].iter().copied().collect();
 
@@ -189,12 +206,12 @@ impl Planner {
 
     /// What do we know about this tile
     fn get_tile(&self, pos: Pos) -> char {
-        self.map.get(&pos).copied().unwrap_or(UNKNOWN)
+        self.map.get_or(pos.into(), UNKNOWN)
     }
 
     /// Update the map
     fn update_map(&mut self, pos: Pos, tile: char) {
-        self.map.insert(pos, tile);
+        self.map.insert(pos.into(), tile);
     }
 
     /// Distance postion is from origin
@@ -298,6 +315,12 @@ impl Pos {
     }
 }
 
+impl From<Pos> for grid::Pos {
+    fn from(pos: Pos) -> Self {
+        (pos.x as i64, pos.y as i64)
+    }
+}
+
 impl ops::Add<Movement> for Pos {
     type Output = Pos;
 
@@ -1,10 +1,10 @@
-use std::{fs, iter};
-use std::path::Path;
+use std::iter;
+use locator::Input;
 
 const PATTERN: [i32; 4] = [0, 1, 0, -1];
 
 fn main() {
-    let (signal, offset) = read_input("input.txt");
+    let (signal, offset) = read_input(locator::input!());
 
     // Part 1
     let mut signal1 = signal.clone();
@@ -40,8 +40,8 @@ fn fft(input: &[i32], offset: usize, rounds: usize) -> Vec<i32> {
     result
 }
 
-fn read_input<T: AsRef<Path>>(path: T) -> (Vec<i32>, usize) {
-    let contents = fs::read_to_string(path).expect("Failed to read input");
+fn read_input(input: impl Into<Input>) -> (Vec<i32>, usize) {
+    let contents = input.into().read_to_string().expect("Failed to read input");
     let offset = contents[..7].parse().expect("Failed to parse message offset");
 
     (digits(&contents), offset)
@@ -1,18 +1,22 @@
 use intcode::emulator::{Program, IntcodeEmulator, Word, Context};
-use std::{io, thread};
+use std::{io, mem};
+use std::path::PathBuf;
 use std::cell::RefCell;
 use std::rc::Rc;
 use std::collections::{HashMap, VecDeque};
-use std::time::Duration;
+use terminal::{Colour, Palette, Style, Terminal};
 
 type Pos = (usize, usize);
 
 const SPACE: char = '.';
 const SCAFFOLDING: char = '#';
 const FPS: u64 = 24;
+const PLAIN_EVERY: usize = 50;  // Only write every so many frames when not on a terminal
 
 fn main() {
-    let program = Program::from_file("input.txt").expect("Failed to read input");
+    let (input, mut args) = locator::locator!().split_args();
+    let program = Program::from_file(input).expect("Failed to read input");
+    let record = terminal::record_arg(&mut args);
 
     // Part 1
     let view = get_view(&program);
@@ -23,11 +27,11 @@ fn main() {
     println!("Part 1: Sum of alignment parameters: {}", calibration);
 
     // Part 2
-    println!("Part 2:");
-    let mut robot = Robot::new(&program);
+    let mut robot = Robot::new(&program, record);
     robot.set_active(true);
-    robot.run();
+    let dust = robot.run().expect("Robot didn't report any dust");
 
+    println!("Part 2: Dust collected: {}", dust);
 }
 
 fn alignment_parameters(intersections: &[Pos]) -> Vec<usize> {
@@ -56,10 +60,11 @@ fn get_view(program: &Program) -> String {
 struct Robot {
     cpu: IntcodeEmulator,
     program: Program,
+    video: Rc<RefCell<Video>>,
 }
 
 impl Robot {
-    fn new(program: &Program) -> Self {
+    fn new(program: &Program, record: Option<PathBuf>) -> Self {
         // Input handler for Robot program
         let mut p: VecDeque<_> = [
             //        1         2
@@ -71,45 +76,25 @@ impl Robot {
             "y",  // enable continuous video feed
             "",  // EOF
         ].join("\n").chars().collect();
+        let video = Rc::new(RefCell::new(Video::new(record)));
+
+        let input_video = Rc::clone(&video);
         let input_handler = Box::new(move |_: &mut Context| {
             if let Some(c) = p.pop_front() {
-                print!("{}", c);  // Echo input
+                input_video.borrow_mut().input(c);
                 Ok(c as Word)
             } else {
                 Err(io::Error::new(io::ErrorKind::BrokenPipe, "No more input"))
             }
         });
 
-        // Output handler
-        let mut line = String::new();
-        let mut last_line = String::new();
+        let output_video = Rc::clone(&video);
         let output_handler = Box::new(move |_: &mut Context, word| {
             // Check if in ASCII range
             if (0..=127).contains(&word) {
-                let c: char = (word as u8).into();
-                line.push(c);
-
-                if c == '\n' {
-                    // Detect double newline
-                    if &last_line == "\n" {
-                        print!("\x1B[H");  // Move to home
-
-                        // Status?
-                        if line.chars().next().unwrap_or('\0').is_alphanumeric() {
-                            print!("\x1B[2J");  // Clear screen
-                        }
-
-                        thread::sleep(Duration::from_millis(1000 / FPS));
-                    }
-
-                    print!("{}", line);
-
-                    last_line = line.clone();
-                    line.clear();
-                }
+                output_video.borrow_mut().output((word as u8).into());
             } else {
-                print!("\x1B[1000H\x1B[K");  // Move to end and clear line
-                println!("STATUS: {}", word);
+                output_video.borrow_mut().dust = Some(word);
             }
 
             Ok(())
@@ -117,18 +102,15 @@ impl Robot {
 
         let cpu = IntcodeEmulator::new(input_handler, output_handler);
 
-        Robot { cpu, program: program.clone() }
+        Robot { cpu, program: program.clone(), video }
     }
 
     fn set_active(&mut self, active: bool) {
         self.program[0] = if active { 2 } else { 1 };
     }
 
-    fn run(&mut self) {
-        print!("\x1B[2J");  // Clear screen
-        print!("\x1B[?25l");  // Hide cursor
-        print!("\x1B[H");  // Move to home
-
+    /// Run the robot, returning how much dust it collected (if it was active)
+    fn run(&mut self) -> Option<Word> {
         self.cpu.load_program(&self.program);
 
         match self.cpu.run() {
@@ -136,8 +118,86 @@ impl Robot {
             Err(exception) => panic!("Unhandled exception: {}", exception),
         }
 
-        println!("END");
-        print!("\x1Bc");  // Reset the terminal
+        let mut video = self.video.borrow_mut();
+        video.show();
+        video.terminal.finish().expect("Failed to draw frame");
+
+        video.dust
+    }
+}
+
+/// Shows the robot's video feed on the terminal
+struct Video {
+    terminal: Terminal,
+    /// Lines of the frame (or message) being received
+    lines: Vec<String>,
+    line: String,
+    status: String,
+    dust: Option<Word>,
+}
+
+impl Video {
+    fn new(record: Option<PathBuf>) -> Self {
+        let robot = Style::fg(Colour::Green).bold();
+        let palette = Palette::new()
+            .with(SPACE, Style::fg(Colour::Blue))
+            .with('^', robot)
+            .with('v', robot)
+            .with('<', robot)
+            .with('>', robot);
+        let terminal = Terminal::new(0, 0)
+            .palette(palette)
+            .fps(Some(FPS))
+            .plain_every(PLAIN_EVERY)
+            .record(record);
+
+        Video { terminal, lines: Vec::new(), line: String::new(), status: String::new(), dust: None }
+    }
+
+    /// A character of output from the robot
+    fn output(&mut self, c: char) {
+        if c != '\n' {
+            self.line.push(c);
+        } else if !self.line.is_empty() {
+            self.lines.push(mem::take(&mut self.line));
+        } else {
+            // A blank line ends each frame
+            self.show();
+        }
+    }
+
+    /// A character of input to the robot, shown after its prompt
+    fn input(&mut self, c: char) {
+        if !self.lines.is_empty() {
+            self.status = mem::take(&mut self.lines).join(" ") + " ";
+        }
+
+        if c == '\n' {
+            self.terminal.set_status(&self.status);
+            self.terminal.present().expect("Failed to draw frame");
+        } else {
+            self.status.push(c);
+        }
+    }
+
+    /// Show the lines received so far as a frame, or as the status if they're a message
+    fn show(&mut self) {
+        let lines = mem::take(&mut self.lines);
+        match lines.first() {
+            None => (),
+            Some(first) if first.starts_with(|c: char| c.is_alphanumeric()) => {
+                self.status = lines.join(" ");
+                self.terminal.set_status(&self.status);
+            },
+            Some(first) => {
+                self.terminal.resize(first.len(), lines.len());
+                let frame = self.terminal.frame();
+                for (y, line) in lines.iter().enumerate() {
+                    frame.print(0, y, line, None);
+                }
+                self.terminal.present().expect("Failed to draw frame");
+            },
+        }
     }
 }
 
@@ -1,6 +1,7 @@
-use std::path::Path;
-use std::fs;
-use std::collections::{HashMap, HashSet, BinaryHeap, BTreeSet};
+use std::cell::RefCell;
+use std::collections::{HashMap, BTreeSet};
+use locator::Input;
+use terminal::{Colour, Palette, Style};
 
 type Pos = (usize, usize);
 
@@ -14,14 +15,14 @@ const WALL: char = '#';
 
 fn main() {
     // Part 1
-    let map = Map::from_file("input1.txt");
+    let map = Map::from_file(locator::locator!().file("input1.txt"));
     map.draw();
 
     let (path, distance) = find_shortest_path(&map, &[ENTRANCE]);
     println!("Part 1: Shortest path that collects all the keys: {:?} (distance: {})", path, distance);
 
     // Part 2
-    let map = Map::from_file("input2.txt");
+    let map = Map::from_file(locator::locator!().file("input2.txt"));
     map.draw();
 
     let (path, distance) = find_shortest_path(&map, &[ENTRANCE1, ENTRANCE2, ENTRANCE3, ENTRANCE4]);
@@ -30,61 +31,46 @@ fn main() {
 
 fn find_shortest_path(map: &Map, start: &[char]) -> (Vec<String>, i32) {
     let objects = map.find_objects();
-    let map_keys: HashSet<_> = objects.keys().copied().filter(|&c| is_key(c)).collect();
+    let n_keys = objects.keys().filter(|&&c| is_key(c)).count();
 
     // Cache of (pos, keys) -> {obj -> distance}
-    let mut distance_cache: HashMap<(Pos, BTreeSet<char>), HashMap<char, usize>> = HashMap::new();
-
-    // Priority queue of (distance-so-far, [path])
-    let mut edge: BinaryHeap<(i32, Vec<String>)> = [
-        (0, start.iter().map(|c| c.to_string()).collect()),
-    ].iter().cloned().collect();
-
-    // Map of ([robot_tile], {keys}) -> distance
-    let mut dist_so_far: HashMap<(Vec<char>, BTreeSet<char>), i32> = [
-        ((start.iter().copied().collect(), BTreeSet::new()), 0),
-    ].iter().cloned().collect();
-
-    while let Some((neg_dist, path)) = edge.pop() {
-        let dist = -neg_dist;  // Make distance positive again
-        let key: Vec<_> = path.iter().map(|k| k.chars().last().unwrap()).collect();
-        let keys: BTreeSet<char> = path.iter().flat_map(|k| k.chars().skip(1)).collect();
-
-        // We're done if we have all the keys
-        if keys.len() == map_keys.len() {
-            return (path, dist);
-        }
+    let distance_cache: RefCell<HashMap<(Pos, BTreeSet<char>), _>> = RefCell::default();
 
-        // Get a mapping of key -> (robot-index, distance)
-        let mut distances: HashMap<char, (usize, i32)> = HashMap::new();
-        for (i, &k) in key.iter().enumerate() {
+    // Search over states of ([robot_tile], {keys})
+    let neighbours = |(robots, keys): &(Vec<char>, BTreeSet<char>)| {
+        let mut distance_cache = distance_cache.borrow_mut();
+        let mut neighbours = Vec::new();
+        for (i, &k) in robots.iter().enumerate() {
             let pos = objects[&k];
-            distances.extend(distance_cache.entry((pos, keys.clone())).or_insert_with(|| map.find_distances(objects[&k], &keys)).iter().map(|(&k, &d)| (k, (i, d as i32))));
+            let distances = distance_cache.entry((pos, keys.clone())).or_insert_with(|| map.find_distances(pos, keys));
+
+            for (&key, &distance) in distances.iter().filter(|&(&o, _)| is_key(o) && !keys.contains(&o)) {
+                let mut robots = robots.clone();
+                let mut keys = keys.clone();
+                robots[i] = key;
+                keys.insert(key);
+                neighbours.push(((robots, keys), distance));
+            }
         }
-        
-        let neighbours: HashSet<_> = distances.keys().copied().filter(|&o| is_key(o) && !path.iter().any(|p| p.contains(o))).collect();
-        for &n in &neighbours {
-            let mut key = key.clone();
-            let mut keys = keys.clone();
-            keys.insert(n);
-            let i = distances[&n].0;
-            let new_dist = dist + (distances[&n].1 as i32);
-
-            let mut path = path.clone();
-            path[i].push(n);
-            key[i] = n;
-
-            let cur_dist = dist_so_far.get(&(key.clone(), keys.clone())).copied();
-            if cur_dist.is_none() || new_dist < cur_dist.unwrap() {
-                dist_so_far.insert((key, keys), new_dist);
-
-                // Use negative distance to make max-heap behave like a min-heap
-                edge.push((-new_dist, path));
+
+        neighbours
+    };
+
+    // We're done if we have all the keys
+    let search = pathfinding::dijkstra(&neighbours, vec![(start.to_vec(), BTreeSet::new())], |(_, keys)| keys.len() == n_keys);
+    let goal = search.goal().expect("Not all keys are reachable!");
+
+    // Reconstruct the order each robot collected its keys
+    let mut path: Vec<String> = start.iter().map(|c| c.to_string()).collect();
+    for (robots, _) in search.path_to(goal).unwrap().into_iter().skip(1) {
+        for (p, r) in path.iter_mut().zip(robots) {
+            if !p.ends_with(r) {
+                p.push(r);
             }
         }
     }
 
-    panic!("Not all keys are reachable!")
+    (path, search.goal_cost().unwrap() as i32)
 }
 
 #[derive(Clone)]
@@ -95,8 +81,8 @@ struct Map {
 }
 
 impl Map {
-    fn from_file<T: AsRef<Path>>(path: T) -> Self {
-        let contents = fs::read_to_string(path).expect("Failed to read input");
+    fn from_file(input: impl Into<Input>) -> Self {
+        let contents = input.into().read_to_string().expect("Failed to read input");
 
         let mut tiles = Vec::new();
         let height = contents.lines().count();
@@ -125,28 +111,15 @@ impl Map {
     }
 
     fn find_distances(&self, from: Pos, keys: &BTreeSet<char>) -> HashMap<char, usize> {
-        let mut distances = HashMap::new();
-
-        // Do a simple BFS
-        let mut seen: HashSet<Pos> = HashSet::new();
-        let mut edge: HashSet<Pos> = [from].iter().copied().collect();
-        let mut d = 0;
-        while !edge.is_empty() {
-            // Remember distances to keys and doors
-            for (_, c) in edge.iter().copied().map(|p| (p, self.at(p)) ).filter(|&(p, c)| (is_door(c) || is_key(c) && p != from)) {
-                distances.insert(c, d);
-            }
-            seen.extend(edge.iter().copied());
-
-            // Calculate new edge
-            edge = edge.into_iter()
-                .flat_map(|p| self.adjacent(p, keys).into_iter().filter(|&p| !seen.contains(&p)))
-                .collect();
-
-            d += 1;
-        }
-
-        distances
+        let neighbours = |&pos: &Pos| self.adjacent(pos, keys).into_iter().map(|p| (p, 1));
+        let search = pathfinding::bfs(&neighbours, vec![from], |_| false);
+
+        // Remember distances to keys and doors
+        search.costs()
+            .map(|(&p, d)| (p, self.at(p), d))
+            .filter(|&(p, c, _)| is_door(c) || is_key(c) && p != from)
+            .map(|(_, c, d)| (c, d))
+            .collect()
     }
 
     fn adjacent(&self, (x, y): Pos, keys: &BTreeSet<char>) -> Vec<Pos> {
@@ -178,19 +151,15 @@ impl Map {
     }
 
     fn draw(&self) {
+        let palette = ('a'..='z').zip('A'..='Z')
+            .fold(Palette::new(), |palette, (key, door)| palette
+                .with(key, Style::fg(Colour::Red))
+                .with(door, Style::fg(Colour::Magenta)))
+            .with(OPEN, Style::fg(Colour::Yellow))
+            .with(ENTRANCE, Style::fg(Colour::Cyan));
         for y in 0..self.height {
-            for x in 0..self.width {
-                let c = self.at((x, y));
-                let color = match c {
-                    c if is_key(c) => "\x1B[31m",  // Red
-                    c if is_door(c) => "\x1B[35m",  // Magenta
-                    OPEN => "\x1B[33m",  // Yellow
-                    ENTRANCE => "\x1b[36m",  // Cyan
-                    _ => "",
-                };
-                print!("{}{}\x1B[m", color, c);
-            }
-            println!();
+            let row: String = (0..self.width).map(|x| self.at((x, y))).collect();
+            println!("{}", palette.paint(&row));
         }
     }
 
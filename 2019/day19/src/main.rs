@@ -16,7 +16,7 @@ const WIDTH: Word = 100;
 const HEIGHT: Word = 100;
 
 fn main() {
-    let program = Program::from_file("input.txt").expect("Failed to read input");
+    let program = Program::from_file(locator::input!()).expect("Failed to read input");
 
     // Part 1
     let mut pulled = 0;
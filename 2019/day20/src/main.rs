@@ -1,6 +1,5 @@
-use std::collections::{HashMap, BinaryHeap};
-use std::fs;
-use std::path::Path;
+use std::collections::HashMap;
+use locator::Input;
 
 type Portal = (char, char);
 type Pos = (usize, usize);
@@ -11,7 +10,7 @@ const START: Portal = ('A', 'A');
 const END: Portal = ('Z', 'Z');
 
 fn main() {
-    let map = Map::from_file("input.txt");
+    let map = Map::from_file(locator::input!());
     map.draw();
 
     // Part 1
@@ -25,42 +24,16 @@ fn main() {
 
 /// Find distance from start to exit.
 fn find_distance_to_exit(map: &Map) -> u32 {
-    // Search using A*
-    let mut edge: BinaryHeap<(i32, Pos)> = [(0, map.start)].iter().copied().collect();
-    let mut distance_so_far: HashMap<Pos, i32> = HashMap::new();
-    while let Some((ndist, pos)) = edge.pop() {
-        let distance = -ndist;
-
-        if pos == map.end {
-            return distance as u32;
-        }
+    let neighbours = |&pos: &Pos| map.adjacent(pos).into_iter().map(|adj| (adj, 1));
+    let search = pathfinding::bfs(&neighbours, vec![map.start], |&pos| pos == map.end);
 
-        let adjacent = map.adjacent(pos);
-        for adj in adjacent {
-            let dist = distance + 1;
-            if !distance_so_far.contains_key(&adj) || dist < distance_so_far[&adj] {
-                distance_so_far.insert(adj, dist);
-                edge.push((-dist, adj));
-            }
-        }
-    }
-    panic!("Could not find exit!");
+    search.goal_cost().expect("Could not find exit!") as u32
 }
 
 /// Find distance from start to exit.
 fn find_distance_to_exit_recursive(map: &Map) -> u32 {
-    // Search using A*
-    // We start at priority 0, the start (AA) and level 0
-    let mut edge: BinaryHeap<(i32, Pos, u32)> = [(0, map.start, 0)].iter().copied().collect();
-    let mut distance_so_far: HashMap<(Pos, u32), i32> = [((map.start, 0), 0)].iter().copied().collect();
-    while let Some((_, pos, level)) = edge.pop() {
-        let distance = distance_so_far[&(pos, level)];
-
-        if pos == map.end {
-            return distance as u32;
-        }
-
-        let adjacent = map.adjacent(pos).into_iter().filter(|&adj| {
+    let neighbours = |&(pos, level): &(Pos, u32)| {
+        let adjacent = map.adjacent(pos).into_iter().filter(move |&adj| {
             if level == 0 {
                 // At level 0 we can't use the portals to an inner location (going up a level)
                 !map.outer_portals.contains_key(&pos)
@@ -70,7 +43,7 @@ fn find_distance_to_exit_recursive(map: &Map) -> u32 {
             }
         });
 
-        for adj in adjacent {
+        adjacent.map(move |adj| {
             let level = if map.inner_portals.contains_key(&pos) && map.outer_portals.contains_key(&adj) {
                 // This is an inner portal leading to an outer location
                 level + 1
@@ -81,15 +54,15 @@ fn find_distance_to_exit_recursive(map: &Map) -> u32 {
                 // Otherwise the level remains unchanged
                 level
             };
-            let new_distance = distance + 1;
-            if !distance_so_far.contains_key(&(adj, level)) || new_distance < distance_so_far[&(adj, level)] {
-                distance_so_far.insert((adj, level), new_distance);
-                let priority = -(level as i32);
-                edge.push((priority, adj, level));
-            }
-        }
-    }
-    panic!("Could not find exit!");
+
+            ((adj, level), 1)
+        })
+    };
+
+    // We start at the start (AA) on level 0
+    let search = pathfinding::bfs(&neighbours, vec![(map.start, 0)], |&(pos, _)| pos == map.end);
+
+    search.goal_cost().expect("Could not find exit!") as u32
 }
 
 struct Map {
@@ -103,8 +76,8 @@ struct Map {
 }
 
 impl Map {
-    fn from_file<T: AsRef<Path>>(path: T) -> Self {
-        let contents = fs::read_to_string(path).expect("Failed to read input");
+    fn from_file(input: impl Into<Input>) -> Self {
+        let contents = input.into().read_to_string().expect("Failed to read input");
         let width = contents.find('\n').unwrap();
         let height = contents.lines().count();
         let tiles: Vec<_> = contents.lines().flat_map(|line| line.chars()).collect();
@@ -4,16 +4,19 @@ set -e
 
 cd "$(dirname "$0")"
 BASEDIR="$PWD"
+# Inputs may be kept outside the repository (see lib/locator)
+INPUT_DIR="${AOC_INPUT_DIR:+${AOC_INPUT_DIR}/2019/day21}"
+PROGRAM="${INPUT_DIR:-${BASEDIR}}/input.txt"
 
 function intcode() {
-	(cd "${BASEDIR}"/../intcode; cargo run -q --release -- "$@")
+	cargo run -q --release -p intcode -- "$@"
 }
 
 echo 'Part 1'
 echo '======'
-intcode -A "${BASEDIR}"/input.txt < part1.txt
+intcode -A "${PROGRAM}" < part1.txt
 echo
 
 echo 'Part 2'
 echo '======'
-intcode -A "${BASEDIR}"/input.txt < part2.txt
+intcode -A "${PROGRAM}" < part2.txt
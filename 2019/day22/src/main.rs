@@ -1,5 +1,4 @@
-use std::path::Path;
-use std::fs;
+use locator::Input;
 
 type Signed = i128;
 type Unsigned = u128;
@@ -13,7 +12,7 @@ const M_CARDS: Unsigned = 119315717514047;
 const M_SHUFFLES: Unsigned = 101741582076661;
 
 fn main() {
-    let techniques = read_input("input.txt");
+    let techniques = read_input(locator::input!());
 
     // Part 1
     let pos = 2019;
@@ -30,8 +29,8 @@ fn main() {
     println!("Part 2: After shuffling {} cards {} times, the card at position {} is: {}", M_CARDS, M_SHUFFLES, pos, card);
 }
 
-fn read_input<T: AsRef<Path>>(path: T) -> Vec<Technique> {
-    let contents = fs::read_to_string(path).expect("Failed to read input");
+fn read_input(input: impl Into<Input>) -> Vec<Technique> {
+    let contents = input.into().read_to_string().expect("Failed to read input");
 
     let mut techniques = Vec::new();
     for line in contents.lines() {
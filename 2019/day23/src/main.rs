@@ -8,7 +8,7 @@ const ADDR_NAT: usize = 0xFF;
 const N_COMPUTERS: usize = 50;
 
 fn main() {
-    let program = Program::from_file("input.txt").expect("Failed to read input");
+    let program = Program::from_file(locator::input!()).expect("Failed to read input");
 
     let mut network = Network::new();
     for addr in 0..N_COMPUTERS {
@@ -1,7 +1,7 @@
-use std::fs;
-use std::path::Path;
-use std::collections::{HashSet, BTreeSet};
+use std::collections::BTreeSet;
 use std::ops::RangeInclusive;
+use automaton::{Automaton, Dense, Grid, Neighbourhood, Sparse};
+use locator::Input;
 
 type Pos = (i32, usize, usize);
 
@@ -12,7 +12,7 @@ const BUG: char = '#';
 const EMPTY: char = '.';
 
 fn main() {
-    let map = Map::from_file("input.txt");
+    let map = Map::from_file(locator::input!());
     println!("Initial state:");
     map.draw(0);
 
@@ -31,68 +31,42 @@ fn main() {
 }
 
 fn simulate(map: &Map) -> Map {
-    let mut map = map.clone();
-    let mut seen = HashSet::new();
+    let grid = Grid::new(WIDTH, HEIGHT, Neighbourhood::VonNeumann);
+    let cells = (0..HEIGHT).flat_map(|y| (0..WIDTH).map(move |x| (LEVEL_ZERO, x, y)))
+        .map(|pos| map.is_bug_at(pos))
+        .collect();
 
-    while !seen.contains(&map.bugs) {
-        seen.insert(map.bugs.clone());
-        let mut new_map = map.clone();
-        for y in 0..HEIGHT {
-            for x in 0..WIDTH {
-                let pos = (0, x, y);
-                let adjacent_bugs = map.number_of_adjacent_bugs(pos);
-                if map.is_bug_at(pos) && adjacent_bugs != 1 {
-                    // A bug dies (becoming an empty space) unless
-                    // there is exactly one bug adjacent to it.
-                    new_map.kill(pos);
-                } else if adjacent_bugs == 1 || adjacent_bugs == 2 {
-                    // An empty space becomes infested with a bug if
-                    // exactly one or two bugs are adjacent to it.
-                    new_map.infest(pos);
-                }
-            }
-        }
-        map = new_map;
-    }
+    let mut automaton = Dense::new(&grid, cells, rule);
+    automaton::find_cycle(&mut automaton, u64::MAX, |a| a.cells().to_vec());
 
-    map
-}
+    let bugs = automaton.cells().iter().enumerate()
+        .filter(|&(_, &bug)| bug)
+        .map(|(n, _)| (LEVEL_ZERO, n % WIDTH, n / WIDTH))
+        .collect();
 
-fn simulate_for(map: &Map, minutes: usize) -> Map {
-    let mut map = map.clone();
-
-    for _ in 0..minutes {
-        let mut new_map = map.clone();
-
-        let depth_range = map.depth_range();
-        // Have to simulate the empty levels above and below the range of populated ones
-        for d in (depth_range.start() - 1)..=(depth_range.end() + 1) {
-            for y in 0..HEIGHT {
-                for x in 0..WIDTH {
-                    if x == 2 && y == 2 {
-                        // Recursive tile
-                        continue;
-                    }
-
-                    let pos = (d, x, y);
-                    let adjacent_bugs = map.number_of_adjacent_bugs_3d(pos);
-                    if map.is_bug_at(pos) && adjacent_bugs != 1 {
-                        // A bug dies (becoming an empty space) unless
-                        // there is exactly one bug adjacent to it.
-                        new_map.kill(pos);
-                    } else if adjacent_bugs == 1 || adjacent_bugs == 2 {
-                        // An empty space becomes infested with a bug if
-                        // exactly one or two bugs are adjacent to it.
-                        new_map.infest(pos);
-                    }
-                }
-            }
-        }
+    Map { bugs }
+}
 
-        map = new_map;
+fn simulate_for(map: &Map, minutes: u64) -> Map {
+    let mut automaton = Sparse::new(map.bugs.iter().copied(), adjacent_3d, rule);
+    while automaton.generation() < minutes {
+        automaton.step();
     }
 
-    map
+    Map { bugs: automaton.live().iter().copied().collect() }
+}
+
+fn rule(bug: bool, adjacent: &[bool]) -> bool {
+    let adjacent_bugs = adjacent.iter().filter(|&&b| b).count();
+    if bug {
+        // A bug dies (becoming an empty space) unless
+        // there is exactly one bug adjacent to it.
+        adjacent_bugs == 1
+    } else {
+        // An empty space becomes infested with a bug if
+        // exactly one or two bugs are adjacent to it.
+        adjacent_bugs == 1 || adjacent_bugs == 2
+    }
 }
 
 fn biodiversity_rating(map: &Map) -> u32 {
@@ -118,8 +92,8 @@ struct Map {
 }
 
 impl Map {
-    fn from_file<T: AsRef<Path>>(path: T) -> Self {
-        let contents = fs::read_to_string(path).expect("Failed to read input");
+    fn from_file(input: impl Into<Input>) -> Self {
+        let contents = input.into().read_to_string().expect("Failed to read input");
 
         let mut bugs = BTreeSet::new();
         for (y, line) in contents.lines().enumerate() {
@@ -141,82 +115,10 @@ impl Map {
         min..=max
     }
 
-    fn infest(&mut self, pos: Pos) {
-        self.bugs.insert(pos);
-    }
-
-    fn kill(&mut self, pos: Pos) {
-        self.bugs.remove(&pos);
-    }
-
     fn total_number_of_bugs(&self) -> usize {
         self.bugs.len()
     }
 
-    fn number_of_adjacent_bugs(&self, (d, x, y): Pos) -> usize {
-        let mut n = 0;
-
-        if x > 0 && self.is_bug_at((d, x - 1, y)) { n += 1 };
-        if self.is_bug_at((d, x + 1, y)) { n += 1 };
-        if y > 0 && self.is_bug_at((d, x, y - 1)) { n += 1 };
-        if self.is_bug_at((d, x, y + 1)) { n += 1 };
-
-        n
-    }
-
-    fn number_of_adjacent_bugs_3d(&self, (d, x, y): Pos) -> usize {
-        assert_ne!((x , y), (2, 2), "Recursive tile");
-        let mut n = 0;
-
-        if y == 0 && self.is_bug_at((d - 1, 2, 1)) {
-            n += 1
-        } else if y == HEIGHT - 1 && self.is_bug_at((d - 1, 2, 3)) {
-            n += 1
-        }
-
-        if x == 0 && self.is_bug_at((d - 1, 1, 2)) {
-            n += 1
-        } else if x == WIDTH - 1 && self.is_bug_at((d - 1, 3, 2)) {
-            n += 1
-        }
-
-        if x == 2 && y == 1 {
-            for x in 0..WIDTH {
-                if self.is_bug_at((d + 1, x, 0)) { n += 1; }
-            }
-        } else if x == 2 && y == 3 {
-            for x in 0..WIDTH {
-                if self.is_bug_at((d + 1, x, 4)) { n += 1; }
-            }
-        } else if x == 1 && y == 2 {
-            for y in 0..HEIGHT {
-                if self.is_bug_at((d + 1, 0, y)) { n += 1; }
-            }
-        } else if x == 3 && y == 2 {
-            for y in 0..HEIGHT {
-                if self.is_bug_at((d + 1, 4, y)) { n += 1; }
-            }
-        }
-
-        if x > 0 && !(y == 2 && x == 3) && self.is_bug_at((d, x - 1, y)) {
-            n += 1;
-        }
-
-        if x < WIDTH - 1 && !(y == 2 && x == 1) && self.is_bug_at((d, x + 1, y)) {
-            n += 1;
-        }
-
-        if y > 0 && !(x == 2 && y == 3) && self.is_bug_at((d, x, y - 1)) {
-            n += 1;
-        }
-
-        if y < HEIGHT - 1 && !(x == 2 && y == 1) && self.is_bug_at((d, x, y + 1)) {
-            n += 1;
-        }
-
-        n
-    }
-
     fn is_bug_at(&self, pos: Pos) -> bool {
         self.bugs.contains(&pos)
     }
@@ -238,3 +140,49 @@ impl Map {
         println!();
     }
 }
+
+/// Tiles adjacent to this one, including those on the levels above and below
+fn adjacent_3d((d, x, y): Pos) -> Vec<Pos> {
+    assert_ne!((x , y), (2, 2), "Recursive tile");
+    let mut adjacent = Vec::new();
+
+    if y == 0 {
+        adjacent.push((d - 1, 2, 1));
+    } else if y == HEIGHT - 1 {
+        adjacent.push((d - 1, 2, 3));
+    }
+
+    if x == 0 {
+        adjacent.push((d - 1, 1, 2));
+    } else if x == WIDTH - 1 {
+        adjacent.push((d - 1, 3, 2));
+    }
+
+    if x == 2 && y == 1 {
+        adjacent.extend((0..WIDTH).map(|x| (d + 1, x, 0)));
+    } else if x == 2 && y == 3 {
+        adjacent.extend((0..WIDTH).map(|x| (d + 1, x, 4)));
+    } else if x == 1 && y == 2 {
+        adjacent.extend((0..HEIGHT).map(|y| (d + 1, 0, y)));
+    } else if x == 3 && y == 2 {
+        adjacent.extend((0..HEIGHT).map(|y| (d + 1, 4, y)));
+    }
+
+    if x > 0 && !(y == 2 && x == 3) {
+        adjacent.push((d, x - 1, y));
+    }
+
+    if x < WIDTH - 1 && !(y == 2 && x == 1) {
+        adjacent.push((d, x + 1, y));
+    }
+
+    if y > 0 && !(x == 2 && y == 3) {
+        adjacent.push((d, x, y - 1));
+    }
+
+    if y < HEIGHT - 1 && !(x == 2 && y == 1) {
+        adjacent.push((d, x, y + 1));
+    }
+
+    adjacent
+}
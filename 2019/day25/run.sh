@@ -4,13 +4,16 @@ set -e
 
 cd "$(dirname "$0")"
 BASEDIR="$PWD"
+# Inputs may be kept outside the repository (see lib/locator)
+INPUT_DIR="${AOC_INPUT_DIR:+${AOC_INPUT_DIR}/2019/day25}"
+PROGRAM="${INPUT_DIR:-${BASEDIR}}/input.txt"
 
 function intcode() {
-	(cd "${BASEDIR}"/../intcode; cargo run -q --release -- "$@")
+	cargo run -q --release -p intcode -- "$@"
 }
 
 if [[ "$1" == "--solve" ]]; then
-	intcode -A "${BASEDIR}"/input.txt <<EOF
+	intcode -A "${PROGRAM}" <<EOF
 north
 north
 take monolith
@@ -34,6 +37,6 @@ west
 west
 EOF
 else
-	intcode -A "${BASEDIR}"/input.txt
+	intcode -A "${PROGRAM}"
 fi
 
@@ -4,10 +4,14 @@ set -e
 
 cd "$(dirname "$0")"
 BASEDIR="${PWD}"
-PROG="${BASEDIR}/../day02/input.txt"
+# Inputs may be kept outside the repository (see lib/locator)
+INPUT_DIR="${AOC_INPUT_DIR:+${AOC_INPUT_DIR}/2019/day02}"
+PROG="${INPUT_DIR:-${BASEDIR}/../day02}/input.txt"
+# Built into the workspace target directory
+INTCODE="${CARGO_TARGET_DIR:-${BASEDIR}/../../target}/release/intcode"
 
 function build() {
-  (cd "${BASEDIR}/../intcode"; cargo build --release)
+  cargo build -q --release -p intcode
 }
 
 function prog() {
@@ -15,7 +19,7 @@ function prog() {
 }
 
 function run() {
-  echo "$(target/release/intcode --dump <(prog "$1" "$2") 2>&1 | grep -F 00000000 | awk '{print $2}')"
+  echo "$("${INTCODE}" --dump <(prog "$1" "$2") 2>&1 | grep -F 00000000 | awk '{print $2}')"
 }
 
 function part1() {
@@ -4,7 +4,9 @@ set -e
 
 cd "$(dirname "$0")"
 BASEDIR="${PWD}"
-PROG="${BASEDIR}/../day07/input.txt"
+# Inputs may be kept outside the repository (see lib/locator)
+INPUT_DIR="${AOC_INPUT_DIR:+${AOC_INPUT_DIR}/2019/day07}"
+PROG="${INPUT_DIR:-${BASEDIR}/../day07}/input.txt"
 
 # Generate all permutations of $1
 function permutations() {
@@ -20,7 +22,7 @@ EOF
 }
 
 function intcode() {
-  cargo run -q --release -- "$@"
+  cargo run -q --release -p intcode -- "$@"
 }
 
 function part1() {
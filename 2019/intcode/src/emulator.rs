@@ -1,11 +1,11 @@
 use std::convert::{TryInto, TryFrom};
-use std::{fmt, fs, io, ops};
-use std::path::Path;
-use std::io::{Write, BufRead};
+use std::{fmt, io, ops};
+use std::io::Write;
 use std::collections::VecDeque;
 use std::cell::RefCell;
 use std::rc::Rc;
 use crate::emulator::Opcode::Halt;
+use locator::Input;
 
 pub type Word = i64;
 pub type InputHandler = dyn FnMut(&mut Context) -> io::Result<Word>;
@@ -27,16 +27,17 @@ impl Program {
         Program(instructions.to_owned())
     }
 
-    pub fn from_file<T: AsRef<Path>>(path: T) -> Result<Program, String> {
-        let file = fs::File::open(&path).map_err(|err| format!("Failed to open file: {}", err))?;
-
-        let mut reader = io::BufReader::new(file);
-        let mut line = String::new();
-        reader.read_line(&mut line).map_err(|err| format!("Failed to read line: {}", err))?;
+    /// Read a program from the first line of a file (or stdin)
+    pub fn from_file(input: impl Into<Input>) -> Result<Program, String> {
+        let input = input.into().read_to_string().map_err(|err| format!("Failed to read input: {}", err))?;
+        Program::parse(input.lines().next().unwrap_or_default())
+    }
 
-        let instructions: Result<Vec<Word>, String> = line.trim()
+    /// Parse a program from comma-separated values
+    pub fn parse(text: &str) -> Result<Program, String> {
+        let instructions: Result<Vec<Word>, String> = text.trim()
             .split(',')
-            .map(|val| val.parse::<Word>().map_err(|err| { format!("Failed to parse value {:?}: {}", val, err) }))
+            .map(|val| val.trim().parse::<Word>().map_err(|err| { format!("Failed to parse value {:?}: {}", val, err) }))
             .collect();
 
         Ok(Program::new(&instructions?))
@@ -563,11 +564,20 @@ mod tests {
     use std::collections::VecDeque;
     use std::rc::Rc;
     use std::cell::RefCell;
+    use locator::locator;
+
+    #[test]
+    fn test_parse() {
+        let program = Program::parse("1,9,10,3,\n2,3,11,0,\n99,30,40,50\n").expect("Failed to parse");
+        assert_eq!(program.0, vec![1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50]);
+
+        assert!(Program::parse("1,2,x").is_err());
+    }
 
     #[test]
     fn test_day2_part1() {
         let mut cpu = IntcodeEmulator::default();
-        let program = Program::from_file("../day02/input.txt").expect("Failed to read input");
+        let program = Program::from_file(locator!().day("day02").input()).expect("Failed to read input");
         cpu.load_program(&program);
         cpu.mem_mut()[1] = 12;
         cpu.mem_mut()[2] = 2;
@@ -580,7 +590,7 @@ mod tests {
     #[test]
     fn test_day2_part2() {
         let mut cpu = IntcodeEmulator::default();
-        let program = Program::from_file("../day02/input.txt").expect("Failed to read input");
+        let program = Program::from_file(locator!().day("day02").input()).expect("Failed to read input");
         cpu.load_program(&program);
         cpu.mem_mut()[1] = 51;
         cpu.mem_mut()[2] = 21;
@@ -592,19 +602,19 @@ mod tests {
 
     #[test]
     fn test_day5_part1() {
-        let program = Program::from_file("../day05/input.txt").expect("Failed to read input");
+        let program = Program::from_file(locator!().day("day05").input()).expect("Failed to read input");
         assert_run(&program, VecDeque::from(vec![1]), &[0, 0, 0, 0, 0, 0, 0, 0, 0, 12440243]);
     }
 
     #[test]
     fn test_day5_part2() {
-        let program = Program::from_file("../day05/input.txt").expect("Failed to read input");
+        let program = Program::from_file(locator!().day("day05").input()).expect("Failed to read input");
         assert_run(&program, VecDeque::from(vec![5]), &[15486302]);
     }
 
     #[test]
     fn test_day9_part1() {
-        let program = Program::from_file("../day09/input.txt").expect("Failed to read input");
+        let program = Program::from_file(locator!().day("day09").input()).expect("Failed to read input");
         assert_run(&program, VecDeque::from(vec![1]), &[3335138414]);
     }
 
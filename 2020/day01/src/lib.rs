@@ -0,0 +1,84 @@
+use std::vec::Vec;
+use runner::Solution;
+
+const TARGET: i32 = 2020;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, String> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<i32, String> {
+        part1(input).ok_or_else(|| String::from("No pair of entries sums to 2020"))
+    }
+
+    fn part2(input: &Self::Input) -> Result<i32, String> {
+        part2(input).ok_or_else(|| String::from("No three entries sum to 2020"))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<i32>, String> {
+    input.lines()
+        .map(|line| line.parse::<i32>().map_err(|err| format!("Failed to parse {:?}: {}", line, err)))
+        .collect()
+}
+
+fn part1(input: &[i32]) -> Option<i32> {
+    // We don't need to compare items to themselves
+    // or other combinations we've already tried
+    for (i, entry1) in input.iter().enumerate() {
+        for entry2 in input.iter().skip(i + 1) {
+            if entry1 + entry2 == TARGET {
+                return Some(entry1 * entry2);
+            }
+        }
+    }
+
+    None
+}
+
+fn part2(input: &[i32]) -> Option<i32> {
+    // We don't need to compare items to themselves
+    // or other combinations we've already tried
+    for (i, entry1) in input.iter().enumerate() {
+        for (j, entry2) in input.iter().skip(i + 1).enumerate() {
+            for entry3 in input.iter().skip(i + j + 2) {
+                if entry1 + entry2 + entry3 == TARGET {
+                    return Some(entry1 * entry2 * entry3);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runner::read_input;
+
+    #[test]
+    fn test_part1_example1() {
+        let input = read_input::<Day01>("input1.txt");
+        assert_eq!(part1(&input), Some(514579));
+    }
+
+    #[test]
+    fn test_part2_example1() {
+        let input = read_input::<Day01>("input1.txt");
+        assert_eq!(part2(&input), Some(241861950));
+    }
+
+    #[test]
+    fn test_no_solution() {
+        assert_eq!(Day01::part1(&vec![1, 2, 3]), Err(String::from("No pair of entries sums to 2020")));
+        assert_eq!(Day01::part2(&vec![2019, 1]), Err(String::from("No three entries sum to 2020")));
+    }
+}
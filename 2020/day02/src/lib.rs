@@ -0,0 +1,90 @@
+use regex::Regex;
+use runner::Solution;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Password>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, String> {
+        parse_input(input)
+    }
+
+    fn part1(passwords: &Self::Input) -> Result<usize, String> {
+        Ok(passwords.iter().filter(|p| p.valid1()).count())
+    }
+
+    fn part2(passwords: &Self::Input) -> Result<usize, String> {
+        Ok(passwords.iter().filter(|p| p.valid2()).count())
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Password>, String> {
+    let mut passwords = Vec::new();
+    let regex= Regex::new(r"(\d+)-(\d+) (\w): (\w+)").unwrap();
+    for line in input.lines() {
+        if line.is_empty() {
+            continue;
+        }
+
+        let m = regex.captures(line).ok_or_else(|| format!("Failed to parse line: {:?}", line))?;
+        let position = |s: &str| match s.parse() {
+            Ok(0) | Err(_) => Err(format!("Bad position {:?} in line: {:?}", s, line)),
+            Ok(n) => Ok(n),
+        };
+        let a = position(&m[1])?;
+        let b = position(&m[2])?;
+        let character = m[3].chars().next().unwrap();
+        let password = m[4].into();
+
+        passwords.push(Password { a, b, character, password });
+    }
+
+    Ok(passwords)
+}
+
+#[derive(Debug)]
+pub struct Password {
+    a: usize,
+    b: usize,
+    character: char,
+    password: String,
+}
+
+impl Password {
+    /// Policy 1: Password must contain `character` a minimum of `a` times and a maximum of `b` times.
+    fn valid1(&self) -> bool {
+        let count = self.password.chars().filter(|&c| c == self.character).count();
+
+        count >= self.a && count <= self.b
+    }
+
+    /// Policy 2: Password must contain `character` at either index `a` or index `b`, but not both.
+    /// Indexing is 1-based (there is no "index 0")
+    fn valid2(&self) -> bool {
+        let chars: Vec<_> = self.password.chars().collect();
+
+        (chars.get(self.a - 1) == Some(&self.character)) ^ (chars.get(self.b - 1) == Some(&self.character))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runner::read_input;
+
+    #[test]
+    fn test_part1_example1() {
+        let passwords = read_input::<Day02>("input1.txt");
+        assert_eq!(Day02::part1(&passwords), Ok(2));
+    }
+
+    #[test]
+    fn test_part2_example1() {
+        let passwords = read_input::<Day02>("input1.txt");
+        assert_eq!(Day02::part2(&passwords), Ok(1));
+    }
+}
+
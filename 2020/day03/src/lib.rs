@@ -0,0 +1,101 @@
+use runner::Solution;
+
+type Tile = char;
+const TREE: Tile = '#';
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Map, String> {
+        parse_input(input)
+    }
+
+    fn part1(map: &Map) -> Result<usize, String> {
+        Ok(part1(map))
+    }
+
+    fn part2(map: &Map) -> Result<usize, String> {
+        Ok(part2(map))
+    }
+}
+
+fn part1(map: &Map) -> usize {
+    trees_encountered(map, 3, 1)
+}
+
+fn part2(map: &Map) -> usize {
+    let mut n = 1;
+    for (x_offset, y_offset) in &[(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)] {
+        n *= trees_encountered(&map, *x_offset, *y_offset);
+    }
+
+    n
+}
+
+/// Calculate number of trees that would be intersected following a slope of `y_offset` / `x_offset`.
+fn trees_encountered(map: &Map, x_offset: usize, y_offset: usize) -> usize {
+    assert_ne!(y_offset, 0);
+    let mut x = 0;
+    let mut y = 0;
+    let mut n_trees = 0;
+
+    while y < map.tiles.len() {
+        if map.get(x, y) == TREE {
+            n_trees += 1;
+        }
+
+        x += x_offset;
+        y += y_offset;
+    }
+
+    n_trees
+}
+
+fn parse_input(input: &str) -> Result<Map, String> {
+    let tiles: Vec<Vec<char>> = input
+        .lines()
+        .map(|line| line.chars().collect())
+        .collect();
+
+    let width = tiles.first().map_or(0, |row| row.len());
+    if width == 0 || tiles.iter().any(|row| row.len() != width) {
+        return Err(String::from("Map rows must all be the same, non-zero, width"));
+    }
+
+    Ok(Map { tiles, width })
+}
+
+pub struct Map {
+    tiles: Vec<Vec<Tile>>,
+    width: usize,
+}
+
+impl Map {
+    /// Get tile at position `x`, `y`.
+    fn get(&self, x: usize, y: usize) -> Tile {
+        self.tiles[y][x % self.width]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runner::read_input;
+
+    #[test]
+    fn test_part1_example1() {
+        let map = read_input::<Day03>("input1.txt");
+        assert_eq!(part1(&map), 7);
+    }
+
+    #[test]
+    fn test_part2_example1() {
+        let map = read_input::<Day03>("input1.txt");
+        assert_eq!(part2(&map), 336);
+    }
+}
+
@@ -0,0 +1,107 @@
+use std::collections::HashSet;
+use runner::Solution;
+
+const ROWS_POW: usize = 7;  // 128 rows
+const COLS_POW: usize = 3;  // 8 columns
+
+const FRONT: char = 'F';
+const BACK: char = 'B';
+const LEFT: char = 'L';
+const RIGHT: char = 'R';
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = HashSet<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, String> {
+        let seatids: HashSet<_> = input
+            .lines()
+            .map(|line| {
+                let chars: Vec<char> = line.chars().collect();
+                let valid = chars.len() == ROWS_POW + COLS_POW
+                    && chars[..ROWS_POW].iter().all(|&c| c == FRONT || c == BACK)
+                    && chars[ROWS_POW..].iter().all(|&c| c == LEFT || c == RIGHT);
+
+                if valid { Ok(seatid(line)) } else { Err(format!("Bad seat: {:?}", line)) }
+            })
+            .collect::<Result<_, String>>()?;
+
+        if seatids.is_empty() {
+            return Err(String::from("No seats"));
+        }
+
+        Ok(seatids)
+    }
+
+    fn part1(seatids: &Self::Input) -> Result<u32, String> {
+        Ok(max_seatid(seatids))
+    }
+
+    fn part2(seatids: &Self::Input) -> Result<u32, String> {
+        find_seat(seatids, max_seatid(seatids)).ok_or_else(|| String::from("No empty seat between two others"))
+    }
+}
+
+fn max_seatid(seatids: &HashSet<u32>) -> u32 {
+    seatids.iter().max().copied().unwrap_or(0)
+}
+
+fn seatid(seq: &str) -> u32 {
+    assert_eq!(seq.len(), ROWS_POW + COLS_POW);
+
+    let row = partition(&seq[..ROWS_POW]);
+    let column = partition(&seq[ROWS_POW..]);
+
+    row * 2_u32.pow(COLS_POW as u32) + column
+}
+
+fn partition(seq: &str) -> u32 {
+    let mut min = 0;
+    let mut max = 2_u32.pow(seq.len() as u32) - 1;
+
+    for c in seq.chars() {
+        let mid = min + (max - min) / 2;
+        match c {
+            FRONT | LEFT => max = mid,
+            BACK | RIGHT => min = mid + 1,
+            _ => panic!("Unknown character {}", c),
+        }
+    }
+
+    min
+}
+
+fn find_seat(seatids: &HashSet<u32>, max_seatid: u32) -> Option<u32> {
+    for i in 1..max_seatid.saturating_sub(1) {
+        // Must be an empty seat...
+        if !seatids.contains(&i) {
+            // ...with seats on either side
+            if seatids.contains(&(i-1)) && seatids.contains(&(i+1)) {
+                return Some(i);
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partition() {
+        let seq = "FBFBBFFRLR";
+        assert_eq!(partition(&seq[..ROWS_POW]), 44);
+        assert_eq!(partition(&seq[ROWS_POW..]), 5);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Day05::parse("FBFBBFFRLR\n").map(|seatids| seatids.into_iter().collect()), Ok(vec![357]));
+        assert_eq!(Day05::parse("FBFBBF\u{20ac}R\n"), Err(String::from("Bad seat: \"FBFBBF\u{20ac}R\"")));
+    }
+}
@@ -0,0 +1,78 @@
+use std::collections::HashSet;
+use parsing::{ParseError, Span};
+use runner::Solution;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<Vec<String>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, String> {
+        let groups: Result<_, ParseError> = Span::new(input)
+            .records()
+            .map(|group| group.lines().map(|person| {
+                match person.as_str().char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+                    Some((i, c)) => Err(person.slice(i..i + c.len_utf8()).error("Unexpected character")),
+                    None => Ok(person.as_str().to_owned()),
+                }
+            }).collect())
+            .collect();
+
+        Ok(groups?)
+    }
+
+    /// Sum of count of questions ANY person answered yes
+    fn part1(groups: &Self::Input) -> Result<usize, String> {
+        Ok(count_any(groups))
+    }
+
+    /// Sum of count of questions ALL people answered yes
+    fn part2(groups: &Self::Input) -> Result<usize, String> {
+        Ok(count_all(groups))
+    }
+}
+
+fn count_any(groups: &[Vec<String>]) -> usize {
+    groups.iter().map(|group| {
+        let set: HashSet<_> = group.iter().flat_map(|p| p.chars()).collect();
+        set.len()
+    }).sum()
+}
+
+fn count_all(groups: &[Vec<String>]) -> usize {
+    groups.iter().map(|group| {
+        let mut set: HashSet<_> = ('a'..='z').collect();
+        for person in group {
+            let questions: HashSet<_> = person.chars().collect();
+            set = set.intersection(&questions).copied().collect();
+        }
+        set.len()
+    }).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runner::read_input;
+
+    #[test]
+    fn test_part1() {
+        let groups = read_input::<Day06>("input1.txt");
+        assert_eq!(count_any(&groups), 11);
+    }
+
+    #[test]
+    fn test_part2() {
+        let groups = read_input::<Day06>("input1.txt");
+        println!("{}", count_all(&groups));
+        assert_eq!(count_all(&groups), 6);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day06::parse("abc\n\naé\n").unwrap_err();
+        assert_eq!(err, "line 3, column 2: Unexpected character: \"é\"");
+    }
+}
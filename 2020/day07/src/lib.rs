@@ -0,0 +1,156 @@
+use std::collections::{HashMap, HashSet};
+use runner::Solution;
+
+const SHINY_GOLD: &str = "shiny gold";
+const NO_OTHER: &str = "no other";
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = HashMap<String, Vec<(usize, String)>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, String> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize, String> {
+        part2(input)
+    }
+}
+
+/// Both parts are about shiny gold bags, so there must be a rule for them
+fn check_shiny_gold(rules: &HashMap<String, Vec<(usize, String)>>) -> Result<(), String> {
+    if rules.contains_key(SHINY_GOLD) { Ok(()) } else { Err(format!("No rule for {} bags", SHINY_GOLD)) }
+}
+
+fn part1(rules: &HashMap<String, Vec<(usize, String)>>) -> Result<usize, String> {
+    check_shiny_gold(rules)?;
+
+    let mut count = 0;
+    for starting_bag in rules.keys() {
+        if starting_bag == SHINY_GOLD {
+            continue;
+        }
+
+        let mut stack = vec![starting_bag];
+        while let Some(bag) = stack.pop() {
+            if bag == SHINY_GOLD {
+                count += 1;
+                break;
+            }
+
+            // Parsing checked that every bag has a rule
+            stack.extend(rules[bag].iter().map(|(_, child)| child));
+        }
+    }
+
+    Ok(count)
+}
+
+fn part2(rules: &HashMap<String, Vec<(usize, String)>>) -> Result<usize, String> {
+    check_shiny_gold(rules)?;
+
+    let mut count: usize = 0;
+    let shiny_gold = SHINY_GOLD.to_owned();
+    let mut stack = vec![(1_usize, &shiny_gold)];
+    while let Some((q, bag)) = stack.pop() {
+        for (n, child) in &rules[bag] {
+            let quantity = q.checked_mul(*n).ok_or("Too many bags to count")?;
+            count = count.checked_add(quantity).ok_or("Too many bags to count")?;
+            stack.push((quantity, child));
+        }
+    }
+
+    Ok(count)
+}
+
+fn parse_input(input: &str) -> Result<HashMap<String, Vec<(usize, String)>>, String> {
+    let mut map: HashMap<String, Vec<_>> = HashMap::new();
+    for line in input.lines() {
+        let (bag, contents) = line.split_once(" contain ").ok_or_else(|| format!("Expected a rule: {:?}", line))?;
+        let bag = trim_bag(bag);
+        for contents in contents.split(",").map(|s| trim_bag(s)) {
+            let entry = map.entry(bag.to_owned()).or_default();
+            if contents == NO_OTHER {
+                continue;
+            }
+
+            let (quantity, child_bag) = contents.split_once(" ")
+                .ok_or_else(|| format!("Expected a quantity of bags: {:?}", contents))?;
+            let quantity = quantity.parse().map_err(|err| format!("Failed to parse quantity {:?}: {}", quantity, err))?;
+
+            entry.push((quantity, child_bag.to_owned()));
+        }
+    }
+
+    // Every bag must have a rule
+    if let Some((_, child_bag)) = map.values().flatten().find(|(_, child_bag)| !map.contains_key(child_bag)) {
+        return Err(format!("No rule for {} bags", child_bag));
+    }
+
+    // Otherwise there would be no end of bags
+    let mut checked = HashSet::new();
+    for bag in map.keys() {
+        if let Some(bag) = find_cycle(&map, bag, &mut Vec::new(), &mut checked) {
+            return Err(format!("{} bags contain themselves", bag));
+        }
+    }
+
+    Ok(map)
+}
+
+/// A bag that contains itself, searching from `bag` (reached through `path`)
+fn find_cycle<'a>(rules: &'a HashMap<String, Vec<(usize, String)>>, bag: &'a str,
+                  path: &mut Vec<&'a str>, checked: &mut HashSet<&'a str>) -> Option<&'a str> {
+    if path.contains(&bag) {
+        return Some(bag);
+    }
+    if !checked.insert(bag) {
+        return None;
+    }
+
+    path.push(bag);
+    let cycle = rules[bag].iter().find_map(|(_, child)| find_cycle(rules, child, path, checked));
+    path.pop();
+
+    cycle
+}
+
+fn trim_bag(s: &str) -> &str {
+    s.trim_end_matches(".").trim_end_matches("s").trim_end_matches("bag").trim()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runner::read_input;
+
+    #[test]
+    fn test_part1_sample1() {
+        let rules = read_input::<Day07>("sample1.txt");
+        assert_eq!(part1(&rules), Ok(4));
+    }
+
+    #[test]
+    fn test_part2_sample1() {
+        let rules = read_input::<Day07>("sample1.txt");
+        assert_eq!(part2(&rules), Ok(32));
+    }
+
+    #[test]
+    fn test_bad_rules() {
+        let rules = parse_input("bright white bags contain no other bags.\n").unwrap();
+        assert_eq!(part1(&rules), Err(String::from("No rule for shiny gold bags")));
+        assert_eq!(part2(&rules), Err(String::from("No rule for shiny gold bags")));
+
+        let rules = "shiny gold bags contain 1 dark red bag.\ndark red bags contain 2 shiny gold bags.\n";
+        assert!(parse_input(rules).unwrap_err().ends_with(" bags contain themselves"));
+    }
+}
+
@@ -0,0 +1,176 @@
+use std::collections::HashSet;
+use parsing::{ParseError, Span};
+use runner::Solution;
+
+type Program = Vec<Instruction>;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Program;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, String> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<i32, String> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<i32, String> {
+        part2(input)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Program, ParseError> {
+    Span::new(input)
+        .lines()
+        .map(|line| {
+            let mut fields = line.fields();
+            let op = fields.next("instruction op")?;
+            let op = Operation::from_str(op.as_str()).ok_or_else(|| op.error("Unknown operation"))?;
+            let arg = fields.parse("instruction arg")?;
+            fields.end()?;
+
+            Ok(Instruction { op, arg })
+        })
+        .collect()
+}
+
+/// Run program until just before an instruction would be executed a second time,
+/// then return value of the accumulator.
+fn part1(input: &Program) -> Result<i32, String> {
+    let mut cpu = CPU::from_program(input);
+    run_until_loop(&mut cpu)?;
+
+    Ok(cpu.acc)
+}
+
+/// Mutate NOP/JMP instructions in a program until it exits cleanly,
+/// then return value of the accumulator.
+fn part2(input: &Program) -> Result<i32, String> {
+    for i in 0..input.len() {
+        let mut program = input.clone();
+
+        match program[i].op {
+            Operation::NOP => program[i].op = Operation::JMP,
+            Operation::JMP => program[i].op = Operation::NOP,
+            _ => continue,
+        }
+
+        let mut cpu = CPU::from_program(&program);
+        run_until_loop(&mut cpu)?;
+
+        if cpu.is_eof() {
+            return Ok(cpu.acc);
+        }
+    }
+
+    Err(String::from("No single NOP/JMP change makes the program exit"))
+}
+
+/// Step through program instructions until EOF or program would re-execute an instruction.
+fn run_until_loop(cpu: &mut CPU) -> Result<(), String> {
+    let mut seen = HashSet::new();
+    while !cpu.is_eof() && !seen.contains(&cpu.pc) {
+        seen.insert(cpu.pc);
+        cpu.step().map_err(|err| format!("Execution failed at {}: {}", cpu.pc, err))?;
+    }
+
+    Ok(())
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct Instruction {
+    op: Operation,
+    arg: i32,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Operation {
+    NOP,
+    JMP,
+    ACC,
+}
+
+impl Operation {
+    fn from_str(s: &str) -> Option<Operation> {
+        match s {
+            "nop" => Some(Operation::NOP),
+            "jmp" => Some(Operation::JMP),
+            "acc" => Some(Operation::ACC),
+            _ => None
+        }
+    }
+}
+
+struct CPU<'a> {
+    pc: usize,
+    acc: i32,
+    program: &'a [Instruction],
+}
+
+impl<'a> CPU<'a> {
+    fn from_program(program: &'a Program) -> Self {
+        CPU { pc: 0, acc: 0, program }
+    }
+
+    fn is_eof(&self) -> bool {
+        self.pc == self.program.len()
+    }
+
+    fn step(&mut self) -> Result<(), &'static str> {
+        if self.pc >= self.program.len() {
+            return Err("Attempt to execute out of bounds");
+        }
+
+        let instr = self.program[self.pc];
+        match instr.op {
+            Operation::NOP => (),
+            Operation::JMP => {
+                self.pc = (self.pc as i32 + instr.arg) as usize;
+                return Ok(());
+            },
+            Operation::ACC => self.acc += instr.arg,
+        }
+
+        self.pc += 1;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runner::read_input;
+
+    #[test]
+    fn test_part1() {
+        let input = read_input::<Day08>("sample1.txt");
+        assert_eq!(part1(&input), Ok(5));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = read_input::<Day08>("sample1.txt");
+        assert_eq!(part2(&input), Ok(8));
+    }
+
+    #[test]
+    fn test_no_solution() {
+        let input = parse_input("jmp +0\njmp -1\n").unwrap();
+        assert_eq!(part2(&input), Err(String::from("No single NOP/JMP change makes the program exit")));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_input("nop +0\njmp\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 4: Missing instruction arg");
+
+        let err = parse_input("nop +0\nadd +1\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: Unknown operation: \"add\"");
+    }
+}
+
@@ -0,0 +1,103 @@
+use std::cmp::Ordering;
+use runner::Solution;
+
+type Input = Vec<u64>;
+
+const PREAMBLE: usize = 25;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Input;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Input, String> {
+        input
+            .lines()
+            .map(|line| line.parse().map_err(|err| format!("Failed to parse {:?}: {}", line, err)))
+            .collect()
+    }
+
+    fn part1(input: &Input) -> Result<u64, String> {
+        part1(input, PREAMBLE).ok_or_else(|| String::from("Every number is the sum of two in its preamble"))
+    }
+
+    fn part2(input: &Input) -> Result<u64, String> {
+        let target = Self::part1(input)?;
+
+        part2(input, target).ok_or_else(|| format!("No contiguous range sums to {}", target))
+    }
+}
+
+fn part1(input: &Input, preamble: usize) -> Option<u64> {
+    for i in preamble..input.len() {
+        let n = input[i];
+
+        if !is_valid(&input[i-preamble..i], n) {
+            return Some(n);
+        }
+    }
+
+    None
+}
+
+fn is_valid(preamble: &[u64], number: u64) -> bool {
+    for j in 0..preamble.len() {
+        for k in j+1..preamble.len() {
+            if preamble[j] + preamble[k] == number {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+fn part2(input: &Input, target: u64) -> Option<u64> {
+    for i in 0..input.len() {
+        if let Some(range) = find_contiguous_range(&input[i..], target) {
+            let min = range.iter().min().unwrap();
+            let max = range.iter().max().unwrap();
+
+            return Some(min + max);
+        }
+    }
+
+    None
+}
+
+fn find_contiguous_range(window: &[u64], target: u64) -> Option<&[u64]> {
+    let mut sum = 0;
+
+    for i in 0..window.len() {
+        sum += window[i];
+
+        match sum.cmp(&target) {
+            Ordering::Greater => break,  // Too big!
+            Ordering::Less => continue,  // Not big enough!
+            Ordering::Equal => return Some(&window[..i]),  // Just right!
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runner::read_input;
+
+    #[test]
+    fn test_part1() {
+        let input = read_input::<Day09>("sample1.txt");
+        assert_eq!(part1(&input, 5), Some(127));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = read_input::<Day09>("sample1.txt");
+        assert_eq!(part2(&input, 127), Some(62));
+    }
+}
+
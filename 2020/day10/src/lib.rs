@@ -0,0 +1,115 @@
+use runner::Solution;
+
+type Input = Vec<u64>;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Input;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, String> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<u64, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<u64, String> {
+        Ok(part2(input))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Input, String> {
+    input
+        .lines()
+        .map(|line| line.parse().map_err(|err| format!("Failed to parse {:?}: {}", line, err)))
+        .collect()
+}
+
+fn part1(input: &Input) -> u64 {
+    let mut adapters = input.clone();
+    adapters.sort();
+
+    let mut ones = 0;
+    let mut threes = 1;  // Device has a built-in 3 jolt adapter
+
+    let mut prev = 0;
+    for &j in adapters.iter() {
+        match j - prev {
+            1 => ones += 1,
+            3 => threes += 1,
+            _ => ()
+        }
+
+        prev = j;
+    }
+
+    ones * threes
+}
+
+fn part2(input: &Input) -> u64 {
+    //let mut adapters: HashSet<u64> = input.iter().copied().collect();
+    let mut adapters = input.clone();
+    adapters.sort();
+
+    let mut combinations = 1;
+
+    let mut n2 = 0;
+    let mut n1 = 0;
+    let mut n0 = 1;
+
+    let mut prev = 0;
+    for &n in &adapters {
+        if n == prev + 1 {
+            let n3 = n2;
+            n2 = n1;
+            n1 = n0;
+            n0 = n1 + n2 + n3;
+        } else {
+            combinations *= n0;
+            n2 = 0;
+            n1 = 0;
+            n0 = 1;
+        }
+        prev = n;
+    }
+
+    // Need to account for a possible last run
+    combinations *= n0;
+
+    combinations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runner::read_input;
+
+    #[test]
+    fn test_part1_sample1() {
+        let input = read_input::<Day10>("sample1.txt");
+        assert_eq!(part1(&input), 35);
+    }
+
+    #[test]
+    fn test_part1_sample2() {
+        let input = read_input::<Day10>("sample2.txt");
+        assert_eq!(part1(&input), 220);
+    }
+
+    #[test]
+    fn test_part2_sample1() {
+        let input = read_input::<Day10>("sample1.txt");
+        assert_eq!(part2(&input), 8);
+    }
+
+    #[test]
+    fn test_part2_sample2() {
+        let input = read_input::<Day10>("sample2.txt");
+        assert_eq!(part2(&input), 19208);
+    }
+}
+
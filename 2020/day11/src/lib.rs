@@ -0,0 +1,211 @@
+pub mod map;
+
+use automaton::{Automaton, Dense, Topology};
+use runner::Solution;
+use crate::map::{Pos, Tile};
+
+pub const FLOOR: char = '.';
+pub const EMPTY: char = 'L';
+pub const OCCUPIED: char = '#';
+
+/// People leave a seat when this many of the seats they can see are occupied
+pub const PART1_MAX_OCCUPIED: usize = 4;
+pub const PART2_MAX_OCCUPIED: usize = 5;
+
+/// How far people look for a seat in each direction
+pub const PART1_MAX_SCAN: usize = 1;
+pub const PART2_MAX_SCAN: usize = usize::MAX;
+
+const DEBUG: bool = false;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = map::Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<map::Map, String> {
+        let map = map::Map::parse(input)?;
+        if let Some(tile) = map.tiles().iter().find(|&&t| t != FLOOR && t != EMPTY && t != OCCUPIED) {
+            return Err(format!("Unknown tile {:?}", tile));
+        }
+
+        Ok(map)
+    }
+
+    fn part1(map: &map::Map) -> Result<usize, String> {
+        Ok(part1(map))
+    }
+
+    fn part2(map: &map::Map) -> Result<usize, String> {
+        Ok(part2(map))
+    }
+}
+
+fn part1(map: &map::Map) -> usize {
+    simulate(map, PART1_MAX_OCCUPIED, PART1_MAX_SCAN)
+}
+
+fn part2(map: &map::Map) -> usize {
+    simulate(map, PART2_MAX_OCCUPIED, PART2_MAX_SCAN)
+}
+
+/// Run the seating simulation until it stabilises, returning the number of occupied seats
+fn simulate(map: &map::Map, max_occupied: usize, max_scan: usize) -> usize {
+    if DEBUG { map.print(); }
+
+    let mut seating = Seating::new(map, max_occupied, max_scan);
+    while seating.step() {
+        if DEBUG { seating.map().print(); }
+    }
+
+    seating.map().count(OCCUPIED)
+}
+
+type Rule = Box<dyn Fn(Tile, &[Tile]) -> Tile + Sync>;
+
+/// The seating system, a round at a time
+pub struct Seating {
+    width: usize,
+    height: usize,
+    automaton: Dense<Tile, Rule>,
+}
+
+impl Seating {
+    /// Seat people on `map`, who leave when `max_occupied` of the seats they can see
+    /// (up to `max_scan` tiles away) are occupied
+    pub fn new(map: &map::Map, max_occupied: usize, max_scan: usize) -> Self {
+        let seats = Seats { map, max_scan };
+        let rule = move |tile, adjacent: &[Tile]| {
+            let occupied_adjacent = adjacent.iter().filter(|&&t| t == OCCUPIED).count();
+            match tile {
+                EMPTY if occupied_adjacent == 0 => OCCUPIED,
+                OCCUPIED if occupied_adjacent >= max_occupied => EMPTY,
+                EMPTY | OCCUPIED | FLOOR => tile,
+                t => panic!("Unknown tile {:?}", t),
+            }
+        };
+        let automaton = Dense::new(&seats, map.tiles().to_vec(), Box::new(rule) as Rule);
+
+        Seating { width: map.width(), height: map.height(), automaton }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The tiles in row-major order
+    pub fn tiles(&self) -> &[Tile] {
+        self.automaton.cells()
+    }
+
+    /// The seats as they are now
+    pub fn map(&self) -> map::Map {
+        map::Map::from_tiles(self.tiles(), self.width, self.height)
+    }
+
+    /// Run one round, returning whether anyone moved
+    pub fn step(&mut self) -> bool {
+        self.automaton.step()
+    }
+}
+
+/// Seats are neighbours if they are visible from each other (up to `max_scan` tiles away)
+struct Seats<'a> {
+    map: &'a map::Map,
+    max_scan: usize,
+}
+
+impl Topology for Seats<'_> {
+    fn len(&self) -> usize {
+        self.map.width() * self.map.height()
+    }
+
+    fn neighbours(&self, index: usize) -> Vec<usize> {
+        let pos = ((index % self.map.width()) as i32, (index / self.map.width()) as i32);
+        if self.map.at(pos) == FLOOR {
+            // Floor never changes
+            return Vec::new();
+        }
+
+        visible_seats(self.map, pos, self.max_scan).into_iter()
+            .map(|(x, y)| y as usize * self.map.width() + x as usize)
+            .collect()
+    }
+}
+
+/// Positions of the first seat visible in each direction
+fn visible_seats(map: &map::Map, (x, y): map::Pos, max_scan: usize) -> Vec<Pos> {
+    let mut seats = Vec::new();
+
+    for &(dx, dy) in &[(1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1), (0, -1), (1, -1)] {
+        if let Some(pos) = scan(map, (x, y), (dx, dy), max_scan) {
+            seats.push(pos);
+        }
+    }
+
+    seats
+}
+
+fn scan(map: &map::Map, (x, y): Pos, (dx, dy): Pos, max_distance: usize) -> Option<Pos> {
+    let mut pos = (x, y);
+    for _ in 0..max_distance {
+        pos = (pos.0 + dx, pos.1 + dy);
+
+        match map.get(pos) {
+            Some(tile) if tile != FLOOR => return Some(pos),
+            None => return None,
+            _ => (),
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runner::read_input;
+
+    fn count_occupied_adjacent(map: &map::Map, pos: map::Pos, max_scan: usize) -> usize {
+        visible_seats(map, pos, max_scan).into_iter()
+            .filter(|&p| map.at(p) == OCCUPIED)
+            .count()
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = read_input::<Day11>("sample1.txt");
+        assert_eq!(part1(&input), 37);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = read_input::<Day11>("sample1.txt");
+        assert_eq!(part2(&input), 26);
+    }
+
+    #[test]
+    fn test_part2_sample2() {
+        let input = read_input::<Day11>("sample2.txt");
+        assert_eq!(count_occupied_adjacent(&input, (3, 4), std::usize::MAX), 8);
+    }
+
+    #[test]
+    fn test_part2_sample3() {
+        let input = read_input::<Day11>("sample3.txt");
+        assert_eq!(count_occupied_adjacent(&input, (1, 1), std::usize::MAX), 0);
+    }
+
+    #[test]
+    fn test_part2_sample4() {
+        let input = read_input::<Day11>("sample4.txt");
+        assert_eq!(count_occupied_adjacent(&input, (3, 3), std::usize::MAX), 0);
+    }
+}
+
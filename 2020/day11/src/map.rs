@@ -1,6 +1,4 @@
 // Simple 2D Map
-use std::path::Path;
-use std::fs;
 
 pub type Pos = (i32, i32);
 pub type Tile = char;
@@ -13,19 +11,29 @@ pub struct Map {
 }
 
 impl Map {
-    pub fn from_file<T: AsRef<Path>>(path: T) -> Self {
-        let contents = fs::read_to_string(path).expect("Failed to read input");
-
+    pub fn parse(contents: &str) -> Result<Self, String> {
         let mut tiles = Vec::new();
         let height = contents.lines().count();
         let width = contents.lines().next().unwrap_or("").len();
 
         for line in contents.lines() {
+            if line.len() != width {
+                return Err(format!("Expected rows {} tiles wide: {:?}", width, line));
+            }
             tiles.extend(line.chars());
         }
 
+        Ok(Map { tiles, width, height })
+    }
+
+    /// A map of `tiles` in row-major order
+    pub fn from_tiles(tiles: &[Tile], width: usize, height: usize) -> Self {
         assert_eq!(tiles.len(), width * height);
-        Map { tiles, width, height }
+        Map { tiles: tiles.to_vec(), width, height }
+    }
+
+    pub fn tiles(&self) -> &[Tile] {
+        &self.tiles
     }
 
     pub fn width(&self) -> usize {
@@ -54,11 +62,6 @@ impl Map {
             && pos.1 >= 0 && (pos.1 as usize) < self.height
     }
 
-    pub fn set(&mut self, (x, y): Pos, tile: Tile) {
-        assert!(self.is_valid_pos(&(x, y)));
-        self.tiles[y as usize * self.width + x as usize] = tile;
-    }
-
     pub fn count(&self, tile: Tile) -> usize {
         self.tiles.iter().filter(|t| **t == tile).count()
     }
@@ -0,0 +1,175 @@
+use runner::Solution;
+
+type Input = Vec<Action>;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Input;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Input, String> {
+        input
+            .lines()
+            .map(|line| Action::from_str(line).ok_or_else(|| format!("Failed to parse instruction {:?}", line)))
+            .collect()
+    }
+
+    fn part1(input: &Input) -> Result<u32, String> {
+        Ok(part1(input).distance())
+    }
+
+    fn part2(input: &Input) -> Result<u32, String> {
+        Ok(part2(input).distance())
+    }
+}
+
+fn part1(input: &Input) -> Ship {
+    let mut ship = Ship::new();
+
+    for &action in input {
+        ship.do_action(action);
+    }
+
+    ship
+}
+
+fn part2(input: &Input) -> Ship {
+    let mut ship = Ship::new();
+
+    for &action in input {
+        ship.do_action_waypoint(action);
+    }
+
+    ship
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum Action {
+    North(u32),
+    South(u32),
+    East(u32),
+    West(u32),
+    Left(u32),
+    Right(u32),
+    Forward(u32),
+}
+
+impl Action {
+    /// Parse an action (turns must be a multiple of 90 degrees, less than a full turn)
+    fn from_str(s: &str) -> Option<Self> {
+        let value: u32 = s.get(1..)?.parse().ok()?;
+        let angle = value.is_multiple_of(90) && value < 360;
+
+        use Action::*;
+        match &s[..1] {
+            "N" => Some(North(value)),
+            "S" => Some(South(value)),
+            "E" => Some(East(value)),
+            "W" => Some(West(value)),
+            "L" if angle => Some(Left(value)),
+            "R" if angle => Some(Right(value)),
+            "F" => Some(Forward(value)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Ship {
+    position: (i32, i32),
+    waypoint: (i32, i32),
+    direction: u32,
+}
+
+impl Ship {
+    fn new() -> Self {
+        Ship { position: (0, 0), waypoint: (10, 1), direction: 0 }
+    }
+
+    fn do_action(&mut self, action: Action) {
+        use Action::*;
+
+        match action {
+            North(val) => self.position.1 += val as i32,
+            South(val) => self.position.1 -= val as i32,
+            East(val) => self.position.0 += val as i32,
+            West(val) => self.position.0 -= val as i32,
+            Left(val) => self.direction = (self.direction + val) % 360,
+            Right(val) => self.direction = (self.direction + 360 - val) % 360,
+            Forward(val) => {
+                match self.direction {
+                    0 => self.position.0 += val as i32,
+                    90 => self.position.1 += val as i32,
+                    180 => self.position.0 -= val as i32,
+                    270 => self.position.1 -= val as i32,
+                    other => unreachable!("Unhandled angle: {}", other),
+                }
+            }
+        }
+    }
+
+    fn do_action_waypoint(&mut self, action: Action) {
+        use Action::*;
+
+        match action {
+            North(val) => self.waypoint.1 += val as i32,
+            South(val) => self.waypoint.1 -= val as i32,
+            East(val) => self.waypoint.0 += val as i32,
+            West(val) => self.waypoint.0 -= val as i32,
+            Left(val) => {
+                match val {
+                    0 => (),
+                    90 => self.waypoint = (-self.waypoint.1, self.waypoint.0),
+                    180 => self.waypoint = (-self.waypoint.0, -self.waypoint.1),
+                    270 => self.waypoint = (self.waypoint.1, -self.waypoint.0),
+                    other => unreachable!("Unhandled angle: {}", other),
+                }
+            },
+            Right(val) => {
+                match val {
+                    0 => (),
+                    90 => self.waypoint = (self.waypoint.1, -self.waypoint.0),
+                    180 => self.waypoint = (-self.waypoint.0, -self.waypoint.1),
+                    270 => self.waypoint = (-self.waypoint.1, self.waypoint.0),
+                    other => unreachable!("Unhandled angle: {}", other),
+                }
+            },
+            Forward(val) => self.position = (
+                self.position.0 + val as i32 * self.waypoint.0,
+                self.position.1 + val as i32 * self.waypoint.1,
+            ),
+        }
+    }
+
+    fn distance(&self) -> u32 {
+        (self.position.0.abs() + self.position.1.abs()) as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runner::read_input;
+
+    #[test]
+    fn test_part1() {
+        let input = read_input::<Day12>("sample1.txt");
+        assert_eq!(part1(&input).distance(), 25);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = read_input::<Day12>("sample1.txt");
+        assert_eq!(part2(&input).distance(), 286);
+    }
+
+    #[test]
+    fn test_parse_angle() {
+        assert!(Day12::parse("R270\nL0\n").is_ok());
+        assert_eq!(Day12::parse("F10\nR45\n").unwrap_err(), "Failed to parse instruction \"R45\"");
+        assert!(Day12::parse("L360\n").is_err());
+    }
+}
+
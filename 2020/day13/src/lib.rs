@@ -0,0 +1,130 @@
+
+use runner::Solution;
+
+type Input = (u64, Vec<Option<u64>>);
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Input;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Input, String> {
+        parse_input(input)
+    }
+
+    /// Earliest bus multiplied by the wait for it
+    fn part1(input: &Input) -> Result<u64, String> {
+        let (wait, earliest_bus) = part1(input);
+        Ok(earliest_bus * wait)
+    }
+
+    fn part2(input: &Input) -> Result<u64, String> {
+        Ok(part2(input))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Input, String> {
+    let mut lines = input.lines();
+
+    let timestamp = lines.next().ok_or("Missing timestamp")?;
+    let timestamp = timestamp.parse().map_err(|err| format!("Failed to parse timestamp {:?}: {}", timestamp, err))?;
+    let timetable: Vec<_> = lines.next().ok_or("Missing timetable")?
+        .split(",").map(|val| match val {
+            "x" => Ok(None),
+            _ => match val.parse() {
+                Ok(0) | Err(_) => Err(format!("Bad bus {:?}", val)),
+                Ok(bus) => Ok(Some(bus)),
+            },
+        }).collect::<Result<_, _>>()?;
+
+    if timetable.iter().all(Option::is_none) {
+        return Err(String::from("No buses in timetable"));
+    }
+
+    Ok((timestamp, timetable))
+}
+
+fn part1((timestamp, timetable): &Input) -> (u64, u64) {
+    let mut buses = Vec::new();
+    for bus in timetable {
+        if let &Some(bus) = bus {
+            let wait = bus - (timestamp % bus);
+            buses.push((wait, bus))
+        }
+    }
+
+    *buses.iter().min().unwrap()
+}
+
+fn part2(input: &Input) -> u64 {
+    let mut numbers = enumerate(&input.1);
+
+    while numbers.len() > 1 {
+        let front = &numbers[..2];
+        let rest = &numbers[2..];
+
+        let mut new = vec![common(front)];
+        new.extend(rest);
+
+        numbers = new;
+    }
+
+    let (n, p) = numbers[0];
+
+    p - n
+}
+
+/// Turn list of `Option<val>` into list of `(index, val)`.
+fn enumerate(timetable: &[Option<u64>]) -> Vec<(u64, u64)> {
+    timetable.into_iter().enumerate()
+        .flat_map(|(n, &bus)| if let Some(bus) = bus { Some((n as u64, bus)) } else { None }).collect()
+}
+
+/// Take a list of `(index, val)` and find the first `t0` mod `p` such that the factors are sequential.
+fn common(numbers: &[(u64, u64)]) -> (u64, u64) {
+    let mut t: u64 = 0;
+    let p = numbers.iter().map(|&(_, x)| x).product();
+
+    'outer: loop {
+        for &(n, bus) in numbers {
+            let x = (t + n as u64) % bus;
+            if x != 0 {
+                t += bus - x;
+                continue 'outer;
+            }
+        }
+
+        break;
+    }
+
+    (p - t, p)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runner::read_input;
+
+    #[test]
+    fn test_part1() {
+        let input = read_input::<Day13>("sample1.txt");
+        assert_eq!(part1(&input), (5, 59));
+    }
+
+    #[test]
+    fn test_part2_common() {
+        let (_, timetable) = read_input::<Day13>("sample1.txt");
+        let n = common(&enumerate(&timetable));
+
+        assert_eq!(n.1 - n.0, 1068781);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = read_input::<Day13>("sample1.txt");
+        assert_eq!(part2(&input), 1068781);
+    }
+}
+
@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+use runner::Solution;
+
+type Input = Vec<usize>;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Input;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Input, String> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Input) -> Result<usize, String> {
+        Ok(recite(input, 2020))
+    }
+
+    fn part2(input: &Input) -> Result<usize, String> {
+        Ok(recite(input, 30000000))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Input, String> {
+    input.trim()
+        .split(",")
+        .map(|val| val.parse().map_err(|err| format!("Failed to parse number {:?}: {}", val, err)))
+        .collect()
+}
+
+fn recite(input: &[usize], max_n: usize) -> usize {
+    // Map of numbers to the last time that number was used
+    let mut map: HashMap<usize, Vec<usize>> = HashMap::new();
+
+    // Insert the starting numbers
+    let mut last = 0;
+    for (n, &x) in input.into_iter().enumerate() {
+        map.entry(x).or_default().push(n + 1);
+        last = x;
+    }
+
+    // Index of previous number
+    for n in input.len()..max_n {
+        // Find the index of the second last time this number was used
+        let &m = map.get(&last)
+            .and_then(|xs| xs.iter().rev().skip(1).next())
+            .unwrap_or(&n);
+
+        let difference = n - m;
+
+        map.entry(difference).or_default().push(n + 1);
+        last = difference;
+    }
+
+    last
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(recite(&[0, 3, 6], 2020), 436);
+    }
+}
+
@@ -0,0 +1,137 @@
+use std::collections::{HashSet, HashMap};
+use parsing::{ParseError, Span};
+use runner::Solution;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Input;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, String> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<u64, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<u64, String> {
+        part2(input)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let [fields, ticket, nearby] = Span::new(input).sections()?;
+
+    let fields = fields.lines().map(|line| {
+        let (key, ranges) = line.key_value(": ")?;
+        let mut values = HashSet::new();
+        for range in ranges.split(" or ") {
+            let (start, end) = range.key_value("-")?;
+            let start: u64 = start.parse("range start")?;
+            values.extend(start..=end.parse("range end")?);
+        }
+
+        Ok((key.as_str().to_owned(), values))
+    }).collect::<Result<_, ParseError>>()?;
+
+    let (_, ticket) = ticket.key_value(":")?;
+    let ticket = ticket.trim().list(",", "number")?;
+
+    let (_, nearby) = nearby.key_value(":")?;
+    let nearby = nearby.trim().lines()
+        .map(|line| line.list(",", "number"))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Input { fields, ticket, nearby })
+}
+
+fn part1(input: &Input) -> u64 {
+    input.nearby.iter()
+        .flat_map(|ticket| ticket.iter().filter(|&val| invalid_value(&input.fields, *val)))
+        .sum()
+}
+
+fn invalid_ticket(fields: &HashMap<String, HashSet<u64>>, values: &[u64]) -> bool {
+    values.iter().any(|val| invalid_value(fields, *val))
+}
+
+fn invalid_value(fields: &HashMap<String, HashSet<u64>>, value: u64) -> bool {
+    !fields.iter().any(|(_, set)| set.contains(&value))
+}
+
+fn part2(input: &Input) -> Result<u64, String> {
+    let nearby: Vec<Vec<u64>> = input.nearby.iter().filter(|ticket| !invalid_ticket(&input.fields, ticket)).cloned().collect();
+    let n_fields = input.fields.len();
+
+    // Map of fields to possible ticket positions
+    let mut possibilities: HashMap<String, HashSet<usize>> = input.fields.keys().map(|f| (f.to_owned(), (0..n_fields).collect())).collect();
+
+    // Remove invalid possibilities
+    for (name, valid_values) in &input.fields {
+        let possible = possibilities.get_mut(name).unwrap();
+        for ticket in &nearby {
+            for (j, &val) in ticket.iter().enumerate() {
+                if !valid_values.contains(&val) {
+                    possible.remove(&j);
+                }
+            }
+        }
+    }
+
+    // Solve the rest by deduction
+    let mut found = HashMap::new();
+    while found.len() < n_fields {
+        let (field, ticket_position) = possibilities.iter()
+            .find_map(|(f, p)| if p.len() == 1 { p.iter().next().map(|&n| (f.to_owned(), n)) } else { None })
+            .ok_or_else(|| format!("Can't deduce the position of {} fields", n_fields - found.len()))?;
+        possibilities.remove(&field);
+        found.insert(field, ticket_position);
+
+        for p in possibilities.values_mut() {
+            p.remove(&ticket_position);
+        }
+    }
+
+    // Look up ticket fields by index
+    found.into_iter()
+        .filter(|(f, _)| f.starts_with("departure"))
+        .map(|(_, n)| input.ticket.get(n).copied().ok_or_else(|| format!("Your ticket has no field {}", n)))
+        .product()
+}
+
+#[derive(Debug)]
+pub struct Input {
+    fields: HashMap<String, HashSet<u64>>,
+    ticket: Vec<u64>,
+    nearby: Vec<Vec<u64>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runner::read_input;
+
+    #[test]
+    fn test_part1() {
+        let input = read_input::<Day16>("sample1.txt");
+        assert_eq!(part1(&input), 71);
+    }
+
+    #[test]
+    fn test_ambiguous() {
+        let input = parse_input("a: 1-3 or 5-7\nb: 1-3 or 5-7\n\nyour ticket:\n1,2\n\nnearby tickets:\n2,1\n").unwrap();
+        assert_eq!(part2(&input), Err(String::from("Can't deduce the position of 2 fields")));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_input("class: 1-3 or 5-x\n\nyour ticket:\n7\n\nnearby tickets:\n7\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 17: Invalid range end (invalid digit found in string): \"x\"");
+
+        let err = parse_input("class: 1-3 or 5-7\n\nyour ticket:\n7,1\n").unwrap_err();
+        assert_eq!(err.message(), "Expected 3 sections separated by blank lines, not 2");
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::HashSet;
+use automaton::{Automaton, Sparse, life_rule};
+use geometry::{BoundingBox, Point};
+use parsing::{ParseError, Span};
+use runner::Solution;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Map<3>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Map<3>, String> {
+        Ok(Map::parse(input)?)
+    }
+
+    fn part1(map: &Map<3>) -> Result<usize, String> {
+        Ok(part1(map))
+    }
+
+    fn part2(map: &Map<3>) -> Result<usize, String> {
+        Ok(part2(map))
+    }
+}
+
+fn part1(map: &Map<3>) -> usize {
+    map.simulate(6).active.len()
+}
+
+fn part2(map: &Map<3>) -> usize {
+    let map: Map<4> = map.extend();
+
+    map.simulate(6).active.len()
+}
+
+const INACTIVE: char = '.';
+const ACTIVE: char = '#';
+
+#[derive(Clone)]
+pub struct Map<const N: usize> {
+    active: HashSet<Point<N>>,
+}
+
+impl<const N: usize> Map<N> {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = Span::new(input).grid(|c| match c {
+            ACTIVE => Some(true),
+            INACTIVE => Some(false),
+            _ => None,
+        })?;
+
+        let active = grid.iter().filter(|(_, &active)| active).map(|((x, y), _)| {
+            let mut pos = Point::origin();
+            pos[0] = x;
+            pos[1] = y;
+            pos
+        }).collect();
+
+        Ok(Map { active })
+    }
+
+    /// The same map with an extra dimension (`M` must be `N + 1`)
+    fn extend<const M: usize>(&self) -> Map<M> {
+        let active = self.active.iter().map(|&pos| pos.extend(0)).collect();
+
+        Map { active }
+    }
+
+    /// The map after a number of cycles
+    fn simulate(&self, cycles: u64) -> Self {
+        let neighbours = |pos: Point<N>| pos.neighbours();
+        let mut automaton = Sparse::new(self.active.iter().copied(), neighbours, life_rule(&[3], &[2, 3]));
+        while automaton.generation() < cycles {
+            automaton.step();
+        }
+
+        Map { active: automaton.into_live() }
+    }
+
+    /// Print the `x`/`y` plane at the given coordinates along the remaining axes
+    #[allow(dead_code)]
+    fn print(&self, rest: &[i64]) {
+        let bounds = match BoundingBox::from_points(self.active.iter().copied()) {
+            Some(bounds) => bounds,
+            None => return,
+        };
+
+        for y in bounds.min.y()..=bounds.max.y() {
+            for x in bounds.min.x()..=bounds.max.x() {
+                let mut pos = Point::origin();
+                pos[0] = x;
+                pos[1] = y;
+                pos.0[2..].copy_from_slice(rest);
+                print!("{}", if self.active.contains(&pos) { ACTIVE } else { INACTIVE });
+            }
+            println!();
+        }
+        println!();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runner::read_input;
+
+    #[test]
+    fn test_neighbours3() {
+        let adj: Vec<_> = Point::<3>::origin().neighbours().collect();
+        assert_eq!(adj.len(), 26);
+    }
+
+    #[test]
+    fn test_neighbours4() {
+        let adj: Vec<_> = Point::<4>::origin().neighbours().collect();
+        assert_eq!(adj.len(), 80);
+    }
+
+    #[test]
+    fn test_sample1_part1() {
+        let map = read_input::<Day17>("sample1.txt");
+        assert_eq!(part1(&map), 112);
+    }
+
+    #[test]
+    fn test_sample1_part2() {
+        let map = read_input::<Day17>("sample1.txt");
+        assert_eq!(part2(&map), 848);
+    }
+}
+
@@ -0,0 +1,378 @@
+use std::fmt;
+
+/// A binary operator
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl BinOp {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '+' => Some(BinOp::Add),
+            '-' => Some(BinOp::Sub),
+            '*' => Some(BinOp::Mul),
+            '/' => Some(BinOp::Div),
+            _ => None,
+        }
+    }
+
+    fn apply(self, a: u64, b: u64) -> Result<u64, EvalError> {
+        match self {
+            BinOp::Add => a.checked_add(b).ok_or(EvalError::Overflow),
+            BinOp::Sub => a.checked_sub(b).ok_or(EvalError::Overflow),
+            BinOp::Mul => a.checked_mul(b).ok_or(EvalError::Overflow),
+            BinOp::Div => a.checked_div(b).ok_or(EvalError::DivideByZero),
+        }
+    }
+}
+
+impl fmt::Display for BinOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            BinOp::Add => '+',
+            BinOp::Sub => '-',
+            BinOp::Mul => '*',
+            BinOp::Div => '/',
+        };
+
+        write!(f, "{}", symbol)
+    }
+}
+
+/// Which side of an operator binds first when the precedence is equal
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Assoc {
+    Left,
+    Right,
+}
+
+/// The precedence and associativity of each supported operator
+///
+/// Operators with higher precedence bind more tightly.
+#[derive(Clone, Debug)]
+pub struct OperatorTable {
+    operators: Vec<(BinOp, u8, Assoc)>,
+}
+
+impl OperatorTable {
+    pub fn new(operators: &[(BinOp, u8, Assoc)]) -> Self {
+        OperatorTable { operators: operators.to_vec() }
+    }
+
+    /// Precedence and associativity of an operator (if it's supported)
+    pub fn get(&self, op: BinOp) -> Option<(u8, Assoc)> {
+        self.operators.iter()
+            .find(|&&(o, _, _)| o == op)
+            .map(|&(_, precedence, assoc)| (precedence, assoc))
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum Token {
+    Number(u64),
+    Op(BinOp),
+    LParen,
+    RParen,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Number(n) => write!(f, "{}", n),
+            Token::Op(op) => write!(f, "{}", op),
+            Token::LParen => write!(f, "("),
+            Token::RParen => write!(f, ")"),
+        }
+    }
+}
+
+/// Error parsing an expression (positions are 0-based character offsets)
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum ParseError {
+    UnexpectedChar(char, usize),
+    NumberTooLarge(usize),
+    UnexpectedToken(String, usize),
+    UnsupportedOperator(BinOp, usize),
+    UnexpectedEnd,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnexpectedChar(c, pos) => write!(f, "Unexpected character {:?} at {}", c, pos),
+            ParseError::NumberTooLarge(pos) => write!(f, "Number too large at {}", pos),
+            ParseError::UnexpectedToken(t, pos) => write!(f, "Unexpected token {:?} at {}", t, pos),
+            ParseError::UnsupportedOperator(op, pos) => write!(f, "Unsupported operator {:?} at {}", op.to_string(), pos),
+            ParseError::UnexpectedEnd => write!(f, "Unexpected end of expression"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Error evaluating an expression
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum EvalError {
+    Overflow,
+    DivideByZero,
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvalError::Overflow => write!(f, "Arithmetic overflow"),
+            EvalError::DivideByZero => write!(f, "Divide by zero"),
+        }
+    }
+}
+
+impl std::error::Error for EvalError {}
+
+/// Split an expression into tokens, along with their position
+fn tokenize(s: &str) -> Result<Vec<(usize, Token)>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = s.char_indices().peekable();
+
+    while let Some((pos, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            c if c.is_ascii_digit() => {
+                let mut end = pos + 1;
+                while let Some(&(i, c)) = chars.peek() {
+                    if !c.is_ascii_digit() {
+                        break;
+                    }
+                    end = i + 1;
+                    chars.next();
+                }
+
+                Token::Number(s[pos..end].parse().map_err(|_| ParseError::NumberTooLarge(pos))?)
+            },
+            c => Token::Op(BinOp::from_char(c).ok_or(ParseError::UnexpectedChar(c, pos))?),
+        };
+
+        tokens.push((pos, token));
+    }
+
+    Ok(tokens)
+}
+
+/// An expression tree
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum Expr {
+    Number(u64),
+    Binary(BinOp, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    /// Parse an expression using the operators in `table`
+    pub fn parse(s: &str, table: &OperatorTable) -> Result<Self, ParseError> {
+        let tokens = tokenize(s)?;
+        let mut parser = Parser { tokens: &tokens, next: 0, table };
+
+        let expr = parser.expression(0)?;
+        if let Some(&(pos, token)) = parser.peek() {
+            return Err(ParseError::UnexpectedToken(token.to_string(), pos));
+        }
+
+        Ok(expr)
+    }
+
+    /// Evaluate the expression, checking for overflow
+    pub fn eval(&self) -> Result<u64, EvalError> {
+        match self {
+            &Expr::Number(n) => Ok(n),
+            Expr::Binary(op, lhs, rhs) => op.apply(lhs.eval()?, rhs.eval()?),
+        }
+    }
+
+    /// Format the expression with only the parentheses needed to parse it again using `table`
+    pub fn pretty(&self, table: &OperatorTable) -> String {
+        let mut out = String::new();
+        self.write_pretty(table, &mut out);
+
+        out
+    }
+
+    fn write_pretty(&self, table: &OperatorTable, out: &mut String) {
+        let (op, lhs, rhs) = match self {
+            Expr::Number(n) => {
+                out.push_str(&n.to_string());
+                return;
+            },
+            Expr::Binary(op, lhs, rhs) => (*op, lhs, rhs),
+        };
+
+        let (precedence, assoc) = table.get(op).expect("Operator should be in table");
+        let needs_parens = |child: &Expr, side: Assoc| match child {
+            &Expr::Binary(child_op, _, _) => {
+                let (child_precedence, _) = table.get(child_op).expect("Operator should be in table");
+                child_precedence < precedence || (child_precedence == precedence && assoc != side)
+            },
+            Expr::Number(_) => false,
+        };
+
+        for (child, side) in [(lhs, Assoc::Left), (rhs, Assoc::Right)].iter() {
+            if *side == Assoc::Right {
+                out.push_str(&format!(" {} ", op));
+            }
+
+            if needs_parens(child, *side) {
+                out.push('(');
+                child.write_pretty(table, out);
+                out.push(')');
+            } else {
+                child.write_pretty(table, out);
+            }
+        }
+    }
+}
+
+impl fmt::Display for Expr {
+    /// Fully parenthesised form of the expression
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Number(n) => write!(f, "{}", n),
+            Expr::Binary(op, lhs, rhs) => write!(f, "({} {} {})", lhs, op, rhs),
+        }
+    }
+}
+
+/// A Pratt parser
+struct Parser<'a> {
+    tokens: &'a [(usize, Token)],
+    next: usize,
+    table: &'a OperatorTable,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&(usize, Token)> {
+        self.tokens.get(self.next)
+    }
+
+    fn advance(&mut self) -> Option<(usize, Token)> {
+        let token = self.tokens.get(self.next).copied();
+        self.next += 1;
+
+        token
+    }
+
+    /// Parse an expression containing only operators that bind at least as tightly as `min_precedence`
+    fn expression(&mut self, min_precedence: u8) -> Result<Expr, ParseError> {
+        let mut lhs = self.operand()?;
+
+        while let Some(&(pos, Token::Op(op))) = self.peek() {
+            let (precedence, assoc) = self.table.get(op).ok_or(ParseError::UnsupportedOperator(op, pos))?;
+            if precedence < min_precedence {
+                break;
+            }
+            self.advance();
+
+            // Left-associative operators don't allow an operator of the same precedence on their right
+            let rhs = match assoc {
+                Assoc::Left => match precedence.checked_add(1) {
+                    Some(min_precedence) => self.expression(min_precedence)?,
+                    // Nothing binds more tightly than the highest precedence
+                    None => self.operand()?,
+                },
+                Assoc::Right => self.expression(precedence)?,
+            };
+
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+
+        Ok(lhs)
+    }
+
+    /// Parse a number or parenthesised expression
+    fn operand(&mut self) -> Result<Expr, ParseError> {
+        match self.advance() {
+            Some((_, Token::Number(n))) => Ok(Expr::Number(n)),
+            Some((_, Token::LParen)) => {
+                let expr = self.expression(0)?;
+                match self.advance() {
+                    Some((_, Token::RParen)) => Ok(expr),
+                    Some((pos, token)) => Err(ParseError::UnexpectedToken(token.to_string(), pos)),
+                    None => Err(ParseError::UnexpectedEnd),
+                }
+            },
+            Some((pos, token)) => Err(ParseError::UnexpectedToken(token.to_string(), pos)),
+            None => Err(ParseError::UnexpectedEnd),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arithmetic() -> OperatorTable {
+        use BinOp::*;
+        OperatorTable::new(&[(Add, 1, Assoc::Left), (Sub, 1, Assoc::Left), (Mul, 2, Assoc::Left), (Div, 2, Assoc::Left)])
+    }
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(tokenize("12 + (3)").unwrap(), vec![
+            (0, Token::Number(12)), (3, Token::Op(BinOp::Add)), (5, Token::LParen), (6, Token::Number(3)), (7, Token::RParen),
+        ]);
+        assert_eq!(tokenize("1 % 2"), Err(ParseError::UnexpectedChar('%', 2)));
+        assert_eq!(tokenize("99999999999999999999"), Err(ParseError::NumberTooLarge(0)));
+    }
+
+    #[test]
+    fn test_associativity() {
+        let table = arithmetic();
+        assert_eq!(Expr::parse("10 - 4 - 3", &table).unwrap().eval(), Ok(3));
+        assert_eq!(Expr::parse("100 / 10 / 5", &table).unwrap().eval(), Ok(2));
+
+        let table = OperatorTable::new(&[(BinOp::Sub, 1, Assoc::Right)]);
+        assert_eq!(Expr::parse("10 - 4 - 3", &table).unwrap().eval(), Ok(9));
+
+        let table = OperatorTable::new(&[(BinOp::Sub, u8::MAX, Assoc::Left), (BinOp::Mul, 0, Assoc::Left)]);
+        assert_eq!(Expr::parse("10 - 4 - 3 * 2", &table).unwrap().to_string(), "(((10 - 4) - 3) * 2)");
+    }
+
+    #[test]
+    fn test_pretty() {
+        let table = arithmetic();
+        for &(s, pretty) in &[
+            ("((1 + 2)) * 3", "(1 + 2) * 3"),
+            ("1 + (2 * 3)", "1 + 2 * 3"),
+            ("(1 - 2) - 3", "1 - 2 - 3"),
+            ("1 - (2 - 3)", "1 - (2 - 3)"),
+            ("1 + (2 + 3)", "1 + (2 + 3)"),
+        ] {
+            let expr = Expr::parse(s, &table).unwrap();
+            assert_eq!(expr.pretty(&table), pretty);
+            assert_eq!(Expr::parse(pretty, &table).unwrap(), expr);
+        }
+
+        assert_eq!(Expr::parse("1 + 2 * 3", &table).unwrap().to_string(), "(1 + (2 * 3))");
+    }
+
+    #[test]
+    fn test_errors() {
+        let table = OperatorTable::new(&[(BinOp::Add, 1, Assoc::Left)]);
+        assert_eq!(Expr::parse("1 +", &table), Err(ParseError::UnexpectedEnd));
+        assert_eq!(Expr::parse("(1 + 2", &table), Err(ParseError::UnexpectedEnd));
+        assert_eq!(Expr::parse("1 2", &table), Err(ParseError::UnexpectedToken("2".to_string(), 2)));
+        assert_eq!(Expr::parse("1 + )", &table), Err(ParseError::UnexpectedToken(")".to_string(), 4)));
+        assert_eq!(Expr::parse("1 * 2", &table), Err(ParseError::UnsupportedOperator(BinOp::Mul, 2)));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let table = arithmetic();
+        assert_eq!(Expr::parse("18446744073709551615 + 1", &table).unwrap().eval(), Err(EvalError::Overflow));
+        assert_eq!(Expr::parse("1 - 2", &table).unwrap().eval(), Err(EvalError::Overflow));
+        assert_eq!(Expr::parse("1 / (2 - 2)", &table).unwrap().eval(), Err(EvalError::DivideByZero));
+        assert_eq!(Expr::parse("4294967296 * 4294967295", &table).unwrap().eval(), Ok(18446744069414584320));
+    }
+}
@@ -0,0 +1,134 @@
+//! An expression language for the homework problems in day 18.
+
+pub mod expr;
+
+use expr::{Assoc, BinOp, Expr, OperatorTable};
+use runner::Solution;
+
+const DEBUG: bool = false;
+
+type Input = Vec<String>;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Input;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Input, String> {
+        // Precedence doesn't change whether an expression is valid, so check with either table
+        let operators = part1_operators();
+        input.lines()
+            .map(|line| match Expr::parse(line, &operators) {
+                Ok(_) => Ok(line.to_owned()),
+                Err(err) => Err(format!("Failed to parse {:?}: {}", line, err)),
+            })
+            .collect()
+    }
+
+    fn part1(input: &Input) -> Result<u64, String> {
+        sum(input, &part1_operators())
+    }
+
+    fn part2(input: &Input) -> Result<u64, String> {
+        sum(input, &part2_operators())
+    }
+}
+
+/// Addition and multiplication have the same precedence
+fn part1_operators() -> OperatorTable {
+    OperatorTable::new(&[(BinOp::Add, 1, Assoc::Left), (BinOp::Mul, 1, Assoc::Left)])
+}
+
+/// Addition is evaluated before multiplication
+fn part2_operators() -> OperatorTable {
+    OperatorTable::new(&[(BinOp::Add, 2, Assoc::Left), (BinOp::Mul, 1, Assoc::Left)])
+}
+
+/// Sum of the values of each expression
+fn sum(input: &Input, operators: &OperatorTable) -> Result<u64, String> {
+    input.iter().try_fold(0u64, |acc, line| {
+        acc.checked_add(eval(line, operators)?).ok_or_else(|| String::from("Sum overflowed"))
+    })
+}
+
+fn eval(s: &str, operators: &OperatorTable) -> Result<u64, String> {
+    let expr = Expr::parse(s, operators).map_err(|err| format!("Failed to parse {:?}: {}", s, err))?;
+    let value = expr.eval().map_err(|err| format!("Failed to evaluate {:?}: {}", s, err))?;
+
+    if DEBUG { println!("{} = {}", expr.pretty(operators), value); }
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval1(s: &str) -> u64 {
+        eval(s, &part1_operators()).unwrap()
+    }
+
+    fn eval2(s: &str) -> u64 {
+        eval(s, &part2_operators()).unwrap()
+    }
+
+    #[test]
+    fn test_example1() {
+        assert_eq!(eval1("1 + 2 * 3 + 4 * 5 + 6"), 71);
+        assert_eq!(eval2("1 + 2 * 3 + 4 * 5 + 6"), 231);
+    }
+
+    #[test]
+    fn test_example2() {
+        assert_eq!(eval1("1 + (2 * 3) + (4 * (5 + 6))"), 51);
+        assert_eq!(eval2("1 + (2 * 3) + (4 * (5 + 6))"), 51);
+    }
+
+    #[test]
+    fn test_example3() {
+        assert_eq!(eval1("2 * 3 + (4 * 5)"), 26);
+        assert_eq!(eval2("2 * 3 + (4 * 5)"), 46);
+    }
+
+    #[test]
+    fn test_example4() {
+        assert_eq!(eval1("5 + (8 * 3 + 9 + 3 * 4 * 3)"), 437);
+        assert_eq!(eval2("5 + (8 * 3 + 9 + 3 * 4 * 3)"), 1445);
+    }
+
+    #[test]
+    fn test_example5() {
+        assert_eq!(eval1("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))"), 12240);
+        assert_eq!(eval2("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))"), 669060);
+    }
+
+    #[test]
+    fn test_example6() {
+        assert_eq!(eval1("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2"), 13632);
+        assert_eq!(eval2("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2"), 23340);
+    }
+
+    #[test]
+    fn test_multiple_digits() {
+        assert_eq!(eval1("12 + 34 * 10"), 460);
+        assert_eq!(eval2("10 * 12 + 34"), 460);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let input = Day18::parse("99999999999 * 99999999999\n").unwrap();
+        assert_eq!(Day18::part1(&input).unwrap_err(), "Failed to evaluate \"99999999999 * 99999999999\": Arithmetic overflow");
+
+        let input = Day18::parse("9999999999 * 999999999\n9999999999 * 999999999\n").unwrap();
+        assert_eq!(Day18::part2(&input).unwrap_err(), "Sum overflowed");
+    }
+
+    #[test]
+    fn test_pretty() {
+        let expr = Expr::parse("1 + 2 * 3 + 4", &part1_operators()).unwrap();
+        assert_eq!(expr.pretty(&part1_operators()), "1 + 2 * 3 + 4");
+        assert_eq!(expr.pretty(&part2_operators()), "(1 + 2 * 3) + 4");
+    }
+}
@@ -0,0 +1,262 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use regex::Regex;
+
+pub type RuleId = u32;
+
+/// Where matches of a rule starting at a position end, for each rule and position tried so far
+type Memo = HashMap<(RuleId, usize), Vec<usize>>;
+
+/// A set of rules that describe which messages are valid
+///
+/// Rules may be recursive, but must not be left-recursive (e.g. `8: 8 42`), which [`Grammar::check`] rejects.
+#[derive(Clone, Debug, Default)]
+pub struct Grammar {
+    rules: HashMap<RuleId, Rule>,
+}
+
+impl Grammar {
+    /// Add or replace a rule from a line of the form `8: 42 | 42 8`
+    pub fn set_rule(&mut self, line: &str) -> Result<(), String> {
+        let rule_re = Regex::new(r"^(\d+): (.*)$").unwrap();
+        let m = rule_re.captures(line.trim()).ok_or("Expected a rule like `8: 42 | 42 8`")?;
+        let id = m[1].parse().map_err(|err| format!("Bad rule number {:?}: {}", &m[1], err))?;
+        let rule = Rule::from_str(&m[2]).ok_or("Expected a rule like `8: 42 | 42 8`")?;
+
+        self.rules.insert(id, rule);
+
+        Ok(())
+    }
+
+    /// Check that rule `id` exists, that no rule refers to one that doesn't and that none are left-recursive
+    pub fn check(&self, id: RuleId) -> Result<(), String> {
+        if !self.rules.contains_key(&id) {
+            return Err(format!("Unknown rule {}", id));
+        }
+
+        for (rule_id, rule) in &self.rules {
+            if let Rule::Subrule(alternatives) = rule {
+                if let Some(missing) = alternatives.iter().flatten().find(|id| !self.rules.contains_key(id)) {
+                    return Err(format!("Rule {} refers to unknown rule {}", rule_id, missing));
+                }
+            }
+        }
+
+        if let Some(id) = self.left_recursive() {
+            return Err(format!("Rule {} is left-recursive", id));
+        }
+
+        Ok(())
+    }
+
+    /// A rule that can come back to itself without matching anything first, so it never finishes matching
+    fn left_recursive(&self) -> Option<RuleId> {
+        let nullable = self.nullable();
+
+        // The rules that a match of a rule can start with
+        let left = |id| match self.rule(id) {
+            Rule::Literal(_) => Vec::new(),
+            Rule::Subrule(alternatives) => alternatives.iter()
+                .flat_map(|sequence| {
+                    let n = sequence.iter().take_while(|id| nullable.contains(id)).count();
+                    sequence.iter().copied().take(n + 1)
+                })
+                .collect(),
+        };
+
+        let mut ids: Vec<_> = self.rules.keys().copied().collect();
+        ids.sort_unstable();
+        let mut checked = HashSet::new();
+
+        ids.into_iter().find_map(|id| find_cycle(id, &left, &mut Vec::new(), &mut checked))
+    }
+
+    /// Rules that can match an empty message
+    fn nullable(&self) -> HashSet<RuleId> {
+        let mut nullable = HashSet::new();
+        loop {
+            let before = nullable.len();
+            for (&id, rule) in &self.rules {
+                if let Rule::Subrule(alternatives) = rule {
+                    if alternatives.iter().any(|sequence| sequence.iter().all(|id| nullable.contains(id))) {
+                        nullable.insert(id);
+                    }
+                }
+            }
+
+            if nullable.len() == before {
+                return nullable;
+            }
+        }
+    }
+
+    pub fn rule(&self, id: RuleId) -> &Rule {
+        self.rules.get(&id).unwrap_or_else(|| panic!("Unknown rule {}", id))
+    }
+
+    /// Does the whole message match a rule
+    pub fn matches(&self, id: RuleId, message: &str) -> bool {
+        let message: Vec<char> = message.chars().collect();
+
+        self.ends(id, &message, 0, &mut Memo::new()).contains(&message.len())
+    }
+
+    /// Parse a message with a rule, returning the first parse tree found
+    pub fn parse(&self, id: RuleId, message: &str) -> Option<Tree> {
+        let message: Vec<char> = message.chars().collect();
+
+        self.parse_rule(id, &message, 0, message.len(), &mut Memo::new())
+    }
+
+    /// All the positions a match of a rule starting at `start` could end
+    fn ends(&self, id: RuleId, message: &[char], start: usize, memo: &mut Memo) -> Vec<usize> {
+        let alternatives = match self.rule(id) {
+            Rule::Literal(c) => {
+                return if message.get(start) == Some(c) { vec![start + 1] } else { vec![] };
+            },
+            Rule::Subrule(alternatives) => alternatives,
+        };
+
+        // Only subrules are memoised, as literals are quicker to match again than to look up
+        if let Some(ends) = memo.get(&(id, start)) {
+            return ends.clone();
+        }
+
+        let mut ends = Vec::new();
+        for sequence in alternatives {
+            for end in self.sequence_ends(sequence, message, start, memo) {
+                if !ends.contains(&end) {
+                    ends.push(end);
+                }
+            }
+        }
+        memo.insert((id, start), ends.clone());
+
+        ends
+    }
+
+    /// All the positions a match of a sequence of rules starting at `start` could end
+    fn sequence_ends(&self, sequence: &[RuleId], message: &[char], start: usize, memo: &mut Memo) -> Vec<usize> {
+        let mut positions = vec![start];
+        for &id in sequence {
+            let mut next = Vec::new();
+            for position in positions {
+                for end in self.ends(id, message, position, memo) {
+                    if !next.contains(&end) {
+                        next.push(end);
+                    }
+                }
+            }
+
+            positions = next;
+        }
+
+        positions
+    }
+
+    fn parse_rule(&self, id: RuleId, message: &[char], start: usize, end: usize, memo: &mut Memo) -> Option<Tree> {
+        match self.rule(id) {
+            &Rule::Literal(c) => {
+                if end == start + 1 && message.get(start) == Some(&c) {
+                    Some(Tree::Node(id, vec![Tree::Leaf(c)]))
+                } else {
+                    None
+                }
+            },
+            Rule::Subrule(alternatives) => {
+                alternatives.iter()
+                    .find_map(|sequence| self.parse_sequence(sequence, message, start, end, memo))
+                    .map(|children| Tree::Node(id, children))
+            },
+        }
+    }
+
+    /// Backtrack through every way of splitting `message[start..end]` between the rules in a sequence
+    fn parse_sequence(&self, sequence: &[RuleId], message: &[char], start: usize, end: usize, memo: &mut Memo)
+        -> Option<Vec<Tree>>
+    {
+        let (&first, rest) = match sequence.split_first() {
+            Some(split) => split,
+            None => return if start == end { Some(Vec::new()) } else { None },
+        };
+
+        for mid in self.ends(first, message, start, memo).into_iter().filter(|&mid| mid <= end) {
+            if let Some(rest) = self.parse_sequence(rest, message, mid, end, memo) {
+                if let Some(tree) = self.parse_rule(first, message, start, mid, memo) {
+                    let mut children = vec![tree];
+                    children.extend(rest);
+                    return Some(children);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// A rule that comes back to itself through the rules that each rule leads to (searching from `id`)
+fn find_cycle(id: RuleId, next: &dyn Fn(RuleId) -> Vec<RuleId>,
+              path: &mut Vec<RuleId>, checked: &mut HashSet<RuleId>) -> Option<RuleId> {
+    if path.contains(&id) {
+        return Some(id);
+    }
+    if !checked.insert(id) {
+        return None;
+    }
+
+    path.push(id);
+    let cycle = next(id).into_iter().find_map(|next_id| find_cycle(next_id, next, path, checked));
+    path.pop();
+
+    cycle
+}
+
+#[derive(Clone, Debug)]
+pub enum Rule {
+    Literal(char),
+    Subrule(Vec<Vec<RuleId>>)
+}
+
+impl Rule {
+    pub fn from_str(s: &str) -> Option<Self> {
+        use Rule::*;
+
+        if s.starts_with('"') {
+            Some(Literal(s.chars().nth(1)?))
+        } else {
+            Some(Subrule(
+                s.split('|')
+                    .map(|s| {
+                        s.split_whitespace().map(|v| v.parse().ok()).collect()
+                    }).collect::<Option<_>>()?))
+        }
+    }
+}
+
+/// How a message was matched by a grammar
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum Tree {
+    /// A rule and the trees of the rules it matched
+    Node(RuleId, Vec<Tree>),
+    /// A literal character
+    Leaf(char),
+}
+
+impl fmt::Display for Tree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Tree::Node(id, children) => {
+                write!(f, "{}(", id)?;
+                for (i, child) in children.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " ")?;
+                    }
+                    write!(f, "{}", child)?;
+                }
+                write!(f, ")")
+            },
+            Tree::Leaf(c) => write!(f, "{:?}", c),
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use grammar::Grammar;
+use parsing::{ParseError, Span};
+use runner::Solution;
+
+mod grammar;
+
+/// Replacement rules for part 2 (making rules 8 and 11 recursive)
+const PART2_RULES: [&str; 2] = [
+    "8: 42 | 42 8",
+    "11: 42 31 | 42 11 31",
+];
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Input;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Input, String> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Input) -> Result<usize, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Input) -> Result<usize, String> {
+        part2(input, &[])
+    }
+}
+
+pub fn part1(input: &Input) -> usize {
+    count_matches(input)
+}
+
+/// Part 2, with `overrides` applied after the part 2 rules
+pub fn part2(input: &Input, overrides: &[String]) -> Result<usize, String> {
+    Ok(count_matches(&part2_input(input, overrides)?))
+}
+
+/// The input with the part 2 rules applied (then any other overrides)
+///
+/// Fails if the rules refer to rules the input doesn't have.
+pub fn part2_input(input: &Input, overrides: &[String]) -> Result<Input, String> {
+    let mut input = input.clone();
+    for rule in PART2_RULES.iter().copied().chain(overrides.iter().map(String::as_str)) {
+        input.set_rule(rule).map_err(|err| format!("Can't use rule {:?}: {}", rule, err))?;
+    }
+
+    Ok(input)
+}
+
+/// Number of messages that completely match rule 0
+fn count_matches(input: &Input) -> usize {
+    input.text.iter()
+        .filter(|message| input.grammar.matches(0, message))
+        .count()
+}
+
+pub fn print_trees(input: &Input) {
+    for message in &input.text {
+        if let Some(tree) = input.grammar.parse(0, message) {
+            println!("{}: {}", message, tree);
+        }
+    }
+}
+
+/// Parse rules of the form `0: 4 1 5`, one per line, then the messages
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let [rules, messages] = Span::new(input).sections()?;
+
+    let mut grammar = Grammar::default();
+    for line in rules.lines() {
+        grammar.set_rule(line.as_str()).map_err(|err| line.error(err))?;
+    }
+    grammar.check(0).map_err(|err| rules.end().error(err))?;
+
+    let text = messages.lines().map(|line| line.as_str().to_owned()).collect();
+
+    Ok(Input { grammar, text })
+}
+
+#[derive(Clone, Debug)]
+pub struct Input {
+    grammar: Grammar,
+    text: Vec<String>,
+}
+
+impl Input {
+    /// Add or replace a rule from a line of the form `8: 42 | 42 8`, unless that makes the rules invalid
+    pub fn set_rule(&mut self, line: &str) -> Result<(), String> {
+        let mut grammar = self.grammar.clone();
+        grammar.set_rule(line)?;
+        grammar.check(0)?;
+        self.grammar = grammar;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use grammar::Tree;
+    use runner::read_input;
+
+    /// The text matched by a parse tree
+    fn text(tree: &Tree) -> String {
+        match tree {
+            Tree::Node(_, children) => children.iter().map(text).collect(),
+            Tree::Leaf(c) => c.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_matches() {
+        let input = read_input::<Day19>("sample1.txt");
+
+        assert!(input.grammar.matches(5, "b"));
+        assert!(input.grammar.matches(4, "a"));
+        for s in &["ab", "ba"] {
+            assert!(input.grammar.matches(3, s));
+        }
+        for s in &["aa", "bb"] {
+            assert!(input.grammar.matches(2, s));
+        }
+        for s in &["aaab", "aaba", "bbab", "bbba", "abaa", "abbb", "baaa", "babb"] {
+            assert!(input.grammar.matches(1, s));
+        }
+        for s in &["", "a", "ab", "abab", "aaaba"] {
+            assert!(!input.grammar.matches(1, s));
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = read_input::<Day19>("sample1.txt");
+        assert_eq!(part1(&input), 2);
+    }
+
+    #[test]
+    fn test_part2() {
+        // The sample has the part 2 rules, so undo them for part 1
+        let mut input = read_input::<Day19>("sample2.txt");
+        input.set_rule("8: 42").unwrap();
+        input.set_rule("11: 42 31").unwrap();
+
+        assert_eq!(part1(&input), 3);
+        assert_eq!(part2(&input, &[]), Ok(12));
+
+        let input = Day19::parse("0: 1\n1: \"a\"\n\na\n").unwrap();
+        assert_eq!(part2(&input, &[]), Err(String::from("Can't use rule \"8: 42 | 42 8\": Rule 8 refers to unknown rule 42")));
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = read_input::<Day19>("sample1.txt");
+        let tree = input.grammar.parse(0, "ababbb").unwrap();
+        assert_eq!(text(&tree), "ababbb");
+        assert_eq!(tree.to_string(), "0(4('a') 1(3(5('b') 4('a')) 2(5('b') 5('b'))) 5('b'))");
+        assert_eq!(input.grammar.parse(0, "bababa"), None);
+    }
+
+    #[test]
+    fn test_bad_rules() {
+        assert!(Day19::parse("0: 1 2\n1: \"a\"\n\nab\n").is_err());
+        assert_eq!(Day19::parse("0: 1\n1: x\n\na\n").unwrap_err(),
+                   "line 2, column 1: Expected a rule like `8: 42 | 42 8`: \"1: x\"");
+        assert_eq!(Day19::parse("0: \"a\"\n").unwrap_err(),
+                   "line 2, column 1: Expected 2 sections separated by blank lines, not 1");
+
+        let mut input = read_input::<Day19>("sample1.txt");
+        assert!(input.set_rule("0: 4 99").is_err());
+        assert_eq!(input.set_rule("1: 1 2"), Err(String::from("Rule 1 is left-recursive")));
+
+        // Rule 6 can match nothing, so rule 2 can start with itself
+        let rules = "0: 2\n2: 6 2 4 | 4\n4: \"a\"\n6: 4 |\n\na\n";
+        assert_eq!(Day19::parse(rules).unwrap_err(), "line 4, column 7: Rule 2 is left-recursive");
+        assert!(Day19::parse("0: 2\n2: 4 2 | 4\n4: \"a\"\n\na\n").is_ok());
+    }
+
+    #[test]
+    fn test_parse_recursive() {
+        let input = part2_input(&read_input::<Day19>("sample2.txt"), &[]).unwrap();
+        let mut n_trees = 0;
+        for message in &input.text {
+            if let Some(tree) = input.grammar.parse(0, message) {
+                assert_eq!(&text(&tree), message);
+                n_trees += 1;
+            }
+        }
+        assert_eq!(n_trees, 12);
+    }
+}
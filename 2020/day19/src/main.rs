@@ -1,169 +1,61 @@
-use std::path::Path;
-use std::fs;
-use std::collections::{HashMap, HashSet};
-use regex::Regex;
+use std::process;
+use aoc2020_day19::{Day19, parse_input, part1, part2, part2_input, print_trees};
 
-fn main() {
-    let input = read_input("input.txt");
-    println!("Part 1: {}", part1(&input));
-
-    let input = read_input("input2.txt");
-    println!("Part 2: {}", part2(&input));
-}
-
-fn part1(input: &Input) -> usize {
-    // Rule 0: 8 11 -> 42 42 31
-    let set42 = input.expand(42);
-    let n = set42.iter().next().unwrap().len();
-    let set31 = input.expand(31);
+const USAGE: &str = "Usage: day19 [--rule 'N: RULE']... [--tree] [--input FILE | --sample N | INPUT]
+       day19 [--input FILE | --sample N] --bench REPS [--warmup N]";
 
-    input.text.iter()
-        .filter(|t| matches(&set42, &t[..2*n], 2) && matches(&set31, &t[2*n..], 1))
-        .count()
-}
+fn main() {
+    let mut rules = Vec::new();
+    let mut show_trees = false;
+    let (mut source, args) = runner::locator!().split_args();
 
-fn matches(set: &HashSet<String>, s: &str, n: usize) -> bool {
-    if n == 0 {
-        return s == "";
+    // Benchmarking (e.g. by `aoc bench`) times the puzzle as given, like any other day
+    if args.iter().any(|arg| arg == "--bench") {
+        return runner::main::<Day19>(runner::locator!());
     }
 
-    for x in set {
-        let len = x.len();
-        if s.len() >= len && &s[..len] == x && matches(set, &s[len..], n - 1) {
-            return true;
+    // Extra rules given with `--rule` override rules from the input in both parts
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--rule" => rules.push(args.next().unwrap_or_else(|| usage())),
+            "--tree" => show_trees = true,
+            "-h" | "--help" => usage(),
+            _ if arg.starts_with('-') && arg != "-" => usage(),
+            _ => source = runner::Input::from_arg(&arg),
         }
     }
 
-    false
-}
-
-fn part2(input: &Input) -> usize {
-    // Rule 0: 8 11 -> 42+ 42(42(...)31)31
-    let set42 = input.expand(42);
-    let n = set42.iter().next().unwrap().len();
-    let set31 = input.expand(31);
-
-    input.text.iter()
-        .filter(|s| {
-            for n31 in 1.. {
-                if n31 * n > s.len() {
-                    // Too long
-                    break;
-                }
-
-                // Must match 42 more times than 31
-                for n42 in n31 + 1.. {
-                    if (n31 + n42) * n > s.len() {
-                        // Too long
-                        break;
-                    }
-
-                    if matches(&set42, &s[..n42*n], n42) && matches(&set31, &s[n42*n..], n31) {
-                        return true;
-                    }
-                }
-            }
-
-            false
-        }).count()
-}
-
-fn read_input<T: AsRef<Path>>(path: T) -> Input {
-    let input = fs::read_to_string(path).expect("Failed to read input");
-    let mut sections = input.split("\n\n");
-
-    let rule_re = Regex::new(r"^(\d+): (.*)$").unwrap();
-    let rules = sections.next().expect("Missing section")
-        .lines().map(|line| {
-            let m = rule_re.captures(&line).expect("Failed to parse rule");
-            let n = m[1].parse().unwrap();
-            let rule = Rule::from_str(&m[2]);
-
-            (n, rule)
-        }).collect();
-
-    let text = sections.next().expect("Missing section")
-        .lines().map(|line| line.to_owned()).collect();
-
-    Input { rules, text }
-}
-
-#[derive(Debug)]
-struct Input {
-    rules: HashMap<u32, Rule>,
-    text: Vec<String>,
-}
-
-impl Input {
-    /// Expand rule into all possible matches
-    fn expand(&self, id: u32) -> HashSet<String> {
-        let rule = self.rules.get(&id).expect("Unknown rule");
-
-        match rule {
-            Rule::Literal(c) => [c.to_string()].iter().cloned().collect(),
-            Rule::Subrule(subrule) => {
-                let mut expanded = HashSet::new();
-
-                for rs in subrule {
-                    match rs.len() {
-                        1 => expanded.extend(self.expand(rs[0])),
-                        2 => {
-                            for a in self.expand(rs[0]) {
-                                for b in self.expand(rs[1]) {
-                                    expanded.insert(a.clone() + b.as_str());
-                                }
-                            }
-                        }
-                        _ => panic!("Unsupported number of terms: {}", rs.len()),
-                    }
-                }
-
-                expanded
-            }
+    let mut input = parsing::read_input(source, parse_input);
+    for rule in &rules {
+        if let Err(err) = input.set_rule(rule) {
+            eprintln!("Bad rule {:?}: {}", rule, err);
+            usage();
         }
     }
-}
 
-#[derive(Debug)]
-enum Rule {
-    Literal(char),
-    Subrule(Vec<Vec<u32>>)
-}
-
-impl Rule {
-    fn from_str(s: &str) -> Self {
-        use Rule::*;
+    if show_trees {
+        print_trees(&input);
+    }
+    println!("Part 1: {}", part1(&input));
 
-        if s.starts_with('"') {
-            Literal(s.chars().nth(1).unwrap())
-        } else {
-            Subrule(
-                s.split('|')
-                    .map(|s| {
-                        s.split_whitespace().map(|v| v.parse().unwrap()).collect()
-                    }).collect())
+    if show_trees {
+        if let Ok(input) = part2_input(&input, &rules) {
+            print_trees(&input);
         }
     }
-
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    fn to_hashset(values: &[&str]) -> HashSet<String> {
-        values.iter().map(|s| s.to_string()).collect()
+    match part2(&input, &rules) {
+        Ok(count) => println!("Part 2: {}", count),
+        Err(err) => fail(&format!("Part 2: {}", err)),
     }
+}
 
-    #[test]
-    fn test_expand() {
-        let input = read_input("sample1.txt");
-
-        assert_eq!(input.expand(5), to_hashset(&["b"]));
-        assert_eq!(input.expand(4), to_hashset(&["a"]));
-        assert_eq!(input.expand(3), to_hashset(&["ab", "ba"]));
-        assert_eq!(input.expand(2), to_hashset(&["aa", "bb"]));
-        assert_eq!(input.expand(1), to_hashset(&["aaab", "aaba", "bbab", "bbba", "abaa", "abbb", "baaa", "babb"]));
-    }
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
 }
 
+fn fail(err: &str) -> ! {
+    eprintln!("Error: {}", err);
+    process::exit(1);
+}
@@ -0,0 +1,186 @@
+use std::fmt;
+
+pub type Pos = (usize, usize);
+
+/// A square image
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Image {
+    size: usize,
+    pixels: Vec<char>,
+}
+
+impl Image {
+    pub fn new(size: usize, pixels: Vec<char>) -> Self {
+        assert_eq!(pixels.len(), size * size, "Image must be square");
+
+        Image { size, pixels }
+    }
+
+    pub fn from_lines<'a>(lines: impl IntoIterator<Item=&'a str>) -> Self {
+        let lines: Vec<_> = lines.into_iter().collect();
+        let pixels = lines.iter().flat_map(|line| line.chars()).collect();
+
+        Image::new(lines.len(), pixels)
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn get(&self, (x, y): Pos) -> Option<char> {
+        if x >= self.size || y >= self.size {
+            None
+        } else {
+            Some(self.pixels[y * self.size + x])
+        }
+    }
+
+    /// Number of pixels of a certain value
+    pub fn count(&self, pixel: char) -> usize {
+        self.pixels.iter().filter(|&&p| p == pixel).count()
+    }
+
+    /// Build a new image by looking up each pixel in this one
+    fn map(&self, size: usize, f: impl Fn(Pos) -> Pos) -> Self {
+        let pixels = (0..size)
+            .flat_map(|y| (0..size).map(move |x| (x, y)))
+            .map(|pos| self.get(f(pos)).unwrap())
+            .collect();
+
+        Image::new(size, pixels)
+    }
+
+    /// Rotate 90° clockwise
+    pub fn rotate(&self) -> Self {
+        let n = self.size;
+        self.map(n, |(x, y)| (y, n - 1 - x))
+    }
+
+    /// Flip horizontally
+    pub fn flip(&self) -> Self {
+        let n = self.size;
+        self.map(n, |(x, y)| (n - 1 - x, y))
+    }
+
+    /// All 8 orientations of this image (4 rotations, each optionally flipped)
+    pub fn orientations(&self) -> Vec<Self> {
+        let mut orientations = Vec::with_capacity(8);
+
+        let mut image = self.clone();
+        for _ in 0..4 {
+            orientations.push(image.flip());
+            let rotated = image.rotate();
+            orientations.push(image);
+            image = rotated;
+        }
+
+        orientations
+    }
+
+    /// The image without its outermost pixels
+    pub fn strip_border(&self) -> Self {
+        self.map(self.size - 2, |(x, y)| (x + 1, y + 1))
+    }
+
+    pub fn top(&self) -> String {
+        (0..self.size).map(|x| self.get((x, 0)).unwrap()).collect()
+    }
+
+    pub fn bottom(&self) -> String {
+        (0..self.size).map(|x| self.get((x, self.size - 1)).unwrap()).collect()
+    }
+
+    pub fn left(&self) -> String {
+        (0..self.size).map(|y| self.get((0, y)).unwrap()).collect()
+    }
+
+    pub fn right(&self) -> String {
+        (0..self.size).map(|y| self.get((self.size - 1, y)).unwrap()).collect()
+    }
+}
+
+impl fmt::Display for Image {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.pixels.chunks(self.size) {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A pattern that can be searched for in an image
+pub struct Pattern {
+    width: usize,
+    height: usize,
+    /// Positions that must be `#`
+    offsets: Vec<Pos>,
+}
+
+impl Pattern {
+    /// Create a pattern where `#` must match and any other character is ignored
+    pub fn new(lines: &[&str]) -> Self {
+        let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+        let height = lines.len();
+        let offsets = lines.iter().enumerate()
+            .flat_map(|(y, line)| line.chars().enumerate().filter(|&(_, c)| c == '#').map(move |(x, _)| (x, y)))
+            .collect();
+
+        Pattern { width, height, offsets }
+    }
+
+    /// Top-left position of every match of this pattern in an image
+    pub fn find(&self, image: &Image) -> Vec<Pos> {
+        let mut matches = Vec::new();
+        if self.width > image.size() || self.height > image.size() {
+            return matches;
+        }
+
+        for y in 0..=image.size() - self.height {
+            for x in 0..=image.size() - self.width {
+                if self.offsets.iter().all(|&(dx, dy)| image.get((x + dx, y + dy)) == Some('#')) {
+                    matches.push((x, y));
+                }
+            }
+        }
+
+        matches
+    }
+
+    /// The pixels covered by this pattern if it matched at `pos`
+    pub fn pixels(&self, (x, y): Pos) -> impl Iterator<Item=Pos> + '_ {
+        self.offsets.iter().map(move |&(dx, dy)| (x + dx, y + dy))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_orientations() {
+        let image = Image::from_lines(vec!["#..", "#..", "##."]);
+        assert_eq!(image.rotate().to_string(), "###\n#..\n...\n");
+        assert_eq!(image.flip().to_string(), "..#\n..#\n.##\n");
+        assert_eq!(image.rotate().rotate().rotate().rotate(), image);
+
+        let mut orientations = image.orientations();
+        orientations.sort_by_key(|i| i.to_string());
+        orientations.dedup();
+        assert_eq!(orientations.len(), 8);
+    }
+
+    #[test]
+    fn test_strip_border() {
+        let image = Image::from_lines(vec!["####", "#..#", "#.##", "####"]);
+        assert_eq!(image.strip_border().to_string(), "..\n.#\n");
+    }
+
+    #[test]
+    fn test_pattern() {
+        let pattern = Pattern::new(&["#.", ".#"]);
+        let image = Image::from_lines(vec!["#..", ".#.", "..#"]);
+        assert_eq!(pattern.find(&image), vec![(0, 0), (1, 1)]);
+        assert_eq!(pattern.pixels((1, 1)).collect::<Vec<_>>(), vec![(1, 1), (2, 2)]);
+    }
+}
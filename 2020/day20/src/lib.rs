@@ -0,0 +1,261 @@
+use std::collections::{HashSet, HashMap};
+use image::{Image, Pattern, Pos};
+use parsing::{ParseError, Span};
+use runner::Solution;
+
+mod image;
+
+type Input = Vec<Tile>;
+
+const SIZE: usize = 10;
+const ROUGH: char = '#';
+const SEA_MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Input;
+    type Part1 = u64;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, String> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<u64, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize, String> {
+        part2(input)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let tiles: Input = Span::new(input).records().map(Tile::parse).collect::<Result<_, _>>()?;
+
+    let n = (tiles.len() as f64).sqrt() as usize;
+    if n == 0 || n * n != tiles.len() {
+        return Err(Span::new(input).end().error(format!("Expected a square number of tiles, not {}", tiles.len())));
+    }
+
+    Ok(tiles)
+}
+
+fn part1(input: &Input) -> u64 {
+    let tile_edges: HashMap<u64, HashSet<String>> = input.iter().map(|t| (t.id, t.edges())).collect();
+
+    let mut corners = Vec::new();
+
+    for (&id, edges) in &tile_edges {
+        let common: HashSet<String> = tile_edges.iter()
+            .filter(|(&tile_id, _)| tile_id != id)
+            .flat_map(|(_, edges)| edges)
+            .cloned()
+            .collect();
+
+        let n = edges.intersection(&common).count();
+        if n == 4 {
+            corners.push(id);
+        }
+    }
+
+    corners.iter().product()
+}
+
+fn part2(input: &Input) -> Result<usize, String> {
+    let mosaic = assemble(input).ok_or_else(|| String::from("Tiles can't be arranged so that their edges match"))?;
+    let image = join(&mosaic);
+
+    Ok(water_roughness(&image))
+}
+
+/// Arrange the tiles into a square so that all adjacent edges match
+///
+/// Returns the oriented tiles in row-major order.
+fn assemble(tiles: &[Tile]) -> Option<Vec<Tile>> {
+    let n = (tiles.len() as f64).sqrt() as usize;
+    assert_eq!(n * n, tiles.len(), "Tiles must form a square");
+
+    let orientations: Vec<Vec<Tile>> = tiles.iter()
+        .map(|tile| tile.image.orientations().into_iter().map(|image| Tile { id: tile.id, image }).collect())
+        .collect();
+
+    let mut placed = Vec::with_capacity(tiles.len());
+    let mut used = vec![false; tiles.len()];
+    if place(n, &orientations, &mut placed, &mut used) {
+        Some(placed)
+    } else {
+        None
+    }
+}
+
+/// Backtracking search for a tile that fits the next position
+fn place(n: usize, orientations: &[Vec<Tile>], placed: &mut Vec<Tile>, used: &mut [bool]) -> bool {
+    if placed.len() == orientations.len() {
+        return true;
+    }
+
+    let (x, y) = (placed.len() % n, placed.len() / n);
+    for i in 0..orientations.len() {
+        if used[i] {
+            continue;
+        }
+
+        for tile in &orientations[i] {
+            if x > 0 && placed[placed.len() - 1].image.right() != tile.image.left() {
+                continue;
+            }
+            if y > 0 && placed[placed.len() - n].image.bottom() != tile.image.top() {
+                continue;
+            }
+
+            used[i] = true;
+            placed.push(tile.clone());
+            if place(n, orientations, placed, used) {
+                return true;
+            }
+            placed.pop();
+            used[i] = false;
+        }
+    }
+
+    false
+}
+
+/// Join assembled tiles into a single image, removing their borders
+fn join(mosaic: &[Tile]) -> Image {
+    let n = (mosaic.len() as f64).sqrt() as usize;
+    let inner: Vec<Image> = mosaic.iter().map(|tile| tile.image.strip_border()).collect();
+    let tile_size = inner[0].size();
+    let size = n * tile_size;
+
+    let pixels = (0..size)
+        .flat_map(|y| (0..size).map(move |x| (x, y)))
+        .map(|(x, y)| {
+            let tile = &inner[(y / tile_size) * n + x / tile_size];
+            tile.get((x % tile_size, y % tile_size)).unwrap()
+        })
+        .collect();
+
+    Image::new(size, pixels)
+}
+
+/// Number of rough water tiles that aren't part of a sea monster
+fn water_roughness(image: &Image) -> usize {
+    let monster = Pattern::new(&SEA_MONSTER);
+
+    // Only one orientation of the image should contain sea monsters
+    for image in image.orientations() {
+        let matches = monster.find(&image);
+        if matches.is_empty() {
+            continue;
+        }
+
+        // Sea monsters may overlap
+        let monster_pixels: HashSet<Pos> = matches.into_iter().flat_map(|pos| monster.pixels(pos)).collect();
+
+        return image.count(ROUGH) - monster_pixels.len();
+    }
+
+    image.count(ROUGH)
+}
+
+#[derive(Clone, Debug)]
+pub struct Tile {
+    id: u64,
+    image: Image,
+}
+
+impl Tile {
+    fn parse(record: Span) -> Result<Self, ParseError> {
+        let (header, image) = record.key_value("\n")?;
+        let id = header.between("Tile ", ":")?.parse("tile ID")?;
+
+        let lines: Vec<_> = image.lines().collect();
+        if let Some(line) = lines.iter().find(|line| line.as_str().chars().count() != SIZE) {
+            return Err(line.error(format!("Expected rows {} wide", SIZE)));
+        }
+        if lines.len() != SIZE {
+            return Err(image.end().error(format!("Expected {} rows", SIZE)));
+        }
+        let image = Image::from_lines(lines.iter().map(|line| line.as_str()));
+
+        Ok(Tile { id, image })
+    }
+
+    fn get(&self, pos: Pos) -> Option<char> {
+        self.image.get(pos)
+    }
+
+    fn edges(&self) -> HashSet<String> {
+        let mut edges = HashSet::new();
+
+        edges.insert((0..SIZE).map(|x| self.get((x, 0)).unwrap()).collect());
+        edges.insert((0..SIZE).map(|x| self.get((x, SIZE-1)).unwrap()).collect());
+        edges.insert((0..SIZE).map(|y| self.get((0, y)).unwrap()).collect());
+        edges.insert((0..SIZE).map(|y| self.get((SIZE-1, y)).unwrap()).collect());
+
+        // Flipped versions
+        edges.insert((0..SIZE).rev().map(|x| self.get((x, 0)).unwrap()).collect());
+        edges.insert((0..SIZE).rev().map(|x| self.get((x, SIZE-1)).unwrap()).collect());
+        edges.insert((0..SIZE).rev().map(|y| self.get((0, y)).unwrap()).collect());
+        edges.insert((0..SIZE).rev().map(|y| self.get((SIZE-1, y)).unwrap()).collect());
+
+        edges
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runner::read_input;
+
+    #[test]
+    fn test_part1() {
+        let input = read_input::<Day20>("sample1.txt");
+        assert_eq!(part1(&input), 20899048083289);
+    }
+
+    #[test]
+    fn test_assemble() {
+        let input = read_input::<Day20>("sample1.txt");
+        let mosaic = assemble(&input).unwrap();
+        let mut corners = vec![mosaic[0].id, mosaic[2].id, mosaic[6].id, mosaic[8].id];
+        corners.sort_unstable();
+        assert_eq!(corners, vec![1171, 1951, 2971, 3079]);
+        assert_eq!(join(&mosaic).size(), 24);
+    }
+
+    #[test]
+    fn test_sea_monsters() {
+        let input = read_input::<Day20>("sample1.txt");
+        let image = join(&assemble(&input).unwrap());
+        let monster = Pattern::new(&SEA_MONSTER);
+        let n_monsters: Vec<_> = image.orientations().iter().map(|i| monster.find(i).len()).collect();
+        assert_eq!(n_monsters.iter().sum::<usize>(), 2);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = read_input::<Day20>("sample1.txt");
+        assert_eq!(part2(&input), Ok(273));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let tile = |id| format!("Tile {}:\n{}", id, "#.........\n".repeat(SIZE));
+        let err = parse_input(&format!("{}\n{}", tile(1), tile(2))).unwrap_err();
+        assert_eq!((err.line(), err.message()), (24, "Expected a square number of tiles, not 2"));
+
+        let err = parse_input(&format!("{}\n{}", tile(1), tile(2).replace("#.........", "#........"))).unwrap_err();
+        assert_eq!((err.line(), err.message()), (14, "Expected rows 10 wide"));
+
+        let err = parse_input(&tile(1).replacen("#.........\n", "", 1)).unwrap_err();
+        assert_eq!(err.to_string(), "line 10, column 11: Expected 10 rows");
+    }
+}
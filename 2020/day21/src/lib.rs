@@ -0,0 +1,114 @@
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use runner::Solution;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<Food>;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, String> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, String> {
+        part2(input)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Food>, String> {
+    let re = Regex::new(r"(.*) \(contains (.*)\)").unwrap();
+
+    input
+        .lines()
+        .map(|line| {
+            let m = re.captures(&line).ok_or_else(|| format!("Failed to parse line: {:?}", line))?;
+            let ingredients = m[1].split_whitespace().map(|s| s.to_owned()).collect();
+            let allergens = m[2].split(", ").map(|s| s.to_owned()).collect();
+
+            Ok(Food { ingredients, allergens })
+        }).collect()
+}
+
+/// Ingredients that could contain each allergen
+fn allergen_candidates(input: &[Food]) -> HashMap<String, HashSet<String>> {
+    let mut allergens: HashMap<String, HashSet<String>> = HashMap::new();
+    for food in input {
+        for allergen in &food.allergens {
+            allergens.entry(allergen.clone())
+                .and_modify(|e| *e = e.intersection(&food.ingredients).cloned().collect())
+                .or_insert_with(|| food.ingredients.clone());
+        }
+    }
+
+    allergens
+}
+
+fn part1(input: &[Food]) -> usize {
+    let allergens = allergen_candidates(input);
+    let foods_with_allergens: HashSet<_> = allergens.iter().flat_map(|(_, f)| f.iter().cloned()).collect();
+
+    input.iter()
+        .flat_map(|f| f.ingredients.iter().cloned())
+        .filter(|ig| !foods_with_allergens.contains(ig))
+        .count()
+}
+
+fn part2(input: &[Food]) -> Result<String, String> {
+    let mut allergens = allergen_candidates(input);
+    let mut allergen_ingredients: HashMap<String, String> = HashMap::new();
+    while !allergens.is_empty() {
+        let (allergen, ingredient) = allergens.iter()
+            .find_map(|(a, is)| if is.len() == 1 { is.iter().next().map(|i| (a.clone(), i.clone())) } else { None })
+            .ok_or_else(|| format!("Can't work out which ingredients contain {} allergens", allergens.len()))?;
+        allergens.remove(&allergen);
+
+        for ingredients in allergens.values_mut() {
+            ingredients.remove(&ingredient);
+        }
+
+        allergen_ingredients.insert(allergen, ingredient);
+    }
+
+    let mut allergens: Vec<_> = allergen_ingredients.keys().cloned().collect();
+    allergens.sort();
+
+    let part2: Vec<_> = allergens.iter().map(|a| allergen_ingredients[a].clone()).collect();
+    Ok(part2.join(","))
+}
+
+#[derive(Debug, Clone)]
+pub struct Food {
+    ingredients: HashSet<String>,
+    allergens: HashSet<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runner::read_input;
+
+    #[test]
+    fn test_part1() {
+        let input = read_input::<Day21>("sample1.txt");
+        assert_eq!(part1(&input), 5);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = read_input::<Day21>("sample1.txt");
+        assert_eq!(part2(&input), Ok(String::from("mxmxvkd,sqjhc,fvjkl")));
+    }
+
+    #[test]
+    fn test_ambiguous() {
+        let input = parse_input("a b (contains x, y)\n").unwrap();
+        assert_eq!(part2(&input), Err(String::from("Can't work out which ingredients contain 2 allergens")));
+    }
+}
@@ -0,0 +1,259 @@
+use std::collections::{VecDeque, HashSet};
+use parsing::{ParseError, Span};
+use runner::Solution;
+
+const DEBUG: bool = false;
+
+type Decks = (VecDeque<u64>, VecDeque<u64>);
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Decks;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Decks, String> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1((deck1, deck2): &Decks) -> Result<u64, String> {
+        let mut combat = Combat::new(deck1.clone(), deck2.clone());
+        combat.play();
+
+        Ok(combat.score())
+    }
+
+    fn part2((deck1, deck2): &Decks) -> Result<u64, String> {
+        let mut combat = RecursiveCombat::new(deck1.clone(), deck2.clone());
+        combat.play();
+
+        Ok(combat.score())
+    }
+}
+
+fn parse_input(input: &str) -> Result<Decks, ParseError> {
+    let [player1, player2] = Span::new(input).sections()?;
+
+    Ok((parse_deck(player1)?, parse_deck(player2)?))
+}
+
+fn parse_deck(player: Span) -> Result<VecDeque<u64>, ParseError> {
+    let (_, cards) = player.key_value(":")?;
+
+    cards.trim().lines().map(|card| card.parse("card")).collect()
+}
+
+#[derive(Debug)]
+struct Combat {
+    deck1: VecDeque<u64>,
+    deck2: VecDeque<u64>,
+}
+
+impl Combat {
+    fn new(deck1: VecDeque<u64>, deck2: VecDeque<u64>) -> Self {
+        Combat { deck1, deck2 }
+    }
+
+    fn play(&mut self) {
+        let mut round = 0;
+        while !self.is_winner() {
+            round += 1;
+
+            if DEBUG {
+                println!("-- Round {} --", round);
+                println!("Player 1's deck: {:?}", self.deck1);
+                println!("Player 2's deck: {:?}", self.deck2);
+            }
+
+            self.play_round();
+            if DEBUG { println!(); }
+        }
+
+        if DEBUG {
+            println!();
+            println!("== Post-game results ==");
+            println!("Player 1's deck: {:?}", self.deck1);
+            println!("Player 2's deck: {:?}", self.deck2);
+        }
+    }
+
+    fn play_round(&mut self) {
+        let player1_card = self.deck1.pop_front().expect("No more cards!");
+        let player2_card = self.deck2.pop_front().expect("No more cards!");
+
+        if DEBUG {
+            println!("Player 1 plays: {}", player1_card);
+            println!("Player 2 plays: {}", player2_card);
+        }
+
+        if player1_card > player2_card {
+            if DEBUG { println!("Player 1 wins the round!"); }
+            self.deck1.push_back(player1_card);
+            self.deck1.push_back(player2_card);
+        } else {
+            if DEBUG { println!("Player 2 wins the round!"); }
+            self.deck2.push_back(player2_card);
+            self.deck2.push_back(player1_card);
+        }
+    }
+
+    fn is_winner(&self) -> bool {
+        self.deck1.is_empty() || self.deck2.is_empty()
+    }
+
+    fn score(&self) -> u64 {
+        let deck = if self.deck1.is_empty() { &self.deck2 } else { &self.deck1 };
+        let deck_size = deck.len();
+
+        deck.iter().enumerate().map(|(i, &n)| n * (deck_size - i) as u64).sum()
+    }
+}
+
+type State = (Vec<u64>, Vec<u64>);
+
+struct RecursiveCombat {
+    deck1: VecDeque<u64>,
+    deck2: VecDeque<u64>,
+    seen: HashSet<State>,
+    game: u64,
+}
+
+impl RecursiveCombat {
+    fn new(deck1: VecDeque<u64>, deck2: VecDeque<u64>) -> Self {
+        RecursiveCombat { deck1, deck2, seen: HashSet::new(), game: 0 }
+    }
+
+    fn play(&mut self) -> u32 {
+        let mut round = 0;
+        while !self.is_winner() {
+            round += 1;
+
+            if DEBUG && self.game == 0 {
+                println!("-- Round {} (Game {}) --", round, self.game);
+                println!("Player 1's deck: {:?}", self.deck1);
+                println!("Player 2's deck: {:?}", self.deck2);
+            }
+
+            self.play_round();
+
+            if DEBUG && self.game == 0 {
+                println!();
+            }
+        }
+
+        if DEBUG && self.game == 0 {
+            println!();
+            println!("== Post-game results ==");
+            println!("Player 1's deck: {:?}", self.deck1);
+            println!("Player 2's deck: {:?}", self.deck2);
+        }
+
+        if self.deck2.is_empty() {
+            1
+        } else {
+            2
+        }
+    }
+
+    fn play_round(&mut self) {
+        let state = self.state();
+        let player1_card = self.deck1.pop_front().expect("No more cards!");
+        let player2_card = self.deck2.pop_front().expect("No more cards!");
+
+        if self.seen.contains(&state) {
+            // Duplicate state - Player 1 wins by default
+            self.deck1.push_back(player1_card);
+            self.deck1.push_back(player2_card);
+            return;
+        }
+
+        let winner = if self.deck1.len() as u64 >= player1_card && self.deck2.len() as u64 >= player2_card {
+            // Recursive combat!!!
+            let deck1: VecDeque<_> = self.deck1.iter().take(player1_card as usize).copied().collect();
+            let deck2: VecDeque<_> = self.deck2.iter().take(player2_card as usize).copied().collect();
+
+            let mut combat = RecursiveCombat {
+                deck1,
+                deck2,
+                seen: HashSet::new(),
+                game: self.game + 1,
+            };
+
+            combat.play()
+        } else {
+            // Regular combat
+            if DEBUG && self.game == 0 {
+                println!("Player 1 plays: {}", player1_card);
+                println!("Player 2 plays: {}", player2_card);
+            }
+
+            if player1_card > player2_card {
+                1
+            } else {
+                2
+            }
+        };
+
+        if DEBUG && self.game == 0 {
+            println!("Player {} wins the round!", winner);
+        }
+
+        match winner {
+            1 => {
+                self.seen.insert(state.clone());
+                self.deck1.push_back(player1_card);
+                self.deck1.push_back(player2_card);
+            },
+            2 => {
+                self.seen.insert(state.clone());
+                self.deck2.push_back(player2_card);
+                self.deck2.push_back(player1_card);
+            },
+            _ => panic!("Unknown winning player {}", winner),
+        }
+    }
+
+    fn state(&self) -> State {
+        let deck1: Vec<_> = self.deck1.iter().copied().collect();
+        let deck2: Vec<_> = self.deck2.iter().copied().collect();
+
+        (deck1, deck2)
+    }
+
+    fn is_winner(&self) -> bool {
+        self.deck1.is_empty() || self.deck2.is_empty()
+    }
+
+    fn score(&self) -> u64 {
+        let deck = if self.deck1.is_empty() { &self.deck2 } else { &self.deck1 };
+        let deck_size = deck.len();
+
+        deck.iter().enumerate().map(|(i, &n)| n * (deck_size - i) as u64).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runner::read_input;
+
+    #[test]
+    fn test_part1() {
+        let (deck1, deck2) = read_input::<Day22>("sample1.txt");
+        let mut combat = Combat::new(deck1.clone(), deck2.clone());
+        combat.play();
+
+        assert_eq!(combat.score(), 306);
+    }
+
+    #[test]
+    fn test_part2() {
+        let (deck1, deck2) = read_input::<Day22>("sample1.txt");
+        let mut combat = RecursiveCombat::new(deck1.clone(), deck2.clone());
+        combat.play();
+
+        assert_eq!(combat.score(), 291);
+    }
+}
+
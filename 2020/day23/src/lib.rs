@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+use runner::Solution;
+
+type Label = u64;
+
+/// The current cup, the 3 cups picked up and somewhere to put them
+const MIN_CUPS: usize = 5;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Vec<Label>;
+    type Part1 = String;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, String> {
+        parse_input(input)
+    }
+
+    fn part1(cups: &Self::Input) -> Result<String, String> {
+        Ok(cups_as_string(&part1(cups, 100)))
+    }
+
+    fn part2(cups: &Self::Input) -> Result<u64, String> {
+        Ok(part2(cups, 10_000_000).into_iter().product())
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Label>, String> {
+    let cups: Vec<Label> = input.trim().chars()
+        .map(|c| c.to_digit(10).map(|n| n as Label).ok_or_else(|| format!("Not a cup label: {:?}", c)))
+        .collect::<Result<_, _>>()?;
+
+    if cups.len() < MIN_CUPS {
+        return Err(format!("Expected at least {} cups, not {}", MIN_CUPS, cups.len()));
+    }
+
+    // Each label from 1 up to the number of cups, once
+    let mut labels = cups.clone();
+    labels.sort_unstable();
+    if !labels.into_iter().eq(1..=cups.len() as Label) {
+        return Err(format!("Expected cups labelled 1 to {} in any order", cups.len()));
+    }
+
+    Ok(cups)
+}
+
+fn part1(cups: &[Label], turns: usize) -> Vec<Label> {
+    let mut circle = CupCircle::new(cups);
+
+    // First cup is the current cup
+    let mut current = cups[0];
+
+    for _ in 1..=turns {
+        // Pick up 3 cups immediately clockwise of the current cup
+        // These are removed from the circle.
+        let pickup = circle.remove(current, 3);
+
+        // Select a destination cup - the one with label minus 1
+        // Skip cups that have been picked up and the labels wrap around
+        let mut destination = current;
+        loop {
+            destination -= 1;
+            if destination == 0 {
+                destination = cups.len() as Label;
+            }
+
+            if !pickup.contains(&destination) {
+                break;
+            }
+        }
+
+        // Place the picked up cups immediately clockwise of the destination cup
+        circle.insert(destination, pickup);
+
+        // Select a new current cup - the one immediately clockwise of the current cup
+        current = circle.next_cup(current);
+    }
+
+    circle.cups_from(1).into_iter().skip(1).collect()
+}
+
+fn part2(cups: &[Label], turns: usize) -> Vec<Label> {
+    let cups: Vec<_> = cups.iter().copied().chain(cups.len() as Label + 1..=1_000_000).collect();
+    let mut circle = CupCircle::new(&cups);
+
+    // First cup is the current cup
+    let mut current = cups[0];
+
+    for _ in 1..=turns {
+        // Pick up 3 cups immediately clockwise of the current cup
+        // These are removed from the circle.
+        let pickup = circle.remove(current, 3);
+
+        // Select a destination cup - the one with label minus 1
+        // Skip cups that have been picked up and the labels wrap around
+        let mut destination = current;
+        loop {
+            destination -= 1;
+            if destination == 0 {
+                destination = cups.len() as Label;
+            }
+
+            if !pickup.contains(&destination) {
+                break;
+            }
+        }
+
+        // Place the picked up cups immediately clockwise of the destination cup
+        circle.insert(destination, pickup);
+
+        // Select a new current cup - the one immediately clockwise of the current cup
+        current = circle.next_cup(current);
+    }
+
+    circle.cups_from(1).into_iter().skip(1).take(2).collect()
+}
+
+fn cups_as_string(cups: &[Label]) -> String {
+    cups.iter().map(|c| c.to_string()).collect()
+}
+
+struct CupCircle {
+    next: HashMap<Label, Label>,
+}
+
+impl CupCircle {
+    fn new(cups: &[Label]) -> Self {
+        let next = (0..cups.len()).map(|i| (cups[i], cups[(i + 1) % cups.len()])).collect();
+
+        CupCircle { next }
+    }
+
+    /// Get the next cup clockwise in the circle.
+    fn next_cup(&self, cup: Label) -> Label {
+        *self.next.get(&cup).expect("Unknown cup label")
+    }
+
+    /// Insert `cups` immediately clockwise of `cup`
+    fn insert(&mut self, cup: Label, cups: Vec<Label>) {
+        let mut next = self.next_cup(cup);
+        for label in cups.into_iter().rev() {
+            self.next.insert(label, next);
+            next = label;
+        }
+
+        self.next.insert(cup, next);
+    }
+
+    /// All cups starting at `cup`.
+    fn cups_from(&self, cup: Label) -> Vec<Label> {
+        let mut cups = vec![cup];
+
+        let mut next = self.next_cup(cup);
+        while next != cup {
+            cups.push(next);
+            next = self.next_cup(next);
+        }
+
+        cups
+    }
+
+    /// Remove `n` cups immediately clockwise of `cup`.
+    fn remove(&mut self, cup: Label, n: usize) -> Vec<Label> {
+        let mut cups = Vec::new();
+
+        let mut next = self.next_cup(cup);
+        for _ in 0..n {
+            cups.push(next);
+            next = self.next_cup(next);
+        }
+
+        self.next.insert(cup, next);
+
+        cups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runner::read_input;
+
+    #[test]
+    fn test_part1() {
+        let cups = read_input::<Day23>("sample1.txt");
+        assert_eq!(part1(&cups, 10), [9, 2, 6, 5, 8, 3, 7, 4]);
+        assert_eq!(part1(&cups, 100), [6, 7, 3, 8, 4, 5, 2, 9]);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse_input("35142\n"), Ok(vec![3, 5, 1, 4, 2]));
+        assert_eq!(parse_input(""), Err(String::from("Expected at least 5 cups, not 0")));
+        assert_eq!(parse_input("12"), Err(String::from("Expected at least 5 cups, not 2")));
+        assert_eq!(parse_input("35102"), Err(String::from("Expected cups labelled 1 to 5 in any order")));
+        assert_eq!(parse_input("35143"), Err(String::from("Expected cups labelled 1 to 5 in any order")));
+        assert_eq!(parse_input("35146"), Err(String::from("Expected cups labelled 1 to 5 in any order")));
+    }
+
+    #[test]
+    fn test_part2() {
+        let cups = read_input::<Day23>("sample1.txt");
+        let result = part2(&cups, 10_000_000);
+        assert_eq!(result, [934001, 159792]);
+        assert_eq!(result.into_iter().product::<u64>(), 149245887792);
+    }
+}
+
@@ -0,0 +1,131 @@
+use std::collections::HashSet;
+use automaton::{Automaton, Sparse, life_rule};
+use hex::Cube;
+use hex::pointy::Direction;
+use runner::Solution;
+
+const NUM_DAYS: u64 = 100;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Vec<Direction>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, String> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize, String> {
+        Ok(part2(input))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<Direction>>, String> {
+    input.lines()
+        .map(|line| Direction::parse_run(line).map_err(|err| err.to_string()))
+        .collect()
+}
+
+fn part1(input: &[Vec<Direction>]) -> usize {
+    let mut map = Map::new();
+
+    for directions in input {
+        map.flip(hexpos(directions));
+    }
+
+    map.count()
+}
+
+fn part2(input: &[Vec<Direction>]) -> usize {
+    let mut map = Map::new();
+
+    // Apply the initial layout
+    for directions in input {
+        map.flip(hexpos(directions));
+    }
+
+    // Black tiles with zero or more than 2 adjacent black tiles are flipped to white
+    // and white tiles with exactly 2 adjacent black tiles are flipped to black
+    let mut automaton = Sparse::new(map.black_tiles, Cube::neighbours, life_rule(&[2], &[1, 2]));
+    while automaton.generation() < NUM_DAYS {
+        automaton.step();
+    }
+    map.black_tiles = automaton.into_live();
+
+    map.count()
+}
+
+/// Calculate the position from the origin.
+fn hexpos(directions: &[Direction]) -> Pos {
+    let mut pos = Cube::origin();
+    for d in directions {
+        pos += d.offset();
+    }
+
+    pos
+}
+
+type Pos = Cube;
+
+#[derive(Debug, Clone)]
+struct Map {
+    black_tiles: HashSet<Pos>,
+}
+
+impl Map {
+    fn new() -> Self {
+        Map { black_tiles: HashSet::new() }
+    }
+
+    /// Flip a tile
+    fn flip(&mut self, pos: Pos) {
+        if self.black_tiles.contains(&pos) {
+            self.black_tiles.remove(&pos);
+        } else {
+            self.black_tiles.insert(pos);
+        }
+    }
+
+    /// Count number of black tiles
+    fn count(&self) -> usize {
+        self.black_tiles.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runner::read_input;
+    use hex::Axial;
+
+    #[test]
+    fn test_hexpos1() {
+        use Direction::*;
+        assert_eq!(Axial::from(hexpos(&[East, SouthEast, West])), Axial::new(0, 1))
+    }
+
+    #[test]
+    fn test_hexpos2() {
+        use Direction::*;
+        assert_eq!(Axial::from(hexpos(&[NorthWest, West, SouthWest, East, East])), Axial::new(0, 0))
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = read_input::<Day24>("sample1.txt");
+        assert_eq!(part1(&input), 10);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = read_input::<Day24>("sample1.txt");
+        assert_eq!(part2(&input), 2208);
+    }
+}
+
@@ -0,0 +1,92 @@
+use runner::Solution;
+
+type Input = Vec<u64>;
+
+const BASE: u64 = 7;
+const PRIME: u64 = 20201227;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Input;
+    type Part1 = u64;
+    /// There's no puzzle for part 2 on the last day
+    type Part2 = &'static str;
+
+    fn parse(input: &str) -> Result<Input, String> {
+        let keys: Input = input.lines()
+            .map(|line| match line.parse() {
+                Ok(key) if key > 0 && key < PRIME => Ok(key),
+                _ => Err(format!("Bad public key {:?}", line)),
+            })
+            .collect::<Result<_, _>>()?;
+
+        if keys.len() != 2 {
+            return Err(format!("Expected 2 public keys, not {}", keys.len()));
+        }
+
+        Ok(keys)
+    }
+
+    fn part1(input: &Input) -> Result<u64, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(_input: &Input) -> Result<&'static str, String> {
+        Ok("Merry Christmas!")
+    }
+}
+
+/// Find `loop_number` of transforms applied to `subject_number` to get `x`.
+fn find_loop_number(x: u64, subject_number: u64) -> u64 {
+    let mut val = 1;
+    for n in 1.. {
+        val = (val * subject_number) % PRIME;
+        if val == x {
+            return n;
+        }
+    }
+    unreachable!()
+}
+
+/// Apply transform to `subject_number` `loop_number` of times.
+fn transform(subject_number: u64, loop_number: u64) -> u64 {
+    let mut val = 1;
+    for _ in 0..loop_number {
+        val = (val * subject_number) % PRIME;
+    }
+
+    val
+}
+
+fn part1(input: &Input) -> u64 {
+    let public1 = input[0];
+    let public2 = input[1];
+
+    let private1 = find_loop_number(public1, BASE);
+    transform(public2, private1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_loops() {
+        assert_eq!(find_loop_number(5764801, BASE), 8);
+        assert_eq!(find_loop_number(17807724, BASE), 11);
+    }
+
+    #[test]
+    fn test_transform() {
+        assert_eq!(transform(BASE, 8), 5764801);
+        assert_eq!(transform(BASE, 11), 17807724);
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = vec![5764801, 17807724];
+        assert_eq!(part1(&input), 14897079);
+    }
+}
+
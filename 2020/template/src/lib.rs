@@ -0,0 +1,45 @@
+use runner::Solution;
+
+type Input = Vec<String>;
+
+pub struct DayXX;
+
+impl Solution for DayXX {
+    type Input = Input;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Input, String> {
+        Ok(input.lines()
+            .map(|line| line.to_owned())
+            .collect())
+    }
+
+    fn part1(input: &Input) -> Result<i64, String> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Input) -> Result<i64, String> {
+        Ok(part2(input))
+    }
+}
+
+fn part1(input: &Input) -> i64 {
+    0
+}
+
+fn part2(input: &Input) -> i64 {
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runner::read_input;
+
+    #[test]
+    fn test_part1() {
+        let input = read_input::<DayXX>("sample1.txt");
+        assert_eq!(part1(&input), 0);
+    }
+}
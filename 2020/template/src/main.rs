@@ -1,35 +1,5 @@
-use std::path::Path;
-use std::fs;
-
-type Input = Vec<String>;
+use aocYYYY_dayXX::DayXX;
 
 fn main() {
-    let input = read_input("input.txt");
-    println!("> {:?}", input);
-
-    println!("Part 1: {}", part1(&input));
-}
-
-fn read_input<T: AsRef<Path>>(path: T) -> Input {
-    let input = fs::read_to_string(path).expect("Failed to read input");
-
-    input.lines()
-        .map(|line| line.to_owned())
-        .collect()
+    runner::main::<DayXX>(runner::locator!());
 }
-
-fn part1(input: &Input) -> i64 {
-    0
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part1() {
-        let input = read_input("sample1.txt");
-        assert_eq!(part1(&input), 0);
-    }
-}
-
@@ -0,0 +1,147 @@
+//! The arcade cabinet of 2019 day 13, played on the canvas.
+//!
+//! The arrow keys move the joystick. Turning off the autopilot hands control to the player,
+//! and a finished game can be replayed from its recorded joystick moves.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use aoc2019_day13::game::{ArcadeCabinet, Controller, GameState, Tile, HEIGHT, WIDTH};
+use intcode::emulator::{Program, Word};
+use wasm_bindgen::prelude::*;
+
+use crate::render::{self, Palette, Simulation};
+use crate::{log, page};
+
+/// The ball, which is round
+const BALL: &[&str] = &[
+    "........",
+    "..####..",
+    ".######.",
+    ".######.",
+    ".######.",
+    ".######.",
+    "..####..",
+    "........",
+];
+
+/// A block, with a gap around it so the rows are easy to see
+const BLOCK: &[&str] = &[
+    "........",
+    ".######.",
+    ".######.",
+    ".######.",
+    ".######.",
+    ".######.",
+    ".######.",
+    "........",
+];
+
+struct Game {
+    arcade: ArcadeCabinet,
+    score: Word,
+}
+
+thread_local! {
+    static STATE: RefCell<Option<Rc<RefCell<GameState>>>> = const { RefCell::new(None) };
+    /// Joystick moves of the last game that was finished
+    static RECORDING: RefCell<Vec<Word>> = const { RefCell::new(Vec::new()) };
+    static PROGRAM: RefCell<Option<Program>> = const { RefCell::new(None) };
+}
+
+impl Simulation for Game {
+    fn size(&self) -> (usize, usize) {
+        (WIDTH, HEIGHT)
+    }
+
+    fn tile(&self, x: usize, y: usize) -> char {
+        self.arcade.state().borrow().tile((x as Word, y as Word)).to_char()
+    }
+
+    fn tick(&mut self) -> bool {
+        let running = match self.arcade.frame() {
+            Ok(running) => running,
+            Err(exception) => {
+                log!("Game crashed: {}", exception);
+                false
+            },
+        };
+
+        let state = self.arcade.state();
+        let mut state = state.borrow_mut();
+        // Only the score is shown outside of the canvas
+        state.updates();
+        if state.score() != self.score {
+            self.score = state.score();
+            page::set_text("score", &format!("SCORE: {:08}", self.score));
+        }
+
+        if !running {
+            log!("Game over! Final score: {}", self.score);
+            RECORDING.with(|recording| recording.replace(state.moves().to_vec()));
+        }
+
+        running
+    }
+}
+
+/// Start a game of `program` with a controller
+async fn play(program: Program, controller: Controller) {
+    let arcade = ArcadeCabinet::new(&program, true);
+    arcade.state().borrow_mut().set_controller(controller);
+    STATE.with(|state| state.replace(Some(arcade.state())));
+    PROGRAM.with(|current| current.replace(Some(program)));
+
+    let palette = Palette::new("#0f0f23", "#cccccc")
+        .fill(Tile::Empty.to_char(), "#0f0f23")
+        .fill(Tile::Wall.to_char(), "#606060")
+        .sprite(Tile::Block.to_char(), BLOCK, "#009900")
+        .fill(Tile::Paddle.to_char(), "#ffff66")
+        .sprite(Tile::Ball.to_char(), BALL, "#ff66ff");
+    page::set_text("score", &format!("SCORE: {:08}", 0));
+
+    render::play(Box::new(Game { arcade, score: 0 }), palette).await;
+}
+
+/// The state of the game being played, if there is one
+fn state() -> Option<Rc<RefCell<GameState>>> {
+    STATE.with(|state| state.borrow().clone())
+}
+
+/// Start a game on the autopilot
+#[wasm_bindgen]
+pub async fn arcade_play(program: String) -> Result<(), JsValue> {
+    let program = Program::parse(&program).map_err(|err| JsValue::from_str(&err))?;
+    play(program, Controller::Autopilot).await;
+
+    Ok(())
+}
+
+/// Replay the joystick moves of the last game that was finished
+#[wasm_bindgen]
+pub async fn arcade_replay() {
+    let program = match PROGRAM.with(|program| program.borrow().clone()) {
+        Some(program) => program,
+        None => return,
+    };
+    let moves = RECORDING.with(|recording| recording.borrow().clone());
+    log!("Replaying {} moves", moves.len());
+
+    play(program, Controller::Replay(moves.into())).await;
+}
+
+/// Turn the autopilot on, or off to let the player take over
+#[wasm_bindgen]
+pub fn arcade_autopilot(on: bool) {
+    if let Some(state) = state() {
+        state.borrow_mut().set_controller(if on { Controller::Autopilot } else { Controller::Player });
+    }
+}
+
+/// Move the joystick left (`-1`), right (`1`) or back to neutral (`0`)
+#[wasm_bindgen]
+pub fn arcade_joystick(stick: i32) {
+    if let Some(state) = state() {
+        state.borrow_mut().set_stick(stick.into());
+    }
+}
@@ -0,0 +1,246 @@
+//! Intcode computer running in the page.
+//!
+//! ASCII output is written to the console and input is queued a line at a time.
+//! Running steps the computer in slices, sleeping between them so the page stays responsive.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt::Write;
+use std::io;
+use std::rc::Rc;
+
+use intcode::emulator::{Exception, IntcodeEmulator, Program, Word};
+use wasm_bindgen::prelude::*;
+
+use crate::{log, page, util};
+
+/// Instructions executed before yielding to the event loop
+const STEPS_PER_SLICE: usize = 10_000;
+
+/// How often to check for input while waiting for it
+const INPUT_POLL_MS: u32 = 50;
+
+/// Rows of 8 words shown in the memory view
+const MEMORY_ROWS: usize = 16;
+
+thread_local! {
+    static COMPUTER: RefCell<Option<Rc<RefCell<Computer>>>> = const { RefCell::new(None) };
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum State {
+    Stopped,
+    Running,
+    WaitingForInput,
+    Halted,
+    Crashed,
+}
+
+struct Computer {
+    cpu: IntcodeEmulator,
+    input: Rc<RefCell<VecDeque<Word>>>,
+    output: Rc<RefCell<String>>,
+    state: State,
+    /// Counts calls of [`intcode_run`], so a loop left over from an earlier one knows to stop
+    generation: u32,
+}
+
+impl Computer {
+    fn new(program: &Program) -> Self {
+        let input = Rc::new(RefCell::new(VecDeque::new()));
+        let output = Rc::new(RefCell::new(String::new()));
+
+        let input_buffer = Rc::clone(&input);
+        let output_buffer = Rc::clone(&output);
+        let mut cpu = IntcodeEmulator::new(
+            Box::new(move |_| {
+                input_buffer.borrow_mut().pop_front()
+                    .ok_or_else(|| io::Error::from(io::ErrorKind::WouldBlock))
+            }),
+            Box::new(move |_, word| {
+                let mut output = output_buffer.borrow_mut();
+                match word {
+                    0x0A => log!("{}", output.drain(..).collect::<String>()),
+                    0x00..=0x7F => output.push(word as u8 as char),
+                    _ => {
+                        // Not a character, so show the value on a line of its own
+                        if !output.is_empty() {
+                            log!("{}", output.drain(..).collect::<String>());
+                        }
+                        log!("{}", word);
+                    },
+                }
+
+                Ok(())
+            }),
+        );
+        cpu.load_program(program);
+
+        Computer { cpu, input, output, state: State::Stopped, generation: 0 }
+    }
+
+    /// Execute up to `steps` instructions, returning the state afterwards
+    fn run(&mut self, steps: usize) -> State {
+        for _ in 0..steps {
+            if self.state != State::Running {
+                break;
+            }
+            self.step();
+        }
+        self.flush();
+
+        self.state
+    }
+
+    /// Execute a single instruction
+    fn step(&mut self) {
+        if self.cpu.is_halted() {
+            self.state = State::Halted;
+            return;
+        }
+
+        match self.cpu.step() {
+            Ok(()) | Err(Exception::Yield) => (),
+            Err(Exception::IOError(err)) if err.kind() == io::ErrorKind::WouldBlock => {
+                self.state = State::WaitingForInput;
+            },
+            Err(exception) => {
+                log!("{}", exception);
+                self.state = State::Crashed;
+            },
+        }
+    }
+
+    /// Write out a partial line of output (e.g. a prompt)
+    fn flush(&mut self) {
+        let mut output = self.output.borrow_mut();
+        if !output.is_empty() {
+            log!("{}", output.drain(..).collect::<String>());
+        }
+    }
+
+    /// Show the registers and memory in the page
+    fn show(&self) {
+        let mut registers = format!("ip:0x{:08x} rb:{} {:?}\n", self.cpu.ip(), self.cpu.rb(), self.state);
+        let _ = write!(registers, "{:08x} {}", self.cpu.ip(),
+                       self.cpu.disassemble().unwrap_or_else(|_| String::from("???")));
+        page::set_text("registers", &registers);
+
+        // A window of memory around the instruction pointer
+        let mem = self.cpu.mem();
+        let start = (self.cpu.ip() & !0b111).saturating_sub(8 * (MEMORY_ROWS / 4));
+        let mut memory = String::new();
+        for addr in (start..mem.len()).step_by(8).take(MEMORY_ROWS) {
+            let flag = if addr == (self.cpu.ip() & !0b111) { '>' } else { ' ' };
+            let line: Vec<_> = mem[addr..mem.len().min(addr + 8)].iter().enumerate()
+                .map(|(offset, &val)| {
+                    let flag = if addr + offset == self.cpu.ip() { '←' } else { ' ' };
+                    format!("{:-11}{}", val, flag)
+                }).collect();
+            let _ = writeln!(memory, "{} {:08x} {}", flag, addr, line.join(" "));
+        }
+        page::set_text("memory", &memory);
+    }
+}
+
+/// The loaded computer, if there is one
+fn computer() -> Option<Rc<RefCell<Computer>>> {
+    COMPUTER.with(|computer| computer.borrow().clone())
+}
+
+/// Load a program, replacing (and stopping) any previous one
+#[wasm_bindgen]
+pub fn intcode_load(program: &str) -> Result<(), JsValue> {
+    let program = Program::parse(program).map_err(|err| JsValue::from_str(&err))?;
+    let computer = Computer::new(&program);
+    computer.show();
+
+    let previous = COMPUTER.with(|current| current.replace(Some(Rc::new(RefCell::new(computer)))));
+    if let Some(previous) = previous {
+        previous.borrow_mut().state = State::Stopped;
+    }
+
+    Ok(())
+}
+
+/// Run the loaded program until it halts, crashes or is stopped
+#[wasm_bindgen]
+pub async fn intcode_run() {
+    let computer = match computer() {
+        Some(computer) => computer,
+        None => return,
+    };
+
+    let generation = {
+        let mut computer = computer.borrow_mut();
+        if computer.state != State::Stopped {
+            return;
+        }
+        computer.state = State::Running;
+        computer.generation = computer.generation.wrapping_add(1);
+        computer.generation
+    };
+
+    loop {
+        let state = computer.borrow_mut().run(STEPS_PER_SLICE);
+        match state {
+            State::Running => util::sleep(0).await,
+            State::WaitingForInput => {
+                computer.borrow().show();
+                util::sleep(INPUT_POLL_MS).await;
+            },
+            _ => break,
+        }
+
+        let mut computer = computer.borrow_mut();
+        // Stopped and run again while this loop slept, so another loop runs it now
+        if computer.generation != generation {
+            return;
+        }
+
+        // Resume once some input has been queued
+        if computer.state == State::WaitingForInput && !computer.input.borrow().is_empty() {
+            computer.state = State::Running;
+        }
+    }
+
+    computer.borrow().show();
+}
+
+/// Execute a single instruction of a stopped program
+#[wasm_bindgen]
+pub fn intcode_step() {
+    if let Some(computer) = computer() {
+        let mut computer = computer.borrow_mut();
+        if computer.state == State::Stopped {
+            computer.step();
+            if computer.state == State::WaitingForInput {
+                computer.state = State::Stopped;
+            }
+            computer.flush();
+        }
+        computer.show();
+    }
+}
+
+/// Stop a running program
+#[wasm_bindgen]
+pub fn intcode_stop() {
+    if let Some(computer) = computer() {
+        let mut computer = computer.borrow_mut();
+        if let State::Running | State::WaitingForInput = computer.state {
+            computer.state = State::Stopped;
+        }
+        computer.show();
+    }
+}
+
+/// Queue a line of ASCII input
+#[wasm_bindgen]
+pub fn intcode_input(line: &str) {
+    if let Some(computer) = computer() {
+        log!("{}", line);
+        let computer = computer.borrow();
+        computer.input.borrow_mut().extend(line.chars().chain("\n".chars()).map(|c| c as Word));
+    }
+}
@@ -1,4 +1,9 @@
+mod arcade;
+mod computer;
 mod page;
+mod render;
+mod seating;
+mod solutions;
 mod util;
 
 use std::f64;
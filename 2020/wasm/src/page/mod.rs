@@ -28,6 +28,13 @@ pub fn log(s: &str) {
     console.set_scroll_top(console.scroll_height());
 }
 
+/// Replace the text of an element.
+pub fn set_text(id: &str, text: &str) {
+    let document = web_sys::window().unwrap().document().unwrap();
+    document.get_element_by_id(id).unwrap()
+        .set_text_content(Some(text));
+}
+
 #[macro_export]
 macro_rules! log {
     ($($v: expr),*) => { crate::page::log(&format!($($v),*)) };
@@ -0,0 +1,342 @@
+//! Drawing grids of tiles on the canvas.
+//!
+//! A [`Simulation`] is a grid of tile characters that changes a frame at a time.
+//! [`Renderer`] draws it through a [`Camera`], which can be panned and zoomed,
+//! looking up how to draw each tile in a [`Palette`].
+//! [`play`] makes it the current animation, which the page controls with the `canvas_*` functions.
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use wasm_bindgen::prelude::*;
+use web_sys::CanvasRenderingContext2d;
+
+use crate::{page, util};
+
+/// Frames per second, unless changed
+const DEFAULT_FPS: f64 = 10.0;
+
+/// Smallest and largest size of a tile, in pixels
+const MIN_ZOOM: f64 = 1.0;
+const MAX_ZOOM: f64 = 64.0;
+
+thread_local! {
+    static ANIMATION: RefCell<Option<Rc<RefCell<Animation>>>> = const { RefCell::new(None) };
+    static FPS: Cell<f64> = const { Cell::new(DEFAULT_FPS) };
+}
+
+/// A grid of tiles that can be animated
+pub trait Simulation {
+    /// Size of the grid in tiles (`width`, `height`)
+    fn size(&self) -> (usize, usize);
+
+    /// The tile at a position
+    fn tile(&self, x: usize, y: usize) -> char;
+
+    /// Advance by one frame, returning whether anything changed
+    fn tick(&mut self) -> bool;
+}
+
+/// How to draw a tile
+#[derive(Clone, Debug)]
+pub enum Style {
+    /// Fill the tile with a colour
+    Fill(String),
+    /// Draw a character in a colour
+    Glyph(char, String),
+    /// Draw the `#` pixels of a small picture in a colour
+    Sprite(&'static [&'static str], String),
+}
+
+/// How to draw each kind of tile
+#[derive(Clone, Debug)]
+pub struct Palette {
+    background: String,
+    foreground: String,
+    styles: HashMap<char, Style>,
+}
+
+impl Palette {
+    /// A palette that draws each tile as its own character
+    pub fn new(background: &str, foreground: &str) -> Self {
+        Palette { background: background.to_owned(), foreground: foreground.to_owned(), styles: HashMap::new() }
+    }
+
+    /// Draw `tile` with `style`
+    pub fn with(mut self, tile: char, style: Style) -> Self {
+        self.styles.insert(tile, style);
+        self
+    }
+
+    /// Fill `tile` with `colour`
+    pub fn fill(self, tile: char, colour: &str) -> Self {
+        self.with(tile, Style::Fill(colour.to_owned()))
+    }
+
+    /// Draw the `#` pixels of `sprite` in `colour`
+    pub fn sprite(self, tile: char, sprite: &'static [&'static str], colour: &str) -> Self {
+        self.with(tile, Style::Sprite(sprite, colour.to_owned()))
+    }
+
+    fn style(&self, tile: char) -> Style {
+        self.styles.get(&tile).cloned()
+            .unwrap_or_else(|| Style::Glyph(tile, self.foreground.clone()))
+    }
+}
+
+/// Which part of the grid is visible
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Camera {
+    /// Position of the top-left corner of the canvas, in tiles
+    pub x: f64,
+    pub y: f64,
+    /// Size of a tile, in pixels
+    pub zoom: f64,
+}
+
+impl Camera {
+    /// Show a grid of `size` tiles as large as it fits on a `canvas` (in pixels), centered
+    pub fn fit((width, height): (usize, usize), (canvas_width, canvas_height): (f64, f64)) -> Self {
+        let zoom = (canvas_width / width.max(1) as f64)
+            .min(canvas_height / height.max(1) as f64)
+            .clamp(MIN_ZOOM, MAX_ZOOM);
+        let x = (width as f64 - canvas_width / zoom) / 2.0;
+        let y = (height as f64 - canvas_height / zoom) / 2.0;
+
+        Camera { x, y, zoom }
+    }
+
+    /// Move the view by a number of pixels
+    pub fn pan(&mut self, dx: f64, dy: f64) {
+        self.x -= dx / self.zoom;
+        self.y -= dy / self.zoom;
+    }
+
+    /// Zoom by `factor`, keeping the point at pixel (`x`, `y`) in place
+    pub fn zoom_at(&mut self, factor: f64, x: f64, y: f64) {
+        let zoom = (self.zoom * factor).clamp(MIN_ZOOM, MAX_ZOOM);
+        self.x += x / self.zoom - x / zoom;
+        self.y += y / self.zoom - y / zoom;
+        self.zoom = zoom;
+    }
+
+    /// Pixel position of the top-left corner of a tile
+    fn screen_pos(&self, x: usize, y: usize) -> (f64, f64) {
+        ((x as f64 - self.x) * self.zoom, (y as f64 - self.y) * self.zoom)
+    }
+}
+
+/// Draws simulations on a canvas
+pub struct Renderer {
+    ctx: CanvasRenderingContext2d,
+    palette: Palette,
+    pub camera: Camera,
+}
+
+impl Renderer {
+    /// A renderer for the page's canvas, fitting a grid of `size` tiles
+    pub fn new(palette: Palette, size: (usize, usize)) -> Self {
+        let ctx = page::canvas_context_2d();
+        let camera = Camera::fit(size, canvas_size(&ctx));
+
+        Renderer { ctx, palette, camera }
+    }
+
+    /// Draw the visible tiles of a simulation
+    pub fn draw(&self, simulation: &dyn Simulation) {
+        let (canvas_width, canvas_height) = canvas_size(&self.ctx);
+        self.ctx.set_fill_style_str(&self.palette.background);
+        self.ctx.fill_rect(0.0, 0.0, canvas_width, canvas_height);
+
+        let (width, height) = simulation.size();
+        let zoom = self.camera.zoom;
+        let visible = |start: f64, pixels: f64, len: usize| {
+            let first = start.floor().max(0.0) as usize;
+            let last = (start + pixels / zoom).ceil().max(0.0) as usize;
+            first.min(len)..last.min(len)
+        };
+
+        self.ctx.set_font(&format!("{}px monospace", zoom.floor()));
+        self.ctx.set_text_align("center");
+        self.ctx.set_text_baseline("middle");
+
+        for y in visible(self.camera.y, canvas_height, height) {
+            for x in visible(self.camera.x, canvas_width, width) {
+                let (px, py) = self.camera.screen_pos(x, y);
+                match self.palette.style(simulation.tile(x, y)) {
+                    Style::Fill(colour) => {
+                        self.ctx.set_fill_style_str(&colour);
+                        self.ctx.fill_rect(px, py, zoom, zoom);
+                    },
+                    Style::Glyph(c, colour) => {
+                        self.ctx.set_fill_style_str(&colour);
+                        let _ = self.ctx.fill_text(&c.to_string(), px + zoom / 2.0, py + zoom / 2.0);
+                    },
+                    Style::Sprite(sprite, colour) => {
+                        self.ctx.set_fill_style_str(&colour);
+                        let pixel = zoom / sprite.len().max(1) as f64;
+                        for (sy, row) in sprite.iter().enumerate() {
+                            for (sx, _) in row.chars().enumerate().filter(|&(_, c)| c == '#') {
+                                self.ctx.fill_rect(px + sx as f64 * pixel, py + sy as f64 * pixel, pixel, pixel);
+                            }
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+fn canvas_size(ctx: &CanvasRenderingContext2d) -> (f64, f64) {
+    ctx.canvas().map(|canvas| (canvas.width() as f64, canvas.height() as f64))
+        .unwrap_or_default()
+}
+
+/// A simulation being shown on the canvas
+pub struct Animation {
+    simulation: Box<dyn Simulation>,
+    renderer: Renderer,
+    playing: bool,
+    /// Counts calls of [`canvas_play`], so a loop left over from an earlier one knows to stop
+    generation: u32,
+}
+
+impl Animation {
+    /// Advance by one frame and draw it, returning whether anything changed
+    fn frame(&mut self) -> bool {
+        let changed = self.simulation.tick();
+        self.draw();
+
+        changed
+    }
+
+    fn draw(&self) {
+        self.renderer.draw(self.simulation.as_ref());
+    }
+}
+
+/// Show a simulation on the canvas (replacing any other) and start playing it
+pub async fn play(simulation: Box<dyn Simulation>, palette: Palette) {
+    let renderer = Renderer::new(palette, simulation.size());
+    let animation = Animation { simulation, renderer, playing: false, generation: 0 };
+    animation.draw();
+
+    let previous = ANIMATION.with(|current| current.replace(Some(Rc::new(RefCell::new(animation)))));
+    if let Some(previous) = previous {
+        previous.borrow_mut().playing = false;
+    }
+
+    canvas_play().await;
+}
+
+/// The animation on the canvas, if there is one
+fn animation() -> Option<Rc<RefCell<Animation>>> {
+    ANIMATION.with(|animation| animation.borrow().clone())
+}
+
+/// Play the animation at its frame rate until it stops changing or is paused
+#[wasm_bindgen]
+pub async fn canvas_play() {
+    let animation = match animation() {
+        Some(animation) if !animation.borrow().playing => animation,
+        _ => return,
+    };
+    let generation = {
+        let mut animation = animation.borrow_mut();
+        animation.playing = true;
+        animation.generation = animation.generation.wrapping_add(1);
+        animation.generation
+    };
+
+    loop {
+        let start = js_sys::Date::now();
+        {
+            let mut animation = animation.borrow_mut();
+            // Paused, or paused and played again while this loop slept (so another loop plays it now)
+            if !animation.playing || animation.generation != generation {
+                break;
+            }
+            if !animation.frame() {
+                animation.playing = false;
+                break;
+            }
+        }
+
+        // Whatever is left of this frame's time
+        let elapsed = js_sys::Date::now() - start;
+        util::sleep((1000.0 / FPS.with(Cell::get) - elapsed).max(0.0) as u32).await;
+    }
+}
+
+/// Pause the animation
+#[wasm_bindgen]
+pub fn canvas_pause() {
+    if let Some(animation) = animation() {
+        animation.borrow_mut().playing = false;
+    }
+}
+
+/// Advance a paused animation by a single frame
+#[wasm_bindgen]
+pub fn canvas_step() {
+    if let Some(animation) = animation() {
+        let mut animation = animation.borrow_mut();
+        if !animation.playing {
+            animation.frame();
+        }
+    }
+}
+
+/// Set the frame rate of animations
+#[wasm_bindgen]
+pub fn canvas_fps(fps: f64) {
+    FPS.with(|current| current.set(fps.max(0.1)));
+}
+
+/// Move the view by a number of pixels
+#[wasm_bindgen]
+pub fn canvas_pan(dx: f64, dy: f64) {
+    if let Some(animation) = animation() {
+        let mut animation = animation.borrow_mut();
+        animation.renderer.camera.pan(dx, dy);
+        animation.draw();
+    }
+}
+
+/// Zoom the view by `factor`, keeping the point at pixel (`x`, `y`) in place
+#[wasm_bindgen]
+pub fn canvas_zoom(factor: f64, x: f64, y: f64) {
+    if let Some(animation) = animation() {
+        let mut animation = animation.borrow_mut();
+        animation.renderer.camera.zoom_at(factor, x, y);
+        animation.draw();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit() {
+        let camera = Camera::fit((10, 5), (800.0, 600.0));
+        assert_eq!(camera.zoom, 64.0);
+        assert_eq!((camera.x, camera.y), (-1.25, -2.1875));
+
+        let camera = Camera::fit((100, 50), (800.0, 600.0));
+        assert_eq!(camera, Camera { x: 0.0, y: -12.5, zoom: 8.0 });
+    }
+
+    #[test]
+    fn test_zoom_at() {
+        let mut camera = Camera { x: 0.0, y: 0.0, zoom: 8.0 };
+        camera.zoom_at(2.0, 80.0, 40.0);
+        assert_eq!(camera, Camera { x: 5.0, y: 2.5, zoom: 16.0 });
+        // The tile under the cursor stays put
+        assert_eq!(camera.screen_pos(10, 5), (80.0, 40.0));
+
+        camera.pan(16.0, -32.0);
+        assert_eq!((camera.x, camera.y), (4.0, 4.5));
+    }
+}
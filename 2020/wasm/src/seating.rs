@@ -0,0 +1,74 @@
+//! The seating system of day 11, animated on the canvas.
+
+use aoc2020_day11::{Day11, Seating, EMPTY, FLOOR, OCCUPIED};
+use aoc2020_day11::{PART1_MAX_OCCUPIED, PART1_MAX_SCAN, PART2_MAX_OCCUPIED, PART2_MAX_SCAN};
+use runner::Solution;
+use wasm_bindgen::prelude::*;
+
+use crate::render::{self, Palette, Simulation};
+
+/// A chair, seen from above
+const CHAIR: &[&str] = &[
+    "........",
+    ".######.",
+    ".#....#.",
+    ".#....#.",
+    ".######.",
+    ".#....#.",
+    ".#....#.",
+    "........",
+];
+
+impl Simulation for Seating {
+    fn size(&self) -> (usize, usize) {
+        (self.width(), self.height())
+    }
+
+    fn tile(&self, x: usize, y: usize) -> char {
+        self.tiles()[y * self.width() + x]
+    }
+
+    fn tick(&mut self) -> bool {
+        self.step()
+    }
+}
+
+/// Animate a seating layout until it stabilises, with the rules of `part` 1 or 2
+#[wasm_bindgen]
+pub async fn seating_play(input: String, part: u32) -> Result<(), JsValue> {
+    let map = Day11::parse(&input).map_err(|err| JsValue::from_str(&err))?;
+    let seating = match part {
+        1 => Seating::new(&map, PART1_MAX_OCCUPIED, PART1_MAX_SCAN),
+        2 => Seating::new(&map, PART2_MAX_OCCUPIED, PART2_MAX_SCAN),
+        _ => return Err(JsValue::from_str(&format!("No part {}", part))),
+    };
+    let palette = Palette::new("#0f0f23", "#cccccc")
+        .fill(FLOOR, "#10101a")
+        .sprite(EMPTY, CHAIR, "#009900")
+        .sprite(OCCUPIED, CHAIR, "#ffff66");
+
+    render::play(Box::new(seating), palette).await;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seating() {
+        let map = Day11::parse("L.L\nLLL\nL.L\n").unwrap();
+        let mut seating = Seating::new(&map, PART1_MAX_OCCUPIED, PART1_MAX_SCAN);
+        assert_eq!(seating.size(), (3, 3));
+
+        assert!(seating.tick());
+        assert_eq!(seating.tile(1, 1), OCCUPIED);
+        assert!(seating.tick());
+        assert_eq!(seating.tile(1, 1), EMPTY);
+        assert_eq!(seating.tile(0, 0), OCCUPIED);
+        assert!(!seating.tick());
+
+        assert!(Day11::parse("L.L\nLL\n").is_err());
+    }
+}
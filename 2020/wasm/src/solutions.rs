@@ -0,0 +1,89 @@
+//! Every 2020 solution, run on an input pasted into the page.
+//!
+//! Timings come from `performance.now()`, since there's no `Instant` in the browser.
+
+use std::time::Duration;
+
+use runner::{Run, Solution};
+use wasm_bindgen::prelude::*;
+
+/// The answers to a puzzle and how long each step took
+#[wasm_bindgen]
+pub struct Answers {
+    run: Run,
+}
+
+#[wasm_bindgen]
+impl Answers {
+    #[wasm_bindgen(getter)]
+    pub fn part1(&self) -> String {
+        self.run.part1.0.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn part2(&self) -> String {
+        self.run.part2.0.clone()
+    }
+
+    /// Time taken to parse the input, in milliseconds
+    #[wasm_bindgen(getter)]
+    pub fn parse_ms(&self) -> f64 {
+        millis(self.run.parse)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn part1_ms(&self) -> f64 {
+        millis(self.run.part1.1)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn part2_ms(&self) -> f64 {
+        millis(self.run.part2.1)
+    }
+}
+
+/// Solve both parts of a day with `input`, failing if the input can't be parsed
+#[wasm_bindgen]
+pub fn solve(day: u32, input: &str) -> Result<Answers, JsValue> {
+    let run = match day {
+        1 => run::<aoc2020_day01::Day01>(input),
+        2 => run::<aoc2020_day02::Day02>(input),
+        3 => run::<aoc2020_day03::Day03>(input),
+        4 => run::<aoc2020_day04::Day04>(input),
+        5 => run::<aoc2020_day05::Day05>(input),
+        6 => run::<aoc2020_day06::Day06>(input),
+        7 => run::<aoc2020_day07::Day07>(input),
+        8 => run::<aoc2020_day08::Day08>(input),
+        9 => run::<aoc2020_day09::Day09>(input),
+        10 => run::<aoc2020_day10::Day10>(input),
+        11 => run::<aoc2020_day11::Day11>(input),
+        12 => run::<aoc2020_day12::Day12>(input),
+        13 => run::<aoc2020_day13::Day13>(input),
+        14 => run::<aoc2020_day14::Day14>(input),
+        15 => run::<aoc2020_day15::Day15>(input),
+        16 => run::<aoc2020_day16::Day16>(input),
+        17 => run::<aoc2020_day17::Day17>(input),
+        18 => run::<aoc2020_day18::Day18>(input),
+        19 => run::<aoc2020_day19::Day19>(input),
+        20 => run::<aoc2020_day20::Day20>(input),
+        21 => run::<aoc2020_day21::Day21>(input),
+        22 => run::<aoc2020_day22::Day22>(input),
+        23 => run::<aoc2020_day23::Day23>(input),
+        24 => run::<aoc2020_day24::Day24>(input),
+        25 => run::<aoc2020_day25::Day25>(input),
+        _ => Err(format!("No solution for day {}", day)),
+    };
+
+    run.map(|run| Answers { run }).map_err(|err| JsValue::from_str(&err))
+}
+
+fn run<S: Solution>(input: &str) -> Result<Run, String> {
+    let performance = web_sys::window().and_then(|window| window.performance());
+    let now = || performance.as_ref().map_or(0.0, |performance| performance.now());
+
+    runner::run_with_clock::<S>(input, || Duration::from_secs_f64(now() / 1000.0))
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
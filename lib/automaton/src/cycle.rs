@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::Automaton;
+
+/// A repeating sequence of states
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Cycle {
+    /// The generation the cycle first starts
+    pub start: u64,
+    /// The number of generations before the cycle repeats
+    pub period: u64,
+}
+
+/// Step until a state repeats, stopping at the first repeat
+///
+/// States are compared using `key`, which may be used to ignore irrelevant details
+/// (e.g. the position of a pattern that translates each generation).
+/// Returns `None` if no cycle is found before generation `limit`.
+pub fn find_cycle<A, K>(automaton: &mut A, limit: u64, key: impl Fn(&A) -> K) -> Option<Cycle>
+    where A: Automaton, K: Hash + Eq
+{
+    let mut seen = HashMap::new();
+    while automaton.generation() < limit {
+        if let Some(&start) = seen.get(&key(automaton)) {
+            return Some(Cycle { start, period: automaton.generation() - start });
+        }
+
+        seen.insert(key(automaton), automaton.generation());
+        automaton.step();
+    }
+
+    None
+}
+
+/// Advance to generation `n`, skipping over whole cycles once the state repeats
+///
+/// The state must be identical (not just equal under `key`) each time around the cycle.
+pub fn advance<A, K>(automaton: &mut A, n: u64, key: impl Fn(&A) -> K) -> Option<Cycle>
+    where A: Automaton, K: Hash + Eq
+{
+    let cycle = find_cycle(automaton, n, key);
+    if let Some(cycle) = cycle {
+        let remaining = n - automaton.generation();
+        automaton.set_generation(n - remaining % cycle.period);
+    }
+
+    while automaton.generation() < n {
+        automaton.step();
+    }
+
+    cycle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts modulo 5, starting from 7
+    struct Counter(u64, u64);
+
+    impl Automaton for Counter {
+        fn step(&mut self) -> bool {
+            self.0 = if self.0 >= 5 { self.0 - 1 } else { (self.0 + 1) % 5 };
+            self.1 += 1;
+            true
+        }
+
+        fn generation(&self) -> u64 {
+            self.1
+        }
+
+        fn set_generation(&mut self, generation: u64) {
+            self.1 = generation;
+        }
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        // 7, 6, 5, 4, 0, 1, 2, 3, 4, ...
+        let mut counter = Counter(7, 0);
+        assert_eq!(find_cycle(&mut counter, 100, |c| c.0), Some(Cycle { start: 3, period: 5 }));
+        assert_eq!(counter.generation(), 8);
+        assert_eq!(find_cycle(&mut Counter(7, 0), 5, |c| c.0), None);
+    }
+
+    #[test]
+    fn test_advance() {
+        for &n in &[2, 10, 1_000_000_000_000] {
+            let mut counter = Counter(7, 0);
+            advance(&mut counter, n, |c| c.0);
+            assert_eq!(counter.generation(), n);
+            assert_eq!(counter.0, if n == 2 { 5 } else { (n - 4) % 5 });
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use crate::Automaton;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Minimum number of cells to update before spreading the work across threads
+#[cfg(feature = "parallel")]
+const PARALLEL_THRESHOLD: usize = 4096;
+
+/// How the cells of a dense automaton are connected
+pub trait Topology {
+    /// Number of cells
+    fn len(&self) -> usize;
+
+    /// Is the topology empty
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Indexes of the neighbours of a cell
+    ///
+    /// The order of neighbours is preserved when they are passed to the rule.
+    fn neighbours(&self, index: usize) -> Vec<usize>;
+}
+
+/// Which cells count as neighbours on a square grid
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Neighbourhood {
+    /// The 4 orthogonally adjacent cells
+    VonNeumann,
+    /// The 8 adjacent cells, including diagonals
+    Moore,
+}
+
+/// A bounded rectangular grid, indexed in row-major order
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Grid {
+    pub width: usize,
+    pub height: usize,
+    pub neighbourhood: Neighbourhood,
+}
+
+impl Grid {
+    pub fn new(width: usize, height: usize, neighbourhood: Neighbourhood) -> Self {
+        Grid { width, height, neighbourhood }
+    }
+}
+
+impl Topology for Grid {
+    fn len(&self) -> usize {
+        self.width * self.height
+    }
+
+    fn neighbours(&self, index: usize) -> Vec<usize> {
+        const VON_NEUMANN: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+        const MOORE: [(isize, isize); 8] = [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+
+        let offsets: &[(isize, isize)] = match self.neighbourhood {
+            Neighbourhood::VonNeumann => &VON_NEUMANN,
+            Neighbourhood::Moore => &MOORE,
+        };
+
+        let (x, y) = ((index % self.width) as isize, (index / self.width) as isize);
+        offsets.iter()
+            .map(|&(dx, dy)| (x + dx, y + dy))
+            .filter(|&(x, y)| 0 <= x && x < self.width as isize && 0 <= y && y < self.height as isize)
+            .map(|(x, y)| y as usize * self.width + x as usize)
+            .collect()
+    }
+}
+
+/// An automaton with a fixed number of cells
+///
+/// The `rule` is called with the state of a cell and the states of its neighbours
+/// and returns the state of the cell in the next generation.
+/// Only cells with a neighbour that changed in the last generation are re-evaluated.
+pub struct Dense<S, R> {
+    cells: Vec<S>,
+    next: Vec<S>,
+    neighbours: Vec<Vec<usize>>,
+    dependents: Vec<Vec<usize>>,
+    active: Vec<usize>,
+    is_active: Vec<bool>,
+    rule: R,
+    generation: u64,
+}
+
+impl<S, R> Dense<S, R>
+    where S: Copy + Eq + Send + Sync, R: Fn(S, &[S]) -> S + Sync
+{
+    /// Create a new automaton with an initial state for each cell in the topology
+    pub fn new(topology: &impl Topology, cells: Vec<S>, rule: R) -> Self {
+        assert_eq!(topology.len(), cells.len(), "Must have one state per cell");
+
+        let neighbours: Vec<Vec<usize>> = (0..cells.len()).map(|i| topology.neighbours(i)).collect();
+        let mut dependents = vec![Vec::new(); cells.len()];
+        for (i, ns) in neighbours.iter().enumerate() {
+            for &n in ns {
+                dependents[n].push(i);
+            }
+        }
+
+        Dense {
+            next: cells.clone(),
+            active: (0..cells.len()).collect(),
+            is_active: vec![true; cells.len()],
+            cells,
+            neighbours,
+            dependents,
+            rule,
+            generation: 0,
+        }
+    }
+
+    /// The current state of every cell
+    pub fn cells(&self) -> &[S] {
+        &self.cells
+    }
+
+    /// Calculate the next state of the active cells
+    fn evaluate(&self) -> Vec<S> {
+        let next_state = |scratch: &mut Vec<S>, i: usize| {
+            scratch.clear();
+            scratch.extend(self.neighbours[i].iter().map(|&n| self.cells[n]));
+            (self.rule)(self.cells[i], scratch)
+        };
+
+        #[cfg(feature = "parallel")]
+        {
+            if self.active.len() >= PARALLEL_THRESHOLD {
+                return self.active.par_iter()
+                    .map_init(Vec::new, |scratch, &i| next_state(scratch, i))
+                    .collect();
+            }
+        }
+
+        let mut scratch = Vec::new();
+        self.active.iter().map(|&i| next_state(&mut scratch, i)).collect()
+    }
+}
+
+impl<S, R> Automaton for Dense<S, R>
+    where S: Copy + Eq + Send + Sync, R: Fn(S, &[S]) -> S + Sync
+{
+    fn step(&mut self) -> bool {
+        let states = self.evaluate();
+
+        // Both buffers are identical at this point, so we only need to write the active cells
+        let mut changed = Vec::new();
+        for (&i, state) in self.active.iter().zip(states) {
+            self.next[i] = state;
+            if state != self.cells[i] {
+                changed.push(i);
+            }
+        }
+        std::mem::swap(&mut self.cells, &mut self.next);
+        for &i in &self.active {
+            self.next[i] = self.cells[i];
+            self.is_active[i] = false;
+        }
+
+        // Only cells next to a change could change in the next generation
+        self.active.clear();
+        for &i in &changed {
+            for &d in self.dependents[i].iter().chain(std::iter::once(&i)) {
+                if !self.is_active[d] {
+                    self.is_active[d] = true;
+                    self.active.push(d);
+                }
+            }
+        }
+
+        self.generation += 1;
+        !changed.is_empty()
+    }
+
+    fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    fn set_generation(&mut self, generation: u64) {
+        self.generation = generation;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::life_rule;
+
+    fn life(width: usize, height: usize, pattern: &str) -> Dense<bool, impl Fn(bool, &[bool]) -> bool + Sync> {
+        let grid = Grid::new(width, height, Neighbourhood::Moore);
+        let cells = pattern.chars().filter(|c| !c.is_whitespace()).map(|c| c == '#').collect();
+
+        Dense::new(&grid, cells, life_rule(&[3], &[2, 3]))
+    }
+
+    #[test]
+    fn test_blinker() {
+        let mut automaton = life(3, 3, "... ### ...");
+        assert!(automaton.step());
+        assert_eq!(automaton.cells(), life(3, 3, ".#. .#. .#.").cells());
+        assert!(automaton.step());
+        assert_eq!(automaton.cells(), life(3, 3, "... ### ...").cells());
+        assert_eq!(automaton.generation(), 2);
+    }
+
+    #[test]
+    fn test_stable() {
+        let mut automaton = life(4, 4, ".... .##. .##. ....");
+        assert!(!automaton.step());
+        assert_eq!(automaton.run_until_stable(), 2);
+    }
+
+    #[test]
+    fn test_glider() {
+        // A glider should travel diagonally across the grid until it hits the corner and becomes a block
+        let mut automaton = life(8, 8, "
+            .#......
+            ..#.....
+            ###.....
+            ........
+            ........
+            ........
+            ........
+            ........");
+        automaton.run_until_stable();
+        assert_eq!(automaton.cells().iter().filter(|&&c| c).count(), 4);
+    }
+
+    #[test]
+    fn test_grid_neighbours() {
+        let grid = Grid::new(3, 2, Neighbourhood::VonNeumann);
+        assert_eq!(grid.neighbours(0), vec![1, 3]);
+        assert_eq!(grid.neighbours(4), vec![1, 5, 3]);
+
+        let grid = Grid::new(3, 3, Neighbourhood::Moore);
+        assert_eq!(grid.neighbours(4).len(), 8);
+    }
+}
@@ -0,0 +1,33 @@
+//! Cellular automata.
+//!
+//! [`Dense`] automata have a fixed set of cells with arbitrary states and neighbours given by a [`Topology`].
+//! [`Sparse`] automata only track live cells, so can grow without bound.
+//! Either can be run for a huge number of generations with [`advance`], which skips over repeating states.
+
+mod cycle;
+mod dense;
+mod sparse;
+
+pub use cycle::{advance, find_cycle, Cycle};
+pub use dense::{Dense, Grid, Neighbourhood, Topology};
+pub use sparse::{life_rule, Sparse};
+
+/// A cellular automaton that evolves one generation at a time
+pub trait Automaton {
+    /// Advance by one generation, returning whether any cell changed
+    fn step(&mut self) -> bool;
+
+    /// Number of generations so far
+    fn generation(&self) -> u64;
+
+    /// Change the generation counter without evolving any cells
+    ///
+    /// Used when skipping over whole cycles.
+    fn set_generation(&mut self, generation: u64);
+
+    /// Advance until no cells change, returning the final generation
+    fn run_until_stable(&mut self) -> u64 {
+        while self.step() {}
+        self.generation()
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use crate::Automaton;
+
+/// A two-state automaton that only stores its live cells
+///
+/// `neighbours` returns the neighbours of a cell (in a consistent order) and must be symmetric:
+/// if `a` is a neighbour of `b` then `b` is a neighbour of `a`.
+/// The `rule` is called with whether a cell is alive and whether each of its neighbours is alive,
+/// and returns whether the cell is alive in the next generation.
+/// Dead cells with no live neighbours always stay dead.
+pub struct Sparse<C, N, R> {
+    live: HashSet<C>,
+    next: HashSet<C>,
+    candidates: HashSet<C>,
+    neighbours: N,
+    rule: R,
+    generation: u64,
+}
+
+impl<C, N, I, R> Sparse<C, N, R>
+    where C: Copy + Hash + Eq, N: Fn(C) -> I, I: IntoIterator<Item=C>, R: Fn(bool, &[bool]) -> bool
+{
+    /// Create a new automaton from its initial live cells
+    pub fn new(live: impl IntoIterator<Item=C>, neighbours: N, rule: R) -> Self {
+        Sparse {
+            live: live.into_iter().collect(),
+            next: HashSet::new(),
+            candidates: HashSet::new(),
+            neighbours,
+            rule,
+            generation: 0,
+        }
+    }
+
+    /// The current live cells
+    pub fn live(&self) -> &HashSet<C> {
+        &self.live
+    }
+
+    /// Consume the automaton, returning its live cells
+    pub fn into_live(self) -> HashSet<C> {
+        self.live
+    }
+}
+
+impl<C, N, I, R> Automaton for Sparse<C, N, R>
+    where C: Copy + Hash + Eq, N: Fn(C) -> I, I: IntoIterator<Item=C>, R: Fn(bool, &[bool]) -> bool
+{
+    fn step(&mut self) -> bool {
+        // Only live cells and their neighbours can be alive in the next generation
+        self.candidates.clear();
+        for &cell in &self.live {
+            self.candidates.insert(cell);
+            self.candidates.extend((self.neighbours)(cell));
+        }
+
+        let mut changed = false;
+        let mut states = Vec::new();
+        self.next.clear();
+        for &cell in &self.candidates {
+            states.clear();
+            states.extend((self.neighbours)(cell).into_iter().map(|n| self.live.contains(&n)));
+
+            let alive = self.live.contains(&cell);
+            let next = (self.rule)(alive, &states);
+            if next {
+                self.next.insert(cell);
+            }
+            changed |= next != alive;
+        }
+
+        std::mem::swap(&mut self.live, &mut self.next);
+        self.generation += 1;
+
+        changed
+    }
+
+    fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    fn set_generation(&mut self, generation: u64) {
+        self.generation = generation;
+    }
+}
+
+/// A rule for Life-like automata in `B/S` notation
+///
+/// Dead cells with a number of live neighbours in `birth` become alive
+/// and live cells with a number of live neighbours in `survival` stay alive.
+pub fn life_rule(birth: &'static [usize], survival: &'static [usize]) -> impl Fn(bool, &[bool]) -> bool + Copy + Sync {
+    move |alive, neighbours| {
+        let count = neighbours.iter().filter(|&&n| n).count();
+        if alive { survival.contains(&count) } else { birth.contains(&count) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn neighbours((x, y): (i64, i64)) -> Vec<(i64, i64)> {
+        (-1..=1).flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+            .filter(|&d| d != (0, 0))
+            .map(|(dx, dy)| (x + dx, y + dy))
+            .collect()
+    }
+
+    #[test]
+    fn test_glider() {
+        let glider = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)];
+        let mut automaton = Sparse::new(glider.iter().copied(), neighbours, life_rule(&[3], &[2, 3]));
+        for _ in 0..4 {
+            assert!(automaton.step());
+        }
+
+        // After 4 generations a glider has moved one cell diagonally
+        let expected: HashSet<_> = glider.iter().map(|&(x, y)| (x + 1, y + 1)).collect();
+        assert_eq!(automaton.live(), &expected);
+        assert_eq!(automaton.generation(), 4);
+    }
+
+    #[test]
+    fn test_ordered_neighbours() {
+        // Rule 90 (each cell is the XOR of its left and right neighbours)
+        let mut automaton = Sparse::new(vec![0i64], |x| vec![x - 1, x + 1], |_, n: &[bool]| n[0] != n[1]);
+        automaton.step();
+        automaton.step();
+        let mut live: Vec<_> = automaton.live().iter().copied().collect();
+        live.sort();
+        assert_eq!(live, vec![-2, 2]);
+    }
+}
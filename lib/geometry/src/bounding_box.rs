@@ -0,0 +1,111 @@
+use crate::{Point, Scalar, Vector};
+
+/// An inclusive axis-aligned bounding box
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Debug)]
+pub struct BoundingBox<const N: usize> {
+    pub min: Point<N>,
+    pub max: Point<N>,
+}
+
+impl<const N: usize> BoundingBox<N> {
+    /// Create a new bounding box from its minimum and maximum corners
+    pub fn new(min: Point<N>, max: Point<N>) -> Self {
+        assert!((0..N).all(|i| min[i] <= max[i]), "Invalid bounds: {:?}..={:?}", min, max);
+        BoundingBox { min, max }
+    }
+
+    /// A bounding box containing a single point
+    pub fn from_point(point: Point<N>) -> Self {
+        BoundingBox { min: point, max: point }
+    }
+
+    /// A bounding box containing all points (`None` if there are no points)
+    pub fn from_points(points: impl IntoIterator<Item=Point<N>>) -> Option<Self> {
+        let mut iter = points.into_iter();
+        let mut bounds = Self::from_point(iter.next()?);
+        for point in iter {
+            bounds.extend(point);
+        }
+
+        Some(bounds)
+    }
+
+    /// Number of lattice points along each axis
+    pub fn size(&self) -> Vector<N> {
+        (self.max - self.min).map(|c| c + 1)
+    }
+
+    /// Number of lattice points inside the bounding box
+    pub fn volume(&self) -> Scalar {
+        self.size().0.iter().product()
+    }
+
+    /// Is this point inside the bounding box
+    pub fn contains(&self, point: Point<N>) -> bool {
+        (0..N).all(|i| self.min[i] <= point[i] && point[i] <= self.max[i])
+    }
+
+    /// Grow the bounding box so that it contains this point
+    pub fn extend(&mut self, point: Point<N>) {
+        for i in 0..N {
+            self.min[i] = self.min[i].min(point[i]);
+            self.max[i] = self.max[i].max(point[i]);
+        }
+    }
+
+    /// Bounding box grown by `margin` along every axis
+    pub fn grow(&self, margin: Scalar) -> Self {
+        let margin = Vector([margin; N]);
+        Self::new(self.min - margin, self.max + margin)
+    }
+
+    /// Iterate over all lattice points inside the bounding box
+    ///
+    /// The last axis varies fastest.
+    pub fn points(&self) -> impl Iterator<Item=Point<N>> {
+        let (min, max) = (self.min, self.max);
+        let mut next = Some(min);
+
+        std::iter::from_fn(move || {
+            let current = next?;
+
+            // Advance like an odometer
+            let mut point = current;
+            next = None;
+            for i in (0..N).rev() {
+                if point[i] < max[i] {
+                    point[i] += 1;
+                    next = Some(point);
+                    break;
+                }
+                point[i] = min[i];
+            }
+
+            Some(current)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_points() {
+        let bounds = BoundingBox::from_points(vec![Point([1, -2, 3]), Point([-1, 5, 0])]).unwrap();
+        assert_eq!(bounds, BoundingBox::new(Point([-1, -2, 0]), Point([1, 5, 3])));
+        assert_eq!(bounds.size(), Vector([3, 8, 4]));
+        assert_eq!(bounds.volume(), 96);
+    }
+
+    #[test]
+    fn test_points() {
+        let bounds = BoundingBox::new(Point([0, 0]), Point([1, 2]));
+        let points: Vec<_> = bounds.points().collect();
+        assert_eq!(points, vec![
+            Point([0, 0]), Point([0, 1]), Point([0, 2]),
+            Point([1, 0]), Point([1, 1]), Point([1, 2]),
+        ]);
+        assert_eq!(bounds.grow(1).points().count() as Scalar, bounds.grow(1).volume());
+    }
+}
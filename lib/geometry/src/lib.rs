@@ -0,0 +1,20 @@
+//! N-dimensional points, vectors and bounding boxes on an integer lattice.
+
+mod bounding_box;
+mod point;
+mod vector;
+
+pub use bounding_box::BoundingBox;
+pub use point::Point;
+pub use vector::Vector;
+
+/// The type of each coordinate
+pub type Scalar = i64;
+
+pub type Point2 = Point<2>;
+pub type Point3 = Point<3>;
+pub type Point4 = Point<4>;
+
+pub type Vector2 = Vector<2>;
+pub type Vector3 = Vector<3>;
+pub type Vector4 = Vector<4>;
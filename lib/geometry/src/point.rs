@@ -0,0 +1,204 @@
+use std::{fmt, ops};
+
+use crate::{Scalar, Vector};
+
+/// A position in N-dimensional space
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Point<const N: usize>(pub [Scalar; N]);
+
+impl<const N: usize> Point<N> {
+    /// Create a new point from its coordinates
+    pub const fn new(coords: [Scalar; N]) -> Self {
+        Point(coords)
+    }
+
+    /// The origin
+    pub const fn origin() -> Self {
+        Point([0; N])
+    }
+
+    /// The `x` coordinate
+    pub fn x(&self) -> Scalar {
+        self.0[0]
+    }
+
+    /// The `y` coordinate
+    pub fn y(&self) -> Scalar {
+        self.0[1]
+    }
+
+    /// The `z` coordinate
+    pub fn z(&self) -> Scalar {
+        self.0[2]
+    }
+
+    /// The `w` coordinate
+    pub fn w(&self) -> Scalar {
+        self.0[3]
+    }
+
+    /// Displacement from the origin to this point
+    pub fn to_vector(self) -> Vector<N> {
+        Vector(self.0)
+    }
+
+    /// Manhattan (taxicab) distance to another point
+    pub fn manhattan_distance(self, other: Self) -> Scalar {
+        (other - self).manhattan_length()
+    }
+
+    /// Chebyshev (chessboard) distance to another point
+    pub fn chebyshev_distance(self, other: Self) -> Scalar {
+        (other - self).chebyshev_length()
+    }
+
+    /// Orthogonally adjacent points (`2N` in total)
+    pub fn orthogonal_neighbours(self) -> impl Iterator<Item=Self> {
+        Vector::orthogonal_directions().map(move |d| self + d)
+    }
+
+    /// All adjacent points, including diagonals (`3ᴺ - 1` in total)
+    pub fn neighbours(self) -> impl Iterator<Item=Self> {
+        Vector::directions().map(move |d| self + d)
+    }
+
+    /// Extend this point with an extra dimension
+    ///
+    /// `M` must be `N + 1`.
+    pub fn extend<const M: usize>(self, coord: Scalar) -> Point<M> {
+        assert_eq!(M, N + 1, "Can only extend by a single dimension");
+        let mut point = Point::origin();
+        point.0[..N].copy_from_slice(&self.0);
+        point.0[N] = coord;
+
+        point
+    }
+}
+
+impl<const N: usize> Default for Point<N> {
+    fn default() -> Self {
+        Self::origin()
+    }
+}
+
+impl<const N: usize> From<[Scalar; N]> for Point<N> {
+    fn from(coords: [Scalar; N]) -> Self {
+        Point(coords)
+    }
+}
+
+impl From<(Scalar, Scalar)> for Point<2> {
+    fn from((x, y): (Scalar, Scalar)) -> Self {
+        Point([x, y])
+    }
+}
+
+impl From<Point<2>> for (Scalar, Scalar) {
+    fn from(point: Point<2>) -> Self {
+        (point.0[0], point.0[1])
+    }
+}
+
+impl<const N: usize> ops::Index<usize> for Point<N> {
+    type Output = Scalar;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<const N: usize> ops::IndexMut<usize> for Point<N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<const N: usize> ops::Add<Vector<N>> for Point<N> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Vector<N>) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<const N: usize> ops::AddAssign<Vector<N>> for Point<N> {
+    fn add_assign(&mut self, rhs: Vector<N>) {
+        for (a, b) in self.0.iter_mut().zip(rhs.0.iter()) {
+            *a += b;
+        }
+    }
+}
+
+impl<const N: usize> ops::Sub<Vector<N>> for Point<N> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Vector<N>) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<const N: usize> ops::SubAssign<Vector<N>> for Point<N> {
+    fn sub_assign(&mut self, rhs: Vector<N>) {
+        for (a, b) in self.0.iter_mut().zip(rhs.0.iter()) {
+            *a -= b;
+        }
+    }
+}
+
+impl<const N: usize> ops::Sub for Point<N> {
+    type Output = Vector<N>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.to_vector() - rhs.to_vector()
+    }
+}
+
+impl<const N: usize> fmt::Debug for Point<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Point{:?}", self.0)
+    }
+}
+
+impl<const N: usize> fmt::Display for Point<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, c) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}", c)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let p = Point([1, 2, 3]);
+        let q = Point([4, 0, -1]);
+        assert_eq!(q - p, Vector([3, -2, -4]));
+        assert_eq!(p + (q - p), q);
+        assert_eq!(p.manhattan_distance(q), 9);
+        assert_eq!(p.chebyshev_distance(q), 4);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        assert_eq!(Point::<3>::origin().neighbours().count(), 26);
+        assert_eq!(Point::<4>::origin().neighbours().count(), 80);
+        assert!(Point([5, 5]).orthogonal_neighbours().all(|p| p.manhattan_distance(Point([5, 5])) == 1));
+    }
+
+    #[test]
+    fn test_extend() {
+        let p: Point<4> = Point([1, 2, 3]).extend(4);
+        assert_eq!(p, Point([1, 2, 3, 4]));
+        assert_eq!(p.to_string(), "1,2,3,4");
+    }
+}
@@ -0,0 +1,222 @@
+use std::{fmt, ops};
+
+use crate::Scalar;
+
+/// A displacement in N-dimensional space
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Vector<const N: usize>(pub [Scalar; N]);
+
+impl<const N: usize> Vector<N> {
+    /// Create a new vector from its components
+    pub const fn new(components: [Scalar; N]) -> Self {
+        Vector(components)
+    }
+
+    /// The zero vector
+    pub const fn zero() -> Self {
+        Vector([0; N])
+    }
+
+    /// Unit vector along an axis
+    pub fn unit(axis: usize) -> Self {
+        let mut v = Self::zero();
+        v.0[axis] = 1;
+
+        v
+    }
+
+    /// Sum of the absolute value of each component
+    pub fn manhattan_length(self) -> Scalar {
+        self.0.iter().map(|c| c.abs()).sum()
+    }
+
+    /// Largest absolute value of any component
+    pub fn chebyshev_length(self) -> Scalar {
+        self.0.iter().map(|c| c.abs()).max().unwrap_or(0)
+    }
+
+    /// Vector with each component replaced by its sign (`-1`, `0` or `1`)
+    pub fn signum(self) -> Self {
+        self.map(Scalar::signum)
+    }
+
+    /// Apply a function to each component
+    pub fn map(self, f: impl Fn(Scalar) -> Scalar) -> Self {
+        let mut v = self;
+        for c in v.0.iter_mut() {
+            *c = f(*c);
+        }
+
+        v
+    }
+
+    /// Rotate 90° in the plane of two axes, so that the `from` axis turns to the `to` axis
+    pub fn rotate90(self, from: usize, to: usize) -> Self {
+        assert_ne!(from, to, "Rotation axes must differ");
+        let mut v = self;
+        v.0[to] = self.0[from];
+        v.0[from] = -self.0[to];
+
+        v
+    }
+
+    /// All unit steps to orthogonally adjacent points (`2N` in total)
+    pub fn orthogonal_directions() -> impl Iterator<Item=Self> {
+        (0..N).flat_map(|axis| {
+            let unit = Self::unit(axis);
+            vec![-unit, unit]
+        })
+    }
+
+    /// All steps to adjacent points, including diagonals (`3ᴺ - 1` in total)
+    pub fn directions() -> impl Iterator<Item=Self> {
+        (0..3usize.pow(N as u32))
+            .map(|mut n| {
+                let mut v = Self::zero();
+                for c in v.0.iter_mut().rev() {
+                    *c = (n % 3) as Scalar - 1;
+                    n /= 3;
+                }
+
+                v
+            })
+            .filter(|&v| v != Self::zero())
+    }
+}
+
+impl Vector<2> {
+    /// Rotate 90° counter-clockwise (when `y` points up)
+    pub fn rotate_left(self) -> Self {
+        self.rotate90(0, 1)
+    }
+
+    /// Rotate 90° clockwise (when `y` points up)
+    pub fn rotate_right(self) -> Self {
+        self.rotate90(1, 0)
+    }
+}
+
+impl<const N: usize> Default for Vector<N> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<const N: usize> From<[Scalar; N]> for Vector<N> {
+    fn from(components: [Scalar; N]) -> Self {
+        Vector(components)
+    }
+}
+
+impl From<(Scalar, Scalar)> for Vector<2> {
+    fn from((x, y): (Scalar, Scalar)) -> Self {
+        Vector([x, y])
+    }
+}
+
+impl<const N: usize> ops::Index<usize> for Vector<N> {
+    type Output = Scalar;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<const N: usize> ops::IndexMut<usize> for Vector<N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<const N: usize> ops::Add for Vector<N> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<const N: usize> ops::AddAssign for Vector<N> {
+    fn add_assign(&mut self, rhs: Self) {
+        for (a, b) in self.0.iter_mut().zip(rhs.0.iter()) {
+            *a += b;
+        }
+    }
+}
+
+impl<const N: usize> ops::Sub for Vector<N> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<const N: usize> ops::SubAssign for Vector<N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        for (a, b) in self.0.iter_mut().zip(rhs.0.iter()) {
+            *a -= b;
+        }
+    }
+}
+
+impl<const N: usize> ops::Mul<Scalar> for Vector<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: Scalar) -> Self::Output {
+        self.map(|c| c * rhs)
+    }
+}
+
+impl<const N: usize> ops::Neg for Vector<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.map(|c| -c)
+    }
+}
+
+impl<const N: usize> fmt::Debug for Vector<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Vector{:?}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Vector::<2>::directions().count(), 8);
+        assert_eq!(Vector::<3>::directions().count(), 26);
+        assert_eq!(Vector::<4>::directions().count(), 80);
+        assert!(Vector::<3>::directions().all(|v| v.chebyshev_length() == 1));
+    }
+
+    #[test]
+    fn test_orthogonal_directions() {
+        let dirs: Vec<_> = Vector::<2>::orthogonal_directions().collect();
+        assert_eq!(dirs, vec![Vector([-1, 0]), Vector([1, 0]), Vector([0, -1]), Vector([0, 1])]);
+        assert_eq!(Vector::<4>::orthogonal_directions().count(), 8);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let east = Vector([1, 0]);
+        assert_eq!(east.rotate_left(), Vector([0, 1]));
+        assert_eq!(east.rotate_right(), Vector([0, -1]));
+        assert_eq!(east.rotate_left().rotate_left().rotate_left().rotate_left(), east);
+        assert_eq!(Vector([1, 2, 3]).rotate90(1, 2), Vector([1, -3, 2]));
+    }
+
+    #[test]
+    fn test_lengths() {
+        let v = Vector([3, -4, 1]);
+        assert_eq!(v.manhattan_length(), 8);
+        assert_eq!(v.chebyshev_length(), 4);
+        assert_eq!(v.signum(), Vector([1, -1, 1]));
+    }
+}
@@ -0,0 +1,107 @@
+use crate::Pos;
+
+/// An inclusive bounding box
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Debug)]
+pub struct Bounds {
+    pub min: Pos,
+    pub max: Pos,
+}
+
+impl Bounds {
+    /// Create a new bounding box from its top-left and bottom-right corners
+    pub fn new(min: Pos, max: Pos) -> Self {
+        assert!(min.0 <= max.0 && min.1 <= max.1, "Invalid bounds: {:?}..={:?}", min, max);
+        Bounds { min, max }
+    }
+
+    /// A bounding box containing a single position
+    pub fn from_pos(pos: Pos) -> Self {
+        Bounds { min: pos, max: pos }
+    }
+
+    /// A bounding box containing all positions (`None` if there are no positions)
+    pub fn from_positions(positions: impl IntoIterator<Item=Pos>) -> Option<Self> {
+        let mut iter = positions.into_iter();
+        let mut bounds = Bounds::from_pos(iter.next()?);
+        for pos in iter {
+            bounds.extend(pos);
+        }
+
+        Some(bounds)
+    }
+
+    /// Width of the bounding box
+    pub fn width(&self) -> usize {
+        (self.max.0 - self.min.0 + 1) as usize
+    }
+
+    /// Height of the bounding box
+    pub fn height(&self) -> usize {
+        (self.max.1 - self.min.1 + 1) as usize
+    }
+
+    /// Number of positions covered by the bounding box
+    pub fn area(&self) -> usize {
+        self.width() * self.height()
+    }
+
+    /// Is this position inside the bounding box
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.min.0 <= pos.0 && pos.0 <= self.max.0
+            && self.min.1 <= pos.1 && pos.1 <= self.max.1
+    }
+
+    /// Is this position on the edge of the bounding box
+    pub fn on_edge(&self, pos: Pos) -> bool {
+        self.contains(pos)
+            && (pos.0 == self.min.0 || pos.0 == self.max.0 || pos.1 == self.min.1 || pos.1 == self.max.1)
+    }
+
+    /// Grow the bounding box so that it contains this position
+    pub fn extend(&mut self, pos: Pos) {
+        self.min = (self.min.0.min(pos.0), self.min.1.min(pos.1));
+        self.max = (self.max.0.max(pos.0), self.max.1.max(pos.1));
+    }
+
+    /// Bounding box grown by `margin` in every direction
+    pub fn grow(&self, margin: i64) -> Self {
+        Bounds::new((self.min.0 - margin, self.min.1 - margin), (self.max.0 + margin, self.max.1 + margin))
+    }
+
+    /// Index of a position in a row-major layout of this bounding box
+    pub fn index(&self, pos: Pos) -> Option<usize> {
+        if self.contains(pos) {
+            Some((pos.1 - self.min.1) as usize * self.width() + (pos.0 - self.min.0) as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Iterate over all positions in row-major order
+    pub fn positions(&self) -> impl Iterator<Item=Pos> {
+        let (xmin, xmax) = (self.min.0, self.max.0);
+        (self.min.1..=self.max.1).flat_map(move |y| (xmin..=xmax).map(move |x| (x, y)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_positions() {
+        let bounds = Bounds::from_positions(vec![(1, 2), (-3, 5), (4, -1)]).unwrap();
+        assert_eq!(bounds, Bounds::new((-3, -1), (4, 5)));
+        assert_eq!(bounds.width(), 8);
+        assert_eq!(bounds.height(), 7);
+        assert!(Bounds::from_positions(vec![]).is_none());
+    }
+
+    #[test]
+    fn test_index() {
+        let bounds = Bounds::new((-1, -1), (1, 1));
+        let indexes: Vec<_> = bounds.positions().map(|pos| bounds.index(pos).unwrap()).collect();
+        assert_eq!(indexes, (0..9).collect::<Vec<_>>());
+        assert_eq!(bounds.index((2, 0)), None);
+    }
+}
@@ -0,0 +1,128 @@
+use std::{fmt, ops};
+
+use crate::{Bounds, Pos};
+
+/// A dense grid covering a fixed bounding box
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Grid<T> {
+    bounds: Bounds,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Create a new grid covering `bounds` with every cell set to `fill`
+    pub fn new(bounds: Bounds, fill: T) -> Self {
+        Grid { bounds, cells: vec![fill; bounds.area()] }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Create a new grid from rows of cells, with the top-left cell at `(0, 0)`
+    pub fn from_rows(rows: impl IntoIterator<Item=Vec<T>>) -> Self {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for row in rows {
+            assert_eq!(*width.get_or_insert(row.len()), row.len(), "Rows must all be the same length");
+            cells.extend(row);
+            height += 1;
+        }
+
+        let width = width.unwrap_or(0);
+        assert!(width > 0 && height > 0, "Grid must not be empty");
+
+        Grid { bounds: Bounds::new((0, 0), (width as i64 - 1, height - 1)), cells }
+    }
+
+    /// The area covered by the grid
+    pub fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
+    /// Width of the grid
+    pub fn width(&self) -> usize {
+        self.bounds.width()
+    }
+
+    /// Height of the grid
+    pub fn height(&self) -> usize {
+        self.bounds.height()
+    }
+
+    /// Get the cell at this position (`None` if outside the grid)
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.bounds.index(pos).map(|i| &self.cells[i])
+    }
+
+    /// Get a mutable reference to the cell at this position (`None` if outside the grid)
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.bounds.index(pos).map(move |i| &mut self.cells[i])
+    }
+
+    /// Set the cell at this position
+    ///
+    /// Panics if the position is outside the grid.
+    pub fn set(&mut self, pos: Pos, value: T) {
+        self[pos] = value;
+    }
+
+    /// Iterate over the rows of the grid, top to bottom
+    pub fn rows(&self) -> impl Iterator<Item=&[T]> {
+        self.cells.chunks(self.width())
+    }
+
+    /// Iterate over all cells and their positions in row-major order
+    pub fn iter(&self) -> impl Iterator<Item=(Pos, &T)> {
+        self.bounds.positions().zip(self.cells.iter())
+    }
+}
+
+impl<T> ops::Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        self.get(pos).unwrap_or_else(|| panic!("Position {:?} is outside grid {:?}", pos, self.bounds))
+    }
+}
+
+impl<T> ops::IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        let bounds = self.bounds;
+        self.get_mut(pos).unwrap_or_else(|| panic!("Position {:?} is outside grid {:?}", pos, bounds))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_negative_bounds() {
+        let mut grid = Grid::new(Bounds::new((-2, -1), (2, 1)), '.');
+        grid.set((-2, -1), '#');
+        grid[(2, 1)] = '@';
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), "#....\n.....\n....@\n");
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[(2, 1)], 6);
+    }
+}
@@ -0,0 +1,16 @@
+//! Two-dimensional grids.
+//!
+//! [`SparseGrid`] grows to fit whatever is put in it (including negative coordinates)
+//! while [`Grid`] is a dense, fixed-size grid that is convenient for rendering.
+//! A sparse grid can be cropped to a dense grid with [`SparseGrid::crop`].
+
+mod bounds;
+mod dense;
+mod sparse;
+
+pub use bounds::Bounds;
+pub use dense::Grid;
+pub use sparse::SparseGrid;
+
+/// A position on a grid (`x`, `y`)
+pub type Pos = (i64, i64);
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+use std::iter::FromIterator;
+
+use crate::{Bounds, Grid, Pos};
+
+/// An unbounded grid that only stores cells that have been set
+///
+/// The bounding box of all set cells is tracked as cells are added and removed.
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Pos, T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> SparseGrid<T> {
+    /// Create a new empty grid
+    pub fn new() -> Self {
+        SparseGrid { cells: HashMap::new(), bounds: None }
+    }
+
+    /// Number of cells that have been set
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Have no cells been set
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Bounding box of all set cells (`None` if the grid is empty)
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// Has the cell at this position been set
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    /// Get the cell at this position
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    /// Get a mutable reference to the cell at this position
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    /// Set the cell at this position, returning the previous value
+    pub fn insert(&mut self, pos: Pos, value: T) -> Option<T> {
+        match &mut self.bounds {
+            Some(bounds) => bounds.extend(pos),
+            None => self.bounds = Some(Bounds::from_pos(pos)),
+        }
+
+        self.cells.insert(pos, value)
+    }
+
+    /// Clear the cell at this position, returning the previous value
+    pub fn remove(&mut self, pos: Pos) -> Option<T> {
+        let value = self.cells.remove(&pos)?;
+        if self.bounds.map(|b| b.on_edge(pos)).unwrap_or(false) {
+            // The bounding box may have shrunk
+            self.bounds = Bounds::from_positions(self.cells.keys().copied());
+        }
+
+        Some(value)
+    }
+
+    /// Iterate over all set cells and their positions (in arbitrary order)
+    pub fn iter(&self) -> impl Iterator<Item=(Pos, &T)> {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    /// Iterate over the positions of all set cells (in arbitrary order)
+    pub fn positions(&self) -> impl Iterator<Item=Pos> + '_ {
+        self.cells.keys().copied()
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// Get the cell at this position or `default` if it has not been set
+    pub fn get_or(&self, pos: Pos, default: T) -> T {
+        self.cells.get(&pos).cloned().unwrap_or(default)
+    }
+
+    /// Copy the cells inside `bounds` to a dense grid, filling unset cells with `fill`
+    pub fn crop(&self, bounds: Bounds, fill: T) -> Grid<T> {
+        let mut grid = Grid::new(bounds, fill);
+        for (&pos, value) in &self.cells {
+            if let Some(cell) = grid.get_mut(pos) {
+                *cell = value.clone();
+            }
+        }
+
+        grid
+    }
+
+    /// Copy all cells to a dense grid, filling unset cells with `fill` (`None` if the grid is empty)
+    pub fn to_dense(&self, fill: T) -> Option<Grid<T>> {
+        self.bounds.map(|bounds| self.crop(bounds, fill))
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid::new()
+    }
+}
+
+impl<T> FromIterator<(Pos, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item=(Pos, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        grid.extend(iter);
+
+        grid
+    }
+}
+
+impl<T> Extend<(Pos, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item=(Pos, T)>>(&mut self, iter: I) {
+        for (pos, value) in iter {
+            self.insert(pos, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+
+        grid.insert((0, 0), 'a');
+        grid.insert((-5, 3), 'b');
+        grid.insert((2, -7), 'c');
+        assert_eq!(grid.bounds(), Some(Bounds::new((-5, -7), (2, 3))));
+
+        grid.remove((-5, 3));
+        assert_eq!(grid.bounds(), Some(Bounds::new((0, -7), (2, 0))));
+
+        grid.remove((0, 0));
+        grid.remove((2, -7));
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn test_crop() {
+        let grid: SparseGrid<char> = vec![((-1, -1), '#'), ((1, 1), '#')].into_iter().collect();
+        assert_eq!(grid.to_dense('.').unwrap().to_string(), "#..\n...\n..#\n");
+        assert_eq!(grid.crop(Bounds::new((0, 0), (1, 1)), '.').to_string(), "..\n.#\n");
+    }
+}
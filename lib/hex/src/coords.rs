@@ -0,0 +1,287 @@
+use std::{fmt, ops};
+
+/// Cube coordinates (`q + r + s == 0`)
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Debug, Default)]
+pub struct Cube {
+    pub q: i64,
+    pub r: i64,
+    pub s: i64,
+}
+
+impl Cube {
+    /// The unit offsets to each neighbouring hex
+    pub const DIRECTIONS: [Cube; 6] = [
+        Cube::new(1, 0, -1), Cube::new(1, -1, 0), Cube::new(0, -1, 1),
+        Cube::new(-1, 0, 1), Cube::new(-1, 1, 0), Cube::new(0, 1, -1),
+    ];
+
+    /// Create a new cube coordinate
+    pub const fn new(q: i64, r: i64, s: i64) -> Self {
+        Cube { q, r, s }
+    }
+
+    /// The origin
+    pub const fn origin() -> Self {
+        Cube::new(0, 0, 0)
+    }
+
+    /// Is this a valid cube coordinate
+    pub fn is_valid(self) -> bool {
+        self.q + self.r + self.s == 0
+    }
+
+    /// Number of steps from the origin
+    pub fn length(self) -> i64 {
+        (self.q.abs() + self.r.abs() + self.s.abs()) / 2
+    }
+
+    /// Number of steps to another hex
+    pub fn distance(self, other: Cube) -> i64 {
+        (other - self).length()
+    }
+
+    /// The six neighbouring hexes
+    pub fn neighbours(self) -> impl Iterator<Item=Cube> {
+        Cube::DIRECTIONS.iter().map(move |&d| self + d)
+    }
+
+    /// All hexes exactly `radius` steps away
+    pub fn ring(self, radius: i64) -> Vec<Cube> {
+        if radius == 0 {
+            return vec![self];
+        }
+
+        let mut result = Vec::with_capacity(6 * radius as usize);
+        let mut hex = self + Cube::DIRECTIONS[4] * radius;
+        for &direction in &Cube::DIRECTIONS {
+            for _ in 0..radius {
+                result.push(hex);
+                hex += direction;
+            }
+        }
+
+        result
+    }
+
+    /// All hexes within `radius` steps, from the centre outwards
+    pub fn spiral(self, radius: i64) -> Vec<Cube> {
+        (0..=radius).flat_map(|r| self.ring(r)).collect()
+    }
+
+    /// Hexes on the straight line to another hex (including both ends)
+    pub fn line_to(self, other: Cube) -> Vec<Cube> {
+        let n = self.distance(other);
+        if n == 0 {
+            return vec![self];
+        }
+
+        // Nudge the line slightly so that points on an edge are rounded consistently
+        let (q0, r0, s0) = (self.q as f64 + 1e-6, self.r as f64 + 1e-6, self.s as f64 - 2e-6);
+        let (q1, r1, s1) = (other.q as f64 + 1e-6, other.r as f64 + 1e-6, other.s as f64 - 2e-6);
+        (0..=n)
+            .map(|i| {
+                let t = i as f64 / n as f64;
+                Cube::round(q0 + (q1 - q0) * t, r0 + (r1 - r0) * t, s0 + (s1 - s0) * t)
+            })
+            .collect()
+    }
+
+    /// Round fractional cube coordinates to the nearest hex
+    pub fn round(q: f64, r: f64, s: f64) -> Cube {
+        let (mut rq, mut rr, mut rs) = (q.round(), r.round(), s.round());
+        let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+
+        // Fix up the component with the largest rounding error
+        if dq > dr && dq > ds {
+            rq = -rr - rs;
+        } else if dr > ds {
+            rr = -rq - rs;
+        } else {
+            rs = -rq - rr;
+        }
+
+        Cube::new(rq as i64, rr as i64, rs as i64)
+    }
+
+    /// Convert to offset coordinates
+    pub fn to_offset(self, layout: OffsetLayout) -> Offset {
+        use OffsetLayout::*;
+        let (q, r) = (self.q, self.r);
+        match layout {
+            OddR => Offset::new(q + (r - (r & 1)) / 2, r),
+            EvenR => Offset::new(q + (r + (r & 1)) / 2, r),
+            OddQ => Offset::new(q, r + (q - (q & 1)) / 2),
+            EvenQ => Offset::new(q, r + (q + (q & 1)) / 2),
+        }
+    }
+
+    /// Convert from offset coordinates
+    pub fn from_offset(offset: Offset, layout: OffsetLayout) -> Cube {
+        use OffsetLayout::*;
+        let (col, row) = (offset.col, offset.row);
+        let axial = match layout {
+            OddR => Axial::new(col - (row - (row & 1)) / 2, row),
+            EvenR => Axial::new(col - (row + (row & 1)) / 2, row),
+            OddQ => Axial::new(col, row - (col - (col & 1)) / 2),
+            EvenQ => Axial::new(col, row - (col + (col & 1)) / 2),
+        };
+
+        axial.into()
+    }
+}
+
+impl ops::Add for Cube {
+    type Output = Cube;
+
+    fn add(self, rhs: Cube) -> Self::Output {
+        Cube::new(self.q + rhs.q, self.r + rhs.r, self.s + rhs.s)
+    }
+}
+
+impl ops::AddAssign for Cube {
+    fn add_assign(&mut self, rhs: Cube) {
+        *self = *self + rhs;
+    }
+}
+
+impl ops::Sub for Cube {
+    type Output = Cube;
+
+    fn sub(self, rhs: Cube) -> Self::Output {
+        Cube::new(self.q - rhs.q, self.r - rhs.r, self.s - rhs.s)
+    }
+}
+
+impl ops::SubAssign for Cube {
+    fn sub_assign(&mut self, rhs: Cube) {
+        *self = *self - rhs;
+    }
+}
+
+impl ops::Mul<i64> for Cube {
+    type Output = Cube;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Cube::new(self.q * rhs, self.r * rhs, self.s * rhs)
+    }
+}
+
+impl ops::Neg for Cube {
+    type Output = Cube;
+
+    fn neg(self) -> Self::Output {
+        Cube::new(-self.q, -self.r, -self.s)
+    }
+}
+
+impl fmt::Display for Cube {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.q, self.r, self.s)
+    }
+}
+
+/// Axial coordinates (cube coordinates without the redundant `s`)
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Debug, Default)]
+pub struct Axial {
+    pub q: i64,
+    pub r: i64,
+}
+
+impl Axial {
+    /// Create a new axial coordinate
+    pub const fn new(q: i64, r: i64) -> Self {
+        Axial { q, r }
+    }
+}
+
+impl From<Cube> for Axial {
+    fn from(cube: Cube) -> Self {
+        Axial::new(cube.q, cube.r)
+    }
+}
+
+impl From<Axial> for Cube {
+    fn from(axial: Axial) -> Self {
+        Cube::new(axial.q, axial.r, -axial.q - axial.r)
+    }
+}
+
+/// Which rows (or columns) are shoved across in an offset layout
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum OffsetLayout {
+    /// Pointy-topped, odd rows shoved right
+    OddR,
+    /// Pointy-topped, even rows shoved right
+    EvenR,
+    /// Flat-topped, odd columns shoved down
+    OddQ,
+    /// Flat-topped, even columns shoved down
+    EvenQ,
+}
+
+/// Offset coordinates (column and row in a rectangular layout)
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Debug, Default)]
+pub struct Offset {
+    pub col: i64,
+    pub row: i64,
+}
+
+impl Offset {
+    /// Create a new offset coordinate
+    pub const fn new(col: i64, row: i64) -> Self {
+        Offset { col, row }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distance() {
+        let a = Cube::new(1, -3, 2);
+        let b = Cube::new(-2, 1, 1);
+        assert_eq!(a.distance(b), 4);
+        assert_eq!(b.distance(a), 4);
+        assert!(Cube::DIRECTIONS.iter().all(|d| d.is_valid() && d.length() == 1));
+    }
+
+    #[test]
+    fn test_ring() {
+        assert_eq!(Cube::origin().ring(0), vec![Cube::origin()]);
+        for radius in 1..5 {
+            let ring = Cube::origin().ring(radius);
+            assert_eq!(ring.len(), 6 * radius as usize);
+            assert!(ring.iter().all(|h| h.length() == radius));
+        }
+        assert_eq!(Cube::origin().spiral(2).len(), 19);
+    }
+
+    #[test]
+    fn test_line_to() {
+        let a = Cube::new(-2, 0, 2);
+        let b = Cube::new(3, -2, -1);
+        let line = a.line_to(b);
+        assert_eq!(line.len(), 6);
+        assert_eq!(line.first(), Some(&a));
+        assert_eq!(line.last(), Some(&b));
+        assert!(line.windows(2).all(|w| w[0].distance(w[1]) == 1));
+    }
+
+    #[test]
+    fn test_offset_roundtrip() {
+        use OffsetLayout::*;
+        for &layout in &[OddR, EvenR, OddQ, EvenQ] {
+            for hex in Cube::origin().spiral(3) {
+                assert_eq!(Cube::from_offset(hex.to_offset(layout), layout), hex);
+            }
+        }
+        assert_eq!(Cube::new(1, 1, -2).to_offset(OddR), Offset::new(1, 1));
+    }
+
+    #[test]
+    fn test_axial_roundtrip() {
+        let hex = Cube::new(3, -5, 2);
+        assert_eq!(Cube::from(Axial::from(hex)), hex);
+    }
+}
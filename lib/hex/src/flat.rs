@@ -0,0 +1,83 @@
+//! Directions on a grid of flat-topped hexes (`n`, `ne`, `se`, `s`, `sw`, `nw`).
+
+use std::str::FromStr;
+
+use crate::{Cube, ParseDirectionError};
+
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Debug)]
+pub enum Direction {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest,
+}
+
+impl Direction {
+    /// All directions, clockwise from north
+    pub const ALL: [Direction; 6] = [
+        Direction::North, Direction::NorthEast, Direction::SouthEast,
+        Direction::South, Direction::SouthWest, Direction::NorthWest,
+    ];
+
+    /// Unit offset in cube coordinates
+    pub fn offset(self) -> Cube {
+        use Direction::*;
+        match self {
+            SouthEast => Cube::DIRECTIONS[0],
+            NorthEast => Cube::DIRECTIONS[1],
+            North => Cube::DIRECTIONS[2],
+            NorthWest => Cube::DIRECTIONS[3],
+            SouthWest => Cube::DIRECTIONS[4],
+            South => Cube::DIRECTIONS[5],
+        }
+    }
+
+    /// Parse a run of directions without separators (e.g. `nnesw`)
+    pub fn parse_run(s: &str) -> Result<Vec<Direction>, ParseDirectionError> {
+        crate::tokenize(s, |t| t.parse::<Direction>().is_ok())
+            .map(|t| t?.parse())
+            .collect()
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Direction::*;
+        Ok(match s {
+            "n" => North,
+            "ne" => NorthEast,
+            "se" => SouthEast,
+            "s" => South,
+            "sw" => SouthWest,
+            "nw" => NorthWest,
+            _ => return Err(ParseDirectionError(s.to_owned())),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Direction::*;
+
+    #[test]
+    fn test_parse_run() {
+        assert_eq!(Direction::parse_run("nnesw").unwrap(), vec![North, NorthEast, SouthWest]);
+        assert!(Direction::parse_run("ne,e").is_err());
+    }
+
+    #[test]
+    fn test_offset() {
+        // Opposite directions cancel out
+        for i in 0..3 {
+            assert_eq!(Direction::ALL[i].offset() + Direction::ALL[i + 3].offset(), Cube::origin());
+        }
+        let pos = [NorthEast, NorthEast, South, South].iter()
+            .fold(Cube::origin(), |pos, d| pos + d.offset());
+        assert_eq!(pos.length(), 2);
+    }
+}
@@ -0,0 +1,53 @@
+//! Hexagonal grids.
+//!
+//! Positions are stored as [`Cube`] coordinates (see <https://www.redblobgames.com/grids/hexagons/>)
+//! and can be converted to and from [`Axial`] and [`Offset`] coordinates.
+//! Hexes are either [`flat`]-topped (moving `n`, `ne`, `se`, ...) or [`pointy`]-topped (moving `e`, `se`, `sw`, ...).
+
+mod coords;
+pub mod flat;
+pub mod pointy;
+
+pub use coords::{Axial, Cube, Offset, OffsetLayout};
+
+use std::fmt;
+
+/// Error parsing a hex direction
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ParseDirectionError(String);
+
+impl fmt::Display for ParseDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unknown direction: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseDirectionError {}
+
+/// Split a run of directions without separators (e.g. `esenee`) into tokens
+///
+/// Two-letter directions are preferred over one-letter directions.
+fn tokenize<'a>(s: &'a str, is_direction: impl Fn(&str) -> bool + 'a) -> impl Iterator<Item=Result<&'a str, ParseDirectionError>> + 'a {
+    let mut rest = s.trim();
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+
+        let token = [2, 1].iter()
+            .filter_map(|&n| rest.get(..n))
+            .find(|&t| is_direction(t));
+
+        match token {
+            Some(token) => {
+                rest = &rest[token.len()..];
+                Some(Ok(token))
+            },
+            None => {
+                let err = ParseDirectionError(rest.to_owned());
+                rest = "";
+                Some(Err(err))
+            },
+        }
+    })
+}
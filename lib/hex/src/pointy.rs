@@ -0,0 +1,79 @@
+//! Directions on a grid of pointy-topped hexes (`e`, `se`, `sw`, `w`, `nw`, `ne`).
+
+use std::str::FromStr;
+
+use crate::{Cube, ParseDirectionError};
+
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Debug)]
+pub enum Direction {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl Direction {
+    /// All directions, clockwise from east
+    pub const ALL: [Direction; 6] = [
+        Direction::East, Direction::SouthEast, Direction::SouthWest,
+        Direction::West, Direction::NorthWest, Direction::NorthEast,
+    ];
+
+    /// Unit offset in cube coordinates
+    pub fn offset(self) -> Cube {
+        use Direction::*;
+        match self {
+            East => Cube::DIRECTIONS[0],
+            NorthEast => Cube::DIRECTIONS[1],
+            NorthWest => Cube::DIRECTIONS[2],
+            West => Cube::DIRECTIONS[3],
+            SouthWest => Cube::DIRECTIONS[4],
+            SouthEast => Cube::DIRECTIONS[5],
+        }
+    }
+
+    /// Parse a run of directions without separators (e.g. `esenee`)
+    pub fn parse_run(s: &str) -> Result<Vec<Direction>, ParseDirectionError> {
+        crate::tokenize(s, |t| t.parse::<Direction>().is_ok())
+            .map(|t| t?.parse())
+            .collect()
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Direction::*;
+        Ok(match s {
+            "e" => East,
+            "se" => SouthEast,
+            "sw" => SouthWest,
+            "w" => West,
+            "nw" => NorthWest,
+            "ne" => NorthEast,
+            _ => return Err(ParseDirectionError(s.to_owned())),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Direction::*;
+
+    #[test]
+    fn test_parse_run() {
+        assert_eq!(Direction::parse_run("esenee").unwrap(), vec![East, SouthEast, NorthEast, East]);
+        assert!(Direction::parse_run("esnw").is_err());
+    }
+
+    #[test]
+    fn test_offset() {
+        let pos = [NorthWest, West, SouthWest, East, East].iter()
+            .fold(Cube::origin(), |pos, d| pos + d.offset());
+        assert_eq!(pos, Cube::origin());
+    }
+}
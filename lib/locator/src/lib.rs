@@ -0,0 +1,264 @@
+//! Finding the input of a day, wherever it's run from.
+//!
+//! A day's files are found relative to its own directory (the `CARGO_MANIFEST_DIR` it was built
+//! with, see [`locator!`]) rather than the current directory. Puzzle inputs can be kept outside
+//! the repository by setting `AOC_INPUT_DIR` to a directory laid out the same way,
+//! e.g. `$AOC_INPUT_DIR/2020/day18/input.txt`.
+//!
+//! On the command line `--input FILE` reads another file (or stdin if `FILE` is `-`)
+//! and `--sample N` reads the day's `sampleN.txt`.
+
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::{env, process};
+
+/// The default input file of a day
+pub const INPUT: &str = "input.txt";
+
+/// Environment variable giving a directory to read puzzle inputs from instead
+pub const INPUT_DIR: &str = "AOC_INPUT_DIR";
+
+/// The options understood by [`Locator::parse_args`]
+pub const USAGE: &str = "[--input FILE | --sample N]";
+
+/// A locator for the day being compiled
+#[macro_export]
+macro_rules! locator {
+    () => {
+        $crate::Locator::new(env!("CARGO_MANIFEST_DIR"))
+    };
+}
+
+/// The input chosen on the command line for the day being compiled
+///
+/// Exits with a usage message if given any other arguments.
+#[macro_export]
+macro_rules! input {
+    () => {
+        $crate::locator!().from_args()
+    };
+}
+
+/// Somewhere to read input from
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum Input {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Input {
+    /// A command-line argument, where `-` means stdin
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Input::Stdin,
+            path => Input::File(PathBuf::from(path)),
+        }
+    }
+
+    /// Read the whole input
+    ///
+    /// Stdin is only read once, so days that read their input more than once see the same input each time.
+    pub fn read_to_string(&self) -> io::Result<String> {
+        static STDIN: OnceLock<String> = OnceLock::new();
+
+        match self {
+            Input::Stdin => {
+                if let Some(input) = STDIN.get() {
+                    return Ok(input.clone());
+                }
+
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+
+                Ok(STDIN.get_or_init(|| input).clone())
+            },
+            Input::File(path) => fs::read_to_string(path)
+                .map_err(|err| io::Error::new(err.kind(), format!("{}: {}", path.display(), err))),
+        }
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Input::Stdin => write!(f, "stdin"),
+            Input::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+impl From<PathBuf> for Input {
+    fn from(path: PathBuf) -> Self {
+        Input::File(path)
+    }
+}
+
+impl From<&Path> for Input {
+    fn from(path: &Path) -> Self {
+        Input::File(path.to_owned())
+    }
+}
+
+impl From<&str> for Input {
+    fn from(path: &str) -> Self {
+        Input::File(PathBuf::from(path))
+    }
+}
+
+impl From<String> for Input {
+    fn from(path: String) -> Self {
+        Input::File(PathBuf::from(path))
+    }
+}
+
+/// Finds the files of a day
+#[derive(Clone, Debug)]
+pub struct Locator {
+    dir: PathBuf,
+    input_dir: Option<PathBuf>,
+}
+
+impl Locator {
+    /// A locator for the day in `dir`, using `AOC_INPUT_DIR` if it's set
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Locator { dir: dir.into(), input_dir: env::var_os(INPUT_DIR).map(PathBuf::from) }
+    }
+
+    /// Read puzzle inputs from `input_dir` (or the day's directory if `None`)
+    pub fn with_input_dir(self, input_dir: Option<PathBuf>) -> Self {
+        Locator { input_dir, ..self }
+    }
+
+    /// The day's directory
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// A locator for another day of the same year (e.g. `day02`)
+    pub fn day(&self, name: &str) -> Self {
+        let dir = self.dir.parent().unwrap_or_else(|| Path::new("")).join(name);
+
+        Locator { dir, ..self.clone() }
+    }
+
+    /// The day's puzzle input
+    pub fn input(&self) -> Input {
+        self.file(INPUT)
+    }
+
+    /// A puzzle input file, found in the input directory if there is one
+    pub fn file(&self, name: &str) -> Input {
+        let dir = match (&self.input_dir, self.year_and_day()) {
+            (Some(input_dir), Some((year, day))) => input_dir.join(year).join(day),
+            _ => self.dir.clone(),
+        };
+
+        Input::File(dir.join(name))
+    }
+
+    /// The day's `sampleN.txt`, which always lives with the code
+    pub fn sample(&self, n: u32) -> Input {
+        Input::File(self.dir.join(format!("sample{}.txt", n)))
+    }
+
+    /// The last two components of the day's directory (e.g. `2020` and `day18`)
+    fn year_and_day(&self) -> Option<(&Path, &Path)> {
+        let day = self.dir.file_name()?;
+        let year = self.dir.parent()?.file_name()?;
+
+        Some((Path::new(year), Path::new(day)))
+    }
+
+    /// Choose the input from command-line arguments, returning it and the arguments it didn't use
+    pub fn parse_args<I: IntoIterator<Item=String>>(&self, args: I) -> Result<(Input, Vec<String>), String> {
+        let mut input = None;
+        let mut rest = Vec::new();
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let chosen = match arg.as_str() {
+                "--input" => {
+                    let path = args.next().ok_or("--input requires a FILE")?;
+                    Input::from_arg(&path)
+                },
+                "--sample" => {
+                    let n = args.next().and_then(|n| n.parse().ok()).ok_or("--sample requires a number")?;
+                    self.sample(n)
+                },
+                _ => {
+                    rest.push(arg);
+                    continue;
+                },
+            };
+
+            if input.replace(chosen).is_some() {
+                return Err(String::from("Only one of --input or --sample may be given"));
+            }
+        }
+
+        Ok((input.unwrap_or_else(|| self.input()), rest))
+    }
+
+    /// The input chosen on the command line, along with the remaining arguments
+    ///
+    /// Exits with a usage message if the input options are invalid.
+    pub fn split_args(&self) -> (Input, Vec<String>) {
+        self.parse_args(env::args().skip(1)).unwrap_or_else(|err| usage(&err))
+    }
+
+    /// The input chosen on the command line
+    ///
+    /// Exits with a usage message if given any other arguments.
+    pub fn from_args(&self) -> Input {
+        let (input, rest) = self.split_args();
+        if let Some(arg) = rest.first() {
+            usage(&format!("Unexpected argument {:?}", arg));
+        }
+
+        input
+    }
+}
+
+fn usage(err: &str) -> ! {
+    eprintln!("{}", err);
+    eprintln!("Usage: {}", USAGE);
+    process::exit(2);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|&arg| arg.to_owned()).collect()
+    }
+
+    #[test]
+    fn test_file() {
+        let locator = Locator::new("/aoc/2020/day18").with_input_dir(None);
+        assert_eq!(locator.input(), Input::File(PathBuf::from("/aoc/2020/day18/input.txt")));
+        assert_eq!(locator.sample(2), Input::File(PathBuf::from("/aoc/2020/day18/sample2.txt")));
+        assert_eq!(locator.day("day02").input(), Input::File(PathBuf::from("/aoc/2020/day02/input.txt")));
+
+        let locator = locator.with_input_dir(Some(PathBuf::from("/inputs")));
+        assert_eq!(locator.input(), Input::File(PathBuf::from("/inputs/2020/day18/input.txt")));
+        assert_eq!(locator.file("input2.txt"), Input::File(PathBuf::from("/inputs/2020/day18/input2.txt")));
+        assert_eq!(locator.sample(1), Input::File(PathBuf::from("/aoc/2020/day18/sample1.txt")));
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let locator = Locator::new("/aoc/2020/day18").with_input_dir(None);
+        assert_eq!(locator.parse_args(args(&[])), Ok((locator.input(), vec![])));
+        assert_eq!(locator.parse_args(args(&["--input", "-", "--turbo"])), Ok((Input::Stdin, args(&["--turbo"]))));
+        assert_eq!(locator.parse_args(args(&["--input", "in.txt"])), Ok((Input::from("in.txt"), vec![])));
+        assert_eq!(locator.parse_args(args(&["-v", "--sample", "2"])), Ok((locator.sample(2), args(&["-v"]))));
+
+        assert!(locator.parse_args(args(&["--input"])).is_err());
+        assert!(locator.parse_args(args(&["--sample", "two"])).is_err());
+        assert!(locator.parse_args(args(&["--sample", "1", "--input", "-"])).is_err());
+    }
+}
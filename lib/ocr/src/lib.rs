@@ -0,0 +1,103 @@
+//! Reading answers drawn in block letters.
+//!
+//! Some puzzles answer with letters drawn as pixel art, in one of two fonts: 4×6 letters
+//! (e.g. 2019 day 8) or 6×10 letters (e.g. 2018 day 10). [`read`] turns a [`Grid`] of pixels
+//! back into text, so the answer can be printed (and checked) like any other.
+//!
+//! Only the letters that have turned up in puzzles are known.
+
+mod font;
+
+use grid::{Bounds, Grid, Pos};
+
+use font::{Font, LARGE, SMALL};
+
+/// Read the letters drawn by the pixels of `grid` for which `lit` is true
+///
+/// Blank space around the letters is ignored. Gives `None` if the letters aren't in a known font.
+pub fn read<T>(grid: &Grid<T>, lit: impl Fn(&T) -> bool) -> Option<String> {
+    let is_lit = |pos: Pos| grid.get(pos).is_some_and(&lit);
+    let bounds = Bounds::from_positions(grid.bounds().positions().filter(|&pos| is_lit(pos)))?;
+    let font = [SMALL, LARGE].iter().find(|font| font.height == bounds.height())?;
+
+    // Most letters start with a lit column, but some (e.g. `J`) don't
+    (0..font.pitch as i64).find_map(|shift| read_line(font, &is_lit, bounds, bounds.min.0 - shift))
+}
+
+/// Read letters at every `font.pitch` columns from `left`
+fn read_line(font: &Font, is_lit: &impl Fn(Pos) -> bool, bounds: Bounds, left: i64) -> Option<String> {
+    (left..=bounds.max.0).step_by(font.pitch).map(|x| {
+        let matches = |glyph: &[&str]| (0..font.height).all(|row| (0..font.pitch).all(|column| {
+            is_lit((x + column as i64, bounds.min.1 + row as i64)) == Font::lit(glyph, column, row)
+        }));
+
+        font.glyphs.iter().find(|(_, glyph)| matches(glyph)).map(|&(c, _)| c)
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(rows: &[&str]) -> Grid<char> {
+        Grid::from_rows(rows.iter().map(|row| row.chars().collect()))
+    }
+
+    #[test]
+    fn test_small() {
+        // 2019 day 8, including a `Y` running into the next letter
+        let image = grid(&[
+            "#.....##..#...##..#.###..",
+            "#....#..#.#...##..#.#..#.",
+            "#....#.....#.#.####.###..",
+            "#....#.##...#..#..#.#..#.",
+            "#....#..#...#..#..#.#..#.",
+            "####..###...#..#..#.###..",
+        ]);
+        assert_eq!(read(&image, |&c| c == '#'), Some(String::from("LGYHB")));
+    }
+
+    #[test]
+    fn test_large() {
+        // 2018 day 10, with a `J` that doesn't start with a lit column
+        let image = grid(&[
+            "..........................",
+            "..######.....###..#....#..",
+            "..#...........#...#....#..",
+            "..#...........#....#..#...",
+            "..#...........#....#..#...",
+            "..#####.......#.....##....",
+            "..#...........#.....##....",
+            "..#...........#....#..#...",
+            "..#.......#...#....#..#...",
+            "..#.......#...#...#....#..",
+            "..######...###....#....#..",
+            "..........................",
+        ]);
+        assert_eq!(read(&image, |&c| c == '#'), Some(String::from("EJX")));
+    }
+
+    #[test]
+    fn test_fonts() {
+        for font in &[SMALL, LARGE] {
+            for (c, glyph) in font.glyphs {
+                assert_eq!(glyph.len(), font.height, "Rows of {:?}", c);
+                assert!(glyph.iter().all(|row| row.len() <= font.pitch), "Width of {:?}", c);
+            }
+        }
+    }
+
+    #[test]
+    fn test_unknown() {
+        let image = grid(&[
+            "#...#",
+            "##.##",
+            "#.#.#",
+            "#...#",
+            "#...#",
+            "#...#",
+        ]);
+        assert_eq!(read(&image, |&c| c == '#'), None);
+        assert_eq!(read(&grid(&["...."]), |&c| c == '#'), None);
+    }
+}
@@ -0,0 +1,150 @@
+//! Parsing puzzle inputs, with errors that say where the input went wrong.
+//!
+//! A [`ParseError`] points at the offending text by line and column. Parsers break the input up
+//! into [`Span`]s (e.g. lines, then their whitespace-separated [`Fields`]), each of which knows
+//! where it is in the whole input:
+//!
+//! ```
+//! use parsing::{ParseError, Span};
+//!
+//! fn parse(input: &str) -> Result<Vec<(String, i32)>, ParseError> {
+//!     Span::new(input).lines().map(|line| {
+//!         let mut fields = line.fields();
+//!         let name = fields.next("name")?.as_str().to_owned();
+//!         let value = fields.parse("value")?;
+//!         fields.end()?;
+//!
+//!         Ok((name, value))
+//!     }).collect()
+//! }
+//!
+//! let err = parse("a 1\nb x\n").unwrap_err();
+//! assert_eq!(err.to_string(), "line 2, column 3: Invalid value (invalid digit found in string): \"x\"");
+//! ```
+//!
+//! Spans also break up the usual puzzle formats (records separated by blank lines, lists,
+//! numbers embedded in text, `key:value` pairs and grids of characters):
+//!
+//! ```
+//! use parsing::{ParseError, Span};
+//!
+//! fn tile(record: Span) -> Result<(u32, grid::Grid<bool>), ParseError> {
+//!     let (header, image) = record.key_value("\n")?;
+//!     let id = header.between("Tile ", ":")?.parse("tile ID")?;
+//!     let image = image.grid(|c| match c { '#' => Some(true), '.' => Some(false), _ => None })?;
+//!
+//!     Ok((id, image))
+//! }
+//!
+//! let input = "Tile 1:\n#.\n.#\n\nTile 2:\n.#x\n";
+//! let err = Span::new(input).records().map(tile).collect::<Result<Vec<_>, _>>().unwrap_err();
+//! assert_eq!(err.to_string(), "line 6, column 3: Unknown cell: \"x\"");
+//! ```
+
+use std::fmt;
+use std::process;
+
+use locator::Input;
+
+mod span;
+
+pub use span::{Fields, Span};
+
+/// A problem with part of an input
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    text: String,
+    message: String,
+}
+
+impl ParseError {
+    /// An error about `text`, which should be a slice of `input`
+    ///
+    /// Text that isn't part of `input` is looked for in it instead, or else is taken to be at the end.
+    pub(crate) fn at(input: &str, text: &str, message: impl Into<String>) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = match (text.as_ptr() as usize).checked_sub(start) {
+            Some(offset) if offset + text.len() <= input.len() => offset,
+            _ => input.find(text).unwrap_or(input.len()),
+        };
+
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rfind('\n').map_or(before, |n| &before[n + 1..]).chars().count() + 1;
+
+        ParseError { line, column, text: text.to_owned(), message: message.into() }
+    }
+
+    /// The line with the problem (from 1)
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The column the offending text starts at (from 1)
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)?;
+        if !self.text.is_empty() {
+            write!(f, ": {:?}", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// For `runner::Solution::parse`, which explains errors as a `String`
+impl From<ParseError> for String {
+    fn from(err: ParseError) -> String {
+        err.to_string()
+    }
+}
+
+/// Read and parse an input, or exit saying what's wrong with it
+pub fn read_input<T>(input: impl Into<Input>, parse: impl FnOnce(&str) -> Result<T, ParseError>) -> T {
+    let input = input.into();
+    let text = input.read_to_string().unwrap_or_else(|err| {
+        eprintln!("Failed to read {}: {}", input, err);
+        process::exit(1);
+    });
+
+    parse(&text).unwrap_or_else(|err| {
+        eprintln!("Failed to parse {}: {}", input, err);
+        process::exit(1);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at() {
+        let input = "abc\ndéf ghi\n";
+        let line = input.lines().nth(1).unwrap();
+        let err = ParseError::at(input, &line[5..], "Bad");
+        assert_eq!((err.line(), err.column(), err.text()), (2, 5, "ghi"));
+        assert_eq!(err.to_string(), "line 2, column 5: Bad: \"ghi\"");
+
+        // Text that isn't a slice of the input
+        assert_eq!(ParseError::at(input, &String::from("c"), "Bad").column(), 3);
+        let err = ParseError::at(input, "xyz", "Truncated");
+        assert_eq!((err.line(), err.column()), (3, 1));
+    }
+}
@@ -0,0 +1,321 @@
+use std::convert::TryInto;
+use std::fmt;
+use std::ops::Range;
+use std::str::{FromStr, SplitWhitespace};
+
+use grid::Grid;
+
+use crate::ParseError;
+
+/// Part of an input, which knows where it is in the whole input
+///
+/// Each way of breaking a span up gives more spans, so errors anywhere can say where they are.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Span<'a> {
+    input: &'a str,
+    text: &'a str,
+}
+
+impl<'a> Span<'a> {
+    /// The whole of an input
+    pub fn new(input: &'a str) -> Self {
+        Span { input, text: input }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    /// An error about this span, quoting only its first line
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        let first_line = self.text.split('\n').next().unwrap_or_default();
+
+        ParseError::at(self.input, first_line, message)
+    }
+
+    /// The empty span just after this one, for errors about something missing
+    pub fn end(self) -> Span<'a> {
+        self.sub(&self.text[self.text.len()..])
+    }
+
+    /// The part of the span at the byte offsets `range` (e.g. of a regex match in it)
+    pub fn slice(self, range: Range<usize>) -> Span<'a> {
+        self.sub(&self.text[range])
+    }
+
+    /// A span of `text`, which must be part of this one
+    fn sub(&self, text: &'a str) -> Span<'a> {
+        Span { input: self.input, text }
+    }
+
+    /// The span without leading or trailing whitespace
+    pub fn trim(self) -> Span<'a> {
+        self.sub(self.text.trim())
+    }
+
+    pub fn lines(self) -> impl Iterator<Item=Span<'a>> {
+        self.text.lines().map(move |line| self.sub(line))
+    }
+
+    /// The whitespace-separated fields of the span
+    pub fn fields(self) -> Fields<'a> {
+        Fields { line: self, fields: self.text.split_whitespace() }
+    }
+
+    /// Groups of lines separated by blank lines (e.g. the passports of 2020 day 4)
+    pub fn records(self) -> impl Iterator<Item=Span<'a>> {
+        self.text.split("\n\n")
+            .map(|record| record.trim_matches('\n'))
+            .filter(|record| !record.is_empty())
+            .map(move |record| self.sub(record))
+    }
+
+    /// Exactly `N` records (e.g. the rules, your ticket and nearby tickets of 2020 day 16)
+    pub fn sections<const N: usize>(self) -> Result<[Span<'a>; N], ParseError> {
+        let records: Vec<_> = self.records().collect();
+        let n = records.len();
+
+        records.try_into().map_err(|_| self.end().error(format!("Expected {} sections separated by blank lines, not {}", N, n)))
+    }
+
+    /// Parts separated by `separator`, without surrounding whitespace
+    pub fn split(self, separator: &'a str) -> impl Iterator<Item=Span<'a>> {
+        self.text.split(separator).map(move |part| self.sub(part).trim())
+    }
+
+    /// Parse the whole span, described by `what` if it's invalid
+    pub fn parse<T: FromStr>(self, what: &str) -> Result<T, ParseError>
+        where T::Err: fmt::Display
+    {
+        self.text.parse().map_err(|err| self.error(format!("Invalid {} ({})", what, err)))
+    }
+
+    /// Values separated by `separator` (e.g. `0,3,6`)
+    pub fn list<T: FromStr>(self, separator: &'a str, what: &str) -> Result<Vec<T>, ParseError>
+        where T::Err: fmt::Display
+    {
+        self.split(separator).map(|part| part.parse(what)).collect()
+    }
+
+    /// Every integer in the span, which may be signed, ignoring anything between them
+    ///
+    /// For example, `position=< 9, -1>` has the numbers 9 and -1. A sign straight after a number
+    /// separates it from the next one instead, so `1-3` has the numbers 1 and 3.
+    pub fn numbers<T: FromStr>(self) -> Result<Vec<T>, ParseError>
+        where T::Err: fmt::Display
+    {
+        let bytes = self.text.as_bytes();
+        let mut numbers = Vec::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            let sign = matches!(bytes[i], b'-' | b'+')
+                && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+                && !(i > 0 && bytes[i - 1].is_ascii_digit());
+            if !sign && !bytes[i].is_ascii_digit() {
+                i += 1;
+                continue;
+            }
+
+            let start = i;
+            i += 1;
+            while bytes.get(i).is_some_and(u8::is_ascii_digit) {
+                i += 1;
+            }
+            numbers.push(self.sub(&self.text[start..i]).parse("number")?);
+        }
+
+        Ok(numbers)
+    }
+
+    /// Exactly `N` integers, as found by [`Span::numbers`]
+    pub fn numbers_exact<T: FromStr, const N: usize>(self) -> Result<[T; N], ParseError>
+        where T::Err: fmt::Display
+    {
+        let numbers = self.numbers()?;
+        let n = numbers.len();
+
+        numbers.try_into().map_err(|_| self.error(format!("Expected {} numbers, not {}", N, n)))
+    }
+
+    /// Split at the first `separator` (e.g. `byr:1937`)
+    pub fn key_value(self, separator: &str) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        match self.text.split_once(separator) {
+            Some((key, value)) => Ok((self.sub(key), self.sub(value))),
+            None => Err(self.error(format!("Expected `key{}value`", separator))),
+        }
+    }
+
+    /// Whitespace-separated keys and values (e.g. `ecl:gry pid:860033327`)
+    pub fn key_values(self, separator: &str) -> Result<Vec<(Span<'a>, Span<'a>)>, ParseError> {
+        self.text.split_whitespace().map(|pair| self.sub(pair).key_value(separator)).collect()
+    }
+
+    /// The text between a `prefix` and `suffix` (e.g. the `2311` of `Tile 2311:`)
+    pub fn between(self, prefix: &str, suffix: &str) -> Result<Span<'a>, ParseError> {
+        match self.text.strip_prefix(prefix).and_then(|rest| rest.strip_suffix(suffix)) {
+            Some(text) => Ok(self.sub(text)),
+            None => Err(self.error(format!("Expected `{}...{}`", prefix, suffix))),
+        }
+    }
+
+    /// A grid of characters, each turned into a cell by `cell` (or `None` if it's not valid)
+    pub fn grid<T>(self, cell: impl Fn(char) -> Option<T>) -> Result<Grid<T>, ParseError> {
+        let mut rows = Vec::new();
+        for line in self.lines() {
+            if rows.first().is_some_and(|row: &Vec<T>| row.len() != line.text.chars().count()) {
+                return Err(line.error(format!("Expected rows {} wide", rows[0].len())));
+            }
+
+            let row = line.text.char_indices()
+                .map(|(i, c)| cell(c).ok_or_else(|| line.sub(&line.text[i..i + c.len_utf8()]).error("Unknown cell")))
+                .collect::<Result<_, _>>()?;
+            rows.push(row);
+        }
+
+        if rows.first().is_none_or(Vec::is_empty) {
+            return Err(self.error("Empty grid"));
+        }
+
+        Ok(Grid::from_rows(rows))
+    }
+}
+
+/// The whitespace-separated fields of a span (e.g. of a line)
+pub struct Fields<'a> {
+    line: Span<'a>,
+    fields: SplitWhitespace<'a>,
+}
+
+impl<'a> Fields<'a> {
+    /// The next field, described by `what` if it's missing
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self, what: &str) -> Result<Span<'a>, ParseError> {
+        match self.fields.next() {
+            Some(field) => Ok(self.line.sub(field)),
+            None => Err(self.line.end().error(format!("Missing {}", what))),
+        }
+    }
+
+    /// Parse the next field, described by `what` if it's missing or invalid
+    pub fn parse<T: FromStr>(&mut self, what: &str) -> Result<T, ParseError>
+        where T::Err: fmt::Display
+    {
+        self.next(what)?.parse(what)
+    }
+
+    /// The next field, which must be `expected`
+    pub fn expect(&mut self, expected: &str) -> Result<(), ParseError> {
+        match self.next(&format!("{:?}", expected))? {
+            field if field.text == expected => Ok(()),
+            field => Err(field.error(format!("Expected {:?}", expected))),
+        }
+    }
+
+    /// Check there are no more fields
+    pub fn end(mut self) -> Result<(), ParseError> {
+        match self.fields.next() {
+            None => Ok(()),
+            Some(field) => Err(self.line.sub(field).error("Unexpected field")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_records() {
+        let input = "a\nb\n\nc\n\n\nd\n";
+        let records: Vec<_> = Span::new(input).records().map(|r| r.as_str()).collect();
+        assert_eq!(records, ["a\nb", "c", "d"]);
+
+        let [_, second, _] = Span::new(input).sections().unwrap();
+        assert_eq!(second.error("Bad").line(), 4);
+        assert_eq!(Span::new(input).sections::<2>().unwrap_err().to_string(),
+                   "line 8, column 1: Expected 2 sections separated by blank lines, not 3");
+        assert_eq!(second.error("Bad").text(), "c");
+        assert_eq!(Span::new(input).error("Bad").text(), "a");
+    }
+
+    #[test]
+    fn test_fields() {
+        let input = "inc 5 if\nx";
+        let mut fields = Span::new(input).lines().next().unwrap().fields();
+        assert_eq!(fields.next("op").map(|op| op.as_str()), Ok("inc"));
+        assert_eq!(fields.parse::<i32>("amount"), Ok(5));
+        assert_eq!(fields.expect("if"), Ok(()));
+        assert_eq!(fields.next("register").unwrap_err().to_string(), "line 1, column 9: Missing register");
+        assert_eq!(fields.end(), Ok(()));
+
+        let mut fields = Span::new(input).fields();
+        assert_eq!(fields.expect("dec").unwrap_err().to_string(), "line 1, column 1: Expected \"dec\": \"inc\"");
+        assert_eq!(fields.parse::<u8>("amount"), Ok(5));
+        assert_eq!(fields.end().unwrap_err().to_string(), "line 1, column 7: Unexpected field: \"if\"");
+    }
+
+    #[test]
+    fn test_list() {
+        let input = "0, 3,6\n1,x";
+        let mut lines = Span::new(input).lines();
+        assert_eq!(lines.next().unwrap().list::<u32>(",", "number"), Ok(vec![0, 3, 6]));
+        assert_eq!(lines.next().unwrap().list::<u32>(",", "number").unwrap_err().to_string(),
+                   "line 2, column 3: Invalid number (invalid digit found in string): \"x\"");
+    }
+
+    #[test]
+    fn test_numbers() {
+        let span = Span::new("position=< 9, -1> velocity=<+2,3>-");
+        assert_eq!(span.numbers::<i32>(), Ok(vec![9, -1, 2, 3]));
+        assert_eq!(span.numbers_exact::<i32, 4>(), Ok([9, -1, 2, 3]));
+        assert_eq!(span.numbers_exact::<i32, 2>().unwrap_err().message(), "Expected 2 numbers, not 4");
+        assert_eq!(Span::new("1-3 a:-2+-4").numbers::<i32>(), Ok(vec![1, 3, -2, -4]));
+
+        let err = Span::new("1 300").numbers::<u8>().unwrap_err();
+        assert_eq!((err.column(), err.text()), (3, "300"));
+    }
+
+    #[test]
+    fn test_key_values() {
+        let input = "ecl:gry pid:860033327\nhcl";
+        let pairs = Span::new(input).key_values(":").unwrap_err();
+        assert_eq!((pairs.line(), pairs.column(), pairs.message()), (2, 1, "Expected `key:value`"));
+
+        let pairs = Span::new(input).lines().next().unwrap().key_values(":").unwrap();
+        let pairs: Vec<_> = pairs.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+        assert_eq!(pairs, [("ecl", "gry"), ("pid", "860033327")]);
+    }
+
+    #[test]
+    fn test_between() {
+        let span = Span::new("Tile 2311:");
+        assert_eq!(span.between("Tile ", ":").and_then(|id| id.parse("tile ID")), Ok(2311));
+        assert!(span.between("Tile ", ".").is_err());
+    }
+
+    #[test]
+    fn test_slice() {
+        let line = Span::new("a\n12 units").lines().nth(1).unwrap();
+        let err = line.slice(3..8).error("Bad");
+        assert_eq!((err.line(), err.column(), err.text()), (2, 4, "units"));
+    }
+
+    #[test]
+    fn test_grid() {
+        let cell = |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        };
+
+        let grid = Span::new("#.\n.#\n").grid(cell).unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!((grid[(0, 0)], grid[(1, 0)]), (true, false));
+
+        let err = Span::new("#.\n.x\n").grid(cell).unwrap_err();
+        assert_eq!((err.line(), err.column(), err.text()), (2, 2, "x"));
+        let err = Span::new("#.\n.\n").grid(cell).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: Expected rows 2 wide: \".\"");
+        assert!(Span::new("").grid(cell).is_err());
+    }
+}
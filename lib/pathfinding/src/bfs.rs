@@ -0,0 +1,84 @@
+use crate::{Graph, Search, State};
+
+/// Breadth-first search from one or more starting states
+///
+/// Edge costs are ignored: each edge counts as a single step.
+/// The search stops once a state matching `goal` is reached (use `|_| false` to explore
+/// every reachable state).
+pub fn bfs<S, G>(graph: &G, starts: impl IntoIterator<Item=S>, mut goal: impl FnMut(&S) -> bool) -> Search<S, usize>
+    where S: State, G: Graph<S>
+{
+    let mut edge: Vec<S> = starts.into_iter().collect();
+    let mut search = Search::new(edge.iter().cloned());
+
+    let mut steps = 0;
+    while !edge.is_empty() {
+        // Every state in the edge is reached before any of their neighbours are visited,
+        // so all predecessors of a goal have been recorded by the time it is found
+        if let Some(found) = edge.iter().find(|&s| goal(s)) {
+            search.set_goal(found.clone());
+            break;
+        }
+
+        steps += 1;
+        let mut new_edge = Vec::new();
+        for state in &edge {
+            for (neighbour, _) in graph.neighbours(state) {
+                if search.relax(state, &neighbour, steps) {
+                    new_edge.push(neighbour);
+                }
+            }
+        }
+
+        edge = new_edge;
+    }
+
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Pos = (i32, i32);
+
+    /// An open 5x5 room
+    fn room(&(x, y): &Pos) -> Vec<(Pos, usize)> {
+        [(0, -1), (-1, 0), (1, 0), (0, 1)].iter()
+            .map(|&(dx, dy)| (x + dx, y + dy))
+            .filter(|&(x, y)| (0..5).contains(&x) && (0..5).contains(&y))
+            .map(|pos| (pos, 1))
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let search = bfs(&room, vec![(0, 0)], |_| false);
+        assert_eq!(search.len(), 25);
+        assert_eq!(search.cost(&(4, 4)), Some(8));
+        assert_eq!(search.path_to(&(4, 4)).map(|p| p.len()), Some(9));
+        assert_eq!(search.goal(), None);
+    }
+
+    #[test]
+    fn test_bfs_multiple_starts() {
+        let search = bfs(&room, vec![(0, 0), (4, 4)], |&(x, y)| x == 4 && y == 0);
+        assert_eq!(search.goal_cost(), Some(4));
+        assert_eq!(search.cost(&(2, 2)), Some(4));
+        assert_eq!(search.cost(&(4, 1)), Some(3));
+    }
+
+    #[test]
+    fn test_path_to_by_key() {
+        let search = bfs(&room, vec![(0, 0)], |&pos| pos == (2, 2));
+        assert_eq!(search.predecessors(&(1, 1)).len(), 2);
+
+        // Prefer moving in reading order (up, left, right, down)
+        let path = search.path_to_by_key(&(2, 2), |&(x, y)| (y, x)).unwrap();
+        assert_eq!(path, vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)]);
+
+        // Prefer moving down first
+        let path = search.path_to_by_key(&(2, 2), |&(x, y)| (x, y)).unwrap();
+        assert_eq!(path, vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)]);
+    }
+}
@@ -0,0 +1,161 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use crate::{Cost, Graph, State};
+
+/// Bidirectional Dijkstra search between two states
+///
+/// Searches forwards from `start` using `forward` and backwards from `goal` using `backward`,
+/// which must return the states that lead *to* a state (for undirected graphs, pass the same graph twice).
+/// Returns the lowest cost and a path from `start` to `goal` (inclusive), if one exists.
+pub fn bidirectional<S, G, R>(forward: &G, backward: &R, start: S, goal: S) -> Option<(G::Cost, Vec<S>)>
+    where S: State, G: Graph<S>, R: Graph<S, Cost=G::Cost>
+{
+    let mut forwards = Frontier::new(start);
+    let mut backwards = Frontier::new(goal);
+
+    // Lowest total cost found so far and where the two searches met
+    let mut best: Option<(G::Cost, S)> = None;
+
+    while let (Some(f), Some(b)) = (forwards.peek(), backwards.peek()) {
+        // Neither search can find anything cheaper
+        if matches!(&best, Some((cost, _)) if f + b >= *cost) {
+            break;
+        }
+
+        // Always grow the smaller search
+        if forwards.visited.len() <= backwards.visited.len() {
+            forwards.expand(forward, &backwards, &mut best);
+        } else {
+            backwards.expand(backward, &forwards, &mut best);
+        }
+    }
+
+    let (cost, meet) = best?;
+    let mut path = forwards.path_to(&meet);
+    path.pop();
+    path.extend(backwards.path_to(&meet).into_iter().rev());
+
+    Some((cost, path))
+}
+
+/// One direction of a bidirectional search
+struct Frontier<S, C> {
+    edge: BinaryHeap<Reverse<(C, usize)>>,
+    queued: Vec<S>,
+    costs: HashMap<S, C>,
+    parents: HashMap<S, S>,
+    visited: HashSet<S>,
+}
+
+impl<S: State, C: Cost> Frontier<S, C> {
+    fn new(start: S) -> Self {
+        let mut frontier = Frontier {
+            edge: BinaryHeap::new(),
+            queued: Vec::new(),
+            costs: HashMap::new(),
+            parents: HashMap::new(),
+            visited: HashSet::new(),
+        };
+        frontier.costs.insert(start.clone(), C::default());
+        frontier.push(start, C::default());
+
+        frontier
+    }
+
+    fn push(&mut self, state: S, cost: C) {
+        self.edge.push(Reverse((cost, self.queued.len())));
+        self.queued.push(state);
+    }
+
+    /// The lowest cost of any state yet to be expanded
+    fn peek(&mut self) -> Option<C> {
+        while let Some(&Reverse((cost, i))) = self.edge.peek() {
+            if !self.visited.contains(&self.queued[i]) {
+                return Some(cost);
+            }
+            self.edge.pop();
+        }
+
+        None
+    }
+
+    /// Expand the lowest cost state, updating `best` if it meets the `other` search
+    fn expand(&mut self, graph: &impl Graph<S, Cost=C>, other: &Self, best: &mut Option<(C, S)>) {
+        let Reverse((cost, i)) = self.edge.pop().expect("Frontier should not be empty");
+        let state = self.queued[i].clone();
+        self.visited.insert(state.clone());
+
+        if let Some(&other_cost) = other.costs.get(&state) {
+            update_best(best, cost + other_cost, &state);
+        }
+
+        for (neighbour, edge_cost) in graph.neighbours(&state) {
+            let new_cost = cost + edge_cost;
+            if matches!(self.costs.get(&neighbour), Some(&c) if c <= new_cost) {
+                continue;
+            }
+
+            self.costs.insert(neighbour.clone(), new_cost);
+            self.parents.insert(neighbour.clone(), state.clone());
+            if let Some(&other_cost) = other.costs.get(&neighbour) {
+                update_best(best, new_cost + other_cost, &neighbour);
+            }
+            self.push(neighbour, new_cost);
+        }
+    }
+
+    /// Path from the start of this search to `state` (inclusive)
+    fn path_to(&self, state: &S) -> Vec<S> {
+        let mut path = vec![state.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+
+        path
+    }
+}
+
+fn update_best<S: Clone, C: Cost>(best: &mut Option<(C, S)>, cost: C, state: &S) {
+    if !matches!(best, Some((c, _)) if *c <= cost) {
+        *best = Some((cost, state.clone()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bidirectional() {
+        // Numbers connected by +1 and *2
+        let forward = |&n: &u32| vec![(n + 1, 1), (n * 2, 1)];
+        let backward = |&n: &u32| {
+            let mut previous = Vec::new();
+            if n > 1 {
+                previous.push((n - 1, 1));
+            }
+            if n % 2 == 0 {
+                previous.push((n / 2, 1));
+            }
+            previous
+        };
+
+        // 1 -> 2 -> 4 -> 5 -> 10 -> 20 -> 40 -> 80 -> 81
+        let (cost, path) = bidirectional(&forward, &backward, 1, 81).unwrap();
+        assert_eq!(cost, 8);
+        assert_eq!(path.len(), 9);
+        assert_eq!(path.first(), Some(&1));
+        assert_eq!(path.last(), Some(&81));
+        assert!(path.windows(2).all(|w| w[1] == w[0] + 1 || w[1] == w[0] * 2));
+
+        assert_eq!(bidirectional(&forward, &backward, 7, 7), Some((0, vec![7])));
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let graph = |&n: &i32| if n < 10 { vec![(n + 2, 1)] } else { vec![] };
+        assert_eq!(bidirectional(&graph, &|&n: &i32| vec![(n - 2, 1)], 0, 5), None);
+    }
+}
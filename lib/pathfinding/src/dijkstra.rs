@@ -0,0 +1,137 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::{Cost, Graph, Search, State};
+
+/// Dijkstra's algorithm from one or more starting states
+///
+/// The search stops once a state matching `goal` is reached with the lowest possible cost
+/// (use `|_| false` to explore every reachable state).
+pub fn dijkstra<S, G>(graph: &G, starts: impl IntoIterator<Item=S>, goal: impl FnMut(&S) -> bool) -> Search<S, G::Cost>
+    where S: State, G: Graph<S>
+{
+    astar(graph, starts, goal, |_| G::Cost::default())
+}
+
+/// A* search from one or more starting states
+///
+/// The `heuristic` estimates the remaining cost to reach a goal.
+/// It must never over-estimate, otherwise the path found may not be the shortest.
+pub fn astar<S, G>(graph: &G, starts: impl IntoIterator<Item=S>, mut goal: impl FnMut(&S) -> bool, heuristic: impl Fn(&S) -> G::Cost) -> Search<S, G::Cost>
+    where S: State, G: Graph<S>
+{
+    let mut edge = BinaryHeap::new();
+    let mut seq = 0;
+    let starts: Vec<S> = starts.into_iter().collect();
+    for start in &starts {
+        edge.push(Entry { priority: heuristic(start), cost: G::Cost::default(), seq, state: start.clone() });
+        seq += 1;
+    }
+
+    let mut search = Search::new(starts);
+    while let Some(Entry { cost, state, .. }) = edge.pop() {
+        if search.cost(&state) != Some(cost) {
+            // Already found a lower cost path to this state
+            continue;
+        }
+
+        if goal(&state) {
+            search.set_goal(state);
+            break;
+        }
+
+        for (neighbour, edge_cost) in graph.neighbours(&state) {
+            let new_cost = cost + edge_cost;
+            if search.relax(&state, &neighbour, new_cost) {
+                edge.push(Entry { priority: new_cost + heuristic(&neighbour), cost: new_cost, seq, state: neighbour });
+                seq += 1;
+            }
+        }
+    }
+
+    search
+}
+
+/// An entry in the priority queue
+///
+/// Ordered so the lowest priority is popped first, then in the order they were pushed.
+struct Entry<S, C> {
+    priority: C,
+    cost: C,
+    seq: usize,
+    state: S,
+}
+
+impl<S, C: Cost> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S, C: Cost> Eq for Entry<S, C> {}
+
+impl<S, C: Cost> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Cost> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (other.priority, other.seq).cmp(&(self.priority, self.seq))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A small weighted graph
+    ///
+    /// ```text
+    ///   a --1-- b --1-- c
+    ///   |               |
+    ///   5               1
+    ///   |               |
+    ///   d ------1------ e
+    /// ```
+    fn graph(&state: &char) -> Vec<(char, u32)> {
+        let edges = [('a', 'b', 1), ('b', 'c', 1), ('c', 'e', 1), ('a', 'd', 5), ('d', 'e', 1)];
+        edges.iter()
+            .filter_map(|&(x, y, cost)| match state {
+                s if s == x => Some((y, cost)),
+                s if s == y => Some((x, cost)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let search = dijkstra(&graph, vec!['a'], |_| false);
+        assert_eq!(search.cost(&'d'), Some(4));
+        assert_eq!(search.path_to(&'d'), Some(vec!['a', 'b', 'c', 'e', 'd']));
+
+        let search = dijkstra(&graph, vec!['a'], |&s| s == 'c');
+        assert_eq!(search.goal(), Some(&'c'));
+        assert_eq!(search.goal_cost(), Some(2));
+
+        // The search stopped before finding a cheaper path to d
+        assert_eq!(search.cost(&'d'), Some(5));
+    }
+
+    #[test]
+    fn test_astar() {
+        // Manhattan distance on a grid with a wall in the way
+        let neighbours = |&(x, y): &(i32, i32)| {
+            vec![(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)].into_iter()
+                .filter(|&(x, y)| (0..10).contains(&x) && (0..10).contains(&y) && !(x == 5 && y < 9))
+                .map(|pos| (pos, 1))
+        };
+        let heuristic = |&(x, y): &(i32, i32)| (9 - x).abs() + y.abs();
+
+        let search = astar(&neighbours, vec![(0, 0)], |&pos| pos == (9, 0), heuristic);
+        assert_eq!(search.goal_cost(), Some(27));
+        assert_eq!(search.path_to(&(9, 0)).unwrap().len(), 28);
+    }
+}
@@ -0,0 +1,53 @@
+//! Graph search.
+//!
+//! A graph is anything that implements [`Graph`], including closures of the form
+//! `|state: &S| -> impl IntoIterator<Item=(S, Cost)>`.
+//! Searches start from one or more states and return a [`Search`], which records the cost of
+//! every state that was reached and can reconstruct the shortest paths to them.
+
+use std::hash::Hash;
+use std::ops::Add;
+
+mod bfs;
+mod bidirectional;
+mod dijkstra;
+mod search;
+mod table;
+
+pub use bfs::bfs;
+pub use bidirectional::bidirectional;
+pub use dijkstra::{astar, dijkstra};
+pub use search::Search;
+pub use table::{all_pairs, DistanceTable};
+
+/// The cost of moving between states
+///
+/// Costs must not be negative. The default value is a cost of zero.
+pub trait Cost: Copy + Ord + Add<Output=Self> + Default {}
+
+impl<T> Cost for T where T: Copy + Ord + Add<Output=T> + Default {}
+
+/// A directed graph with weighted edges
+pub trait Graph<S> {
+    type Cost: Cost;
+    type Edges: IntoIterator<Item=(S, Self::Cost)>;
+
+    /// The states reachable from `state` and the cost of moving to each of them
+    fn neighbours(&self, state: &S) -> Self::Edges;
+}
+
+impl<S, F, I, C> Graph<S> for F
+    where F: Fn(&S) -> I, I: IntoIterator<Item=(S, C)>, C: Cost
+{
+    type Cost = C;
+    type Edges = I;
+
+    fn neighbours(&self, state: &S) -> I {
+        self(state)
+    }
+}
+
+/// Trait alias for types that can be used as search states
+pub trait State: Clone + Eq + Hash {}
+
+impl<T> State for T where T: Clone + Eq + Hash {}
@@ -0,0 +1,152 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{Cost, State};
+
+/// The result of a search
+///
+/// Records the lowest cost to every state that was reached along with all of the
+/// predecessors of each state that lie on a lowest-cost path to it.
+#[derive(Clone, Debug)]
+pub struct Search<S: State, C> {
+    nodes: HashMap<S, Node<S, C>>,
+    goal: Option<S>,
+}
+
+#[derive(Clone, Debug)]
+struct Node<S, C> {
+    cost: C,
+    predecessors: Vec<S>,
+}
+
+impl<S: State, C: Cost> Search<S, C> {
+    pub(crate) fn new(starts: impl IntoIterator<Item=S>) -> Self {
+        let nodes = starts.into_iter()
+            .map(|s| (s, Node { cost: C::default(), predecessors: Vec::new() }))
+            .collect();
+
+        Search { nodes, goal: None }
+    }
+
+    /// Record an edge from `from` to `to`, returning `true` if it lowered the cost of `to`
+    pub(crate) fn relax(&mut self, from: &S, to: &S, cost: C) -> bool {
+        let node = match self.nodes.get_mut(to) {
+            Some(node) => node,
+            None => {
+                self.nodes.insert(to.clone(), Node { cost, predecessors: vec![from.clone()] });
+                return true;
+            },
+        };
+
+        if cost < node.cost {
+            node.cost = cost;
+            node.predecessors.clear();
+            node.predecessors.push(from.clone());
+            true
+        } else {
+            // Another path with the same cost
+            if cost == node.cost && !node.predecessors.contains(from) {
+                node.predecessors.push(from.clone());
+            }
+            false
+        }
+    }
+
+    pub(crate) fn set_goal(&mut self, goal: S) {
+        self.goal = Some(goal);
+    }
+
+    /// The lowest cost of reaching a state, if it was reached
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.nodes.get(state).map(|node| node.cost)
+    }
+
+    /// The lowest cost of every state that was reached
+    pub fn costs(&self) -> impl Iterator<Item=(&S, C)> {
+        self.nodes.iter().map(|(state, node)| (state, node.cost))
+    }
+
+    /// Number of states that were reached
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Were no states reached
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Was this state reached
+    pub fn is_reached(&self, state: &S) -> bool {
+        self.nodes.contains_key(state)
+    }
+
+    /// The goal that ended the search, if one was found
+    pub fn goal(&self) -> Option<&S> {
+        self.goal.as_ref()
+    }
+
+    /// The cost of reaching the goal, if one was found
+    pub fn goal_cost(&self) -> Option<C> {
+        self.goal.as_ref().and_then(|goal| self.cost(goal))
+    }
+
+    /// The states that come immediately before `state` on a lowest-cost path
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.nodes.get(state).map(|node| node.predecessors.as_slice()).unwrap_or(&[])
+    }
+
+    /// A lowest-cost path from a start to `state` (inclusive)
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.is_reached(state) {
+            return None;
+        }
+
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// The lowest-cost path from a start to `state` (inclusive) that comes first when
+    /// comparing the `key` of each state along the path in order
+    ///
+    /// For example, a key of `(y, x)` picks the path whose first step is first in reading order.
+    pub fn path_to_by_key<K: Ord>(&self, state: &S, key: impl Fn(&S) -> K) -> Option<Vec<S>> {
+        if !self.is_reached(state) {
+            return None;
+        }
+
+        // Find every state that lies on a lowest-cost path to the target
+        let mut on_path = HashSet::new();
+        let mut successors: HashMap<&S, Vec<&S>> = HashMap::new();
+        let mut starts = Vec::new();
+        let mut stack = vec![state];
+        while let Some(current) = stack.pop() {
+            if !on_path.insert(current) {
+                continue;
+            }
+
+            let predecessors = self.predecessors(current);
+            if predecessors.is_empty() {
+                starts.push(current);
+            }
+            for previous in predecessors {
+                successors.entry(previous).or_default().push(current);
+                stack.push(previous);
+            }
+        }
+
+        // Then walk forwards, always taking the lowest key
+        let mut current = starts.into_iter().min_by_key(|&s| key(s))?;
+        let mut path = vec![current.clone()];
+        while current != state {
+            current = successors[current].iter().copied().min_by_key(|&s| key(s))?;
+            path.push(current.clone());
+        }
+
+        Some(path)
+    }
+}
@@ -0,0 +1,67 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{dijkstra, Graph, State};
+
+/// The lowest cost between every pair of a set of states
+#[derive(Clone, Debug)]
+pub struct DistanceTable<S, C> {
+    costs: HashMap<S, HashMap<S, C>>,
+}
+
+impl<S: State, C: Copy> DistanceTable<S, C> {
+    /// The lowest cost from one state to another, if it is reachable
+    pub fn get(&self, from: &S, to: &S) -> Option<C> {
+        self.costs.get(from).and_then(|costs| costs.get(to)).copied()
+    }
+
+    /// The states reachable from a state and their costs
+    pub fn from(&self, from: &S) -> impl Iterator<Item=(&S, C)> {
+        self.costs.get(from).into_iter().flat_map(|costs| costs.iter().map(|(s, &c)| (s, c)))
+    }
+
+    /// The states in this table
+    pub fn states(&self) -> impl Iterator<Item=&S> {
+        self.costs.keys()
+    }
+}
+
+/// Build a table of the lowest cost between every pair of `states`
+///
+/// Only the given states are included, though paths may pass through any state in the graph.
+pub fn all_pairs<S, G>(graph: &G, states: impl IntoIterator<Item=S>) -> DistanceTable<S, G::Cost>
+    where S: State, G: Graph<S>
+{
+    let states: HashSet<S> = states.into_iter().collect();
+    let costs = states.iter()
+        .map(|from| {
+            let search = dijkstra(graph, vec![from.clone()], |_| false);
+            let costs = search.costs()
+                .filter(|(to, _)| states.contains(to))
+                .map(|(to, cost)| (to.clone(), cost))
+                .collect();
+
+            (from.clone(), costs)
+        })
+        .collect();
+
+    DistanceTable { costs }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_pairs() {
+        // A one-way ring of 10 states
+        let ring = |&n: &u32| vec![((n + 1) % 10, 2)];
+        let table = all_pairs(&ring, vec![0, 3, 7]);
+
+        assert_eq!(table.get(&0, &3), Some(6));
+        assert_eq!(table.get(&3, &0), Some(14));
+        assert_eq!(table.get(&7, &7), Some(0));
+        assert_eq!(table.get(&0, &5), None);
+        assert_eq!(table.from(&3).count(), 3);
+        assert_eq!(table.states().count(), 3);
+    }
+}
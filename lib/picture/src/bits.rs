@@ -0,0 +1,54 @@
+/// Packs codes into bytes, least-significant bit first (as both DEFLATE and GIF do)
+#[derive(Clone, Debug, Default)]
+pub(crate) struct BitWriter {
+    bytes: Vec<u8>,
+    acc: u32,
+    n: u32,
+}
+
+impl BitWriter {
+    pub(crate) fn new() -> Self {
+        BitWriter::default()
+    }
+
+    /// Write the low `n` bits of `value`
+    pub(crate) fn write(&mut self, value: u32, n: u32) {
+        debug_assert!(n <= 16);
+        self.acc |= (value & ((1 << n) - 1)) << self.n;
+        self.n += n;
+        while self.n >= 8 {
+            self.bytes.push(self.acc as u8);
+            self.acc >>= 8;
+            self.n -= 8;
+        }
+    }
+
+    /// Write an `n`-bit Huffman code, which is packed most-significant bit first
+    pub(crate) fn write_code(&mut self, code: u32, n: u32) {
+        self.write(code.reverse_bits() >> (32 - n), n);
+    }
+
+    /// The bytes written, with the last one padded with zeros
+    pub(crate) fn finish(mut self) -> Vec<u8> {
+        if self.n > 0 {
+            self.bytes.push(self.acc as u8);
+        }
+
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write() {
+        let mut bits = BitWriter::new();
+        bits.write(0b1, 1);
+        bits.write(0b01, 2);
+        bits.write_code(0b0011, 4);
+        bits.write(0x1ff, 10);
+        assert_eq!(bits.finish(), vec![0b1110_0011, 0b1111_1111, 0b0]);
+    }
+}
@@ -0,0 +1,267 @@
+//! Animated [GIF](https://www.w3.org/Graphics/GIF/spec-gif89a.txt) encoding.
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use crate::bits::BitWriter;
+use crate::image::Image;
+
+/// Largest LZW code (codes are at most 12 bits)
+const MAX_CODE: u16 = 4095;
+
+/// Writes frames of an animated GIF, which loops forever
+///
+/// Every frame must be the same size and use the same colours as the first.
+/// Only the part of each frame that changed since the last one is stored.
+pub struct Animation<W: Write> {
+    out: W,
+    last: Option<Image>,
+}
+
+impl Animation<BufWriter<File>> {
+    /// Write the animation to a file
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Animation::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<W: Write> Animation<W> {
+    pub fn new(out: W) -> Self {
+        Animation { out, last: None }
+    }
+
+    /// Add a frame that's shown for `delay` (to the nearest 1/100th of a second)
+    pub fn frame(&mut self, image: &Image, delay: Duration) -> io::Result<()> {
+        let (left, top, right, bottom) = match &self.last {
+            None => {
+                self.write_header(image)?;
+                (0, 0, image.width(), image.height())
+            },
+            Some(last) => {
+                assert_eq!((image.width(), image.height()), (last.width(), last.height()), "Frame size changed");
+                assert_eq!(image.colours(), last.colours(), "Frame colours changed");
+                changed(last, image).unwrap_or((0, 0, 1, 1))
+            },
+        };
+
+        // Graphic control extension, giving the delay in 1/100ths of a second
+        let delay = ((delay.as_millis() + 5) / 10).min(u16::MAX as u128) as u16;
+        self.out.write_all(&[0x21, 0xf9, 4, 0])?;
+        self.out.write_all(&delay.to_le_bytes())?;
+        self.out.write_all(&[0, 0])?;
+
+        // Image descriptor, without a local colour table
+        self.out.write_all(&[0x2c])?;
+        for n in [left, top, right - left, bottom - top] {
+            self.out.write_all(&(n as u16).to_le_bytes())?;
+        }
+        self.out.write_all(&[0])?;
+
+        let pixels = (top..bottom).flat_map(|y| (left..right).map(move |x| image.pixel(x, y)));
+        let min_code_size = table_bits(image.colours().len()).max(2);
+        self.out.write_all(&[min_code_size as u8])?;
+        write_blocks(&mut self.out, &lzw(pixels, min_code_size))?;
+
+        self.last = Some(image.clone());
+
+        Ok(())
+    }
+
+    /// Write the end of the animation
+    pub fn finish(mut self) -> io::Result<()> {
+        self.out.write_all(&[0x3b])?;
+        self.out.flush()
+    }
+
+    fn write_header(&mut self, image: &Image) -> io::Result<()> {
+        self.out.write_all(b"GIF89a")?;
+        self.out.write_all(&(image.width() as u16).to_le_bytes())?;
+        self.out.write_all(&(image.height() as u16).to_le_bytes())?;
+
+        // A global colour table of 2^bits colours, with 8 bits per primary colour
+        let bits = table_bits(image.colours().len());
+        self.out.write_all(&[0xf0 | (bits - 1) as u8, 0, 0])?;
+        for n in 0..1 << bits {
+            let rgb = image.colours().get(n).copied().unwrap_or(0);
+            self.out.write_all(&rgb.to_be_bytes()[1..])?;
+        }
+
+        // Loop forever
+        self.out.write_all(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00")
+    }
+}
+
+/// Bits needed for a colour table of `n` colours
+fn table_bits(n: usize) -> u32 {
+    (usize::BITS - n.saturating_sub(1).leading_zeros()).max(1)
+}
+
+/// Bounds (left, top, right, bottom) of the pixels that differ between two images
+fn changed(a: &Image, b: &Image) -> Option<(usize, usize, usize, usize)> {
+    let mut bounds: Option<(usize, usize, usize, usize)> = None;
+    for y in 0..a.height() {
+        for x in 0..a.width() {
+            if a.pixel(x, y) != b.pixel(x, y) {
+                bounds = Some(match bounds {
+                    None => (x, y, x + 1, y + 1),
+                    Some((left, top, right, bottom)) => (left.min(x), top.min(y), right.max(x + 1), bottom.max(y + 1)),
+                });
+            }
+        }
+    }
+
+    bounds
+}
+
+/// Write data as sub-blocks of up to 255 bytes, followed by an empty block
+fn write_blocks(out: &mut impl Write, data: &[u8]) -> io::Result<()> {
+    for block in data.chunks(255) {
+        out.write_all(&[block.len() as u8])?;
+        out.write_all(block)?;
+    }
+
+    out.write_all(&[0])
+}
+
+/// LZW-compress pixels, as GIF does
+fn lzw(pixels: impl IntoIterator<Item=u8>, min_code_size: u32) -> Vec<u8> {
+    let clear: u16 = 1 << min_code_size;
+    let end = clear + 1;
+
+    let mut bits = BitWriter::new();
+    // The code for each code followed by a pixel, where 0 means there isn't one yet
+    let mut codes = vec![0u16; (MAX_CODE as usize + 1) << min_code_size];
+    let index = |code: u16, pixel: u8| (code as usize) << min_code_size | pixel as usize;
+    let mut next = end + 1;
+    let mut width = min_code_size + 1;
+    bits.write(clear as u32, width);
+
+    let mut prefix: Option<u16> = None;
+    for pixel in pixels {
+        let code = match prefix {
+            None => {
+                prefix = Some(pixel as u16);
+                continue;
+            },
+            Some(code) => code,
+        };
+
+        let longer = codes[index(code, pixel)];
+        if longer != 0 {
+            prefix = Some(longer);
+            continue;
+        }
+
+        bits.write(code as u32, width);
+        if next <= MAX_CODE {
+            codes[index(code, pixel)] = next;
+            next += 1;
+            // The decoder adds each code a step later, so only widen once it would need to
+            if next > 1 << width && width < 12 {
+                width += 1;
+            }
+        } else {
+            bits.write(clear as u32, width);
+            codes.fill(0);
+            next = end + 1;
+            width = min_code_size + 1;
+        }
+        prefix = Some(pixel as u16);
+    }
+
+    if let Some(code) = prefix {
+        bits.write(code as u32, width);
+    }
+    bits.write(end as u32, width);
+
+    bits.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Palette;
+    use grid::Grid;
+
+    /// Decompress GIF LZW data, to check it round-trips
+    fn unlzw(data: &[u8], min_code_size: u32) -> Vec<u8> {
+        let clear = 1 << min_code_size;
+        let end = clear + 1;
+        let mut table: Vec<Vec<u8>> = Vec::new();
+        let mut width = min_code_size + 1;
+        let mut last: Option<Vec<u8>> = None;
+        let mut pixels = Vec::new();
+
+        let mut bit = 0;
+        while bit + width as usize <= data.len() * 8 {
+            let code = (0..width as usize).fold(0, |code, n| {
+                let b = bit + n;
+                code | (((data[b / 8] >> (b % 8)) & 1) as usize) << n
+            });
+            bit += width as usize;
+
+            if code == clear {
+                table = (0..clear).map(|c| vec![c as u8]).chain([vec![], vec![]]).collect();
+                width = min_code_size + 1;
+                last = None;
+                continue;
+            } else if code == end {
+                break;
+            }
+
+            let entry = match (table.get(code), &last) {
+                (Some(entry), _) => entry.clone(),
+                (_, Some(last)) => [last.clone(), vec![last[0]]].concat(),
+                _ => panic!("Bad code {}", code),
+            };
+            if let Some(last) = last {
+                table.push([last, vec![entry[0]]].concat());
+                if table.len() == 1 << width && width < 12 {
+                    width += 1;
+                }
+            }
+            pixels.extend(&entry);
+            last = Some(entry);
+        }
+
+        pixels
+    }
+
+    #[test]
+    fn test_lzw() {
+        let pixels: Vec<u8> = (0..10000).map(|n| ((n / 7) % 3 + (n % 13) / 12) as u8).collect();
+        assert_eq!(unlzw(&lzw(pixels.iter().copied(), 2), 2), pixels);
+
+        // Long enough to fill the table of codes
+        let pixels: Vec<u8> = (0..50000u32).map(|n| (n.wrapping_mul(2654435761) >> 29) as u8).collect();
+        assert_eq!(unlzw(&lzw(pixels.iter().copied(), 3), 3), pixels);
+    }
+
+    #[test]
+    fn test_table_bits() {
+        assert_eq!(table_bits(1), 1);
+        assert_eq!(table_bits(2), 1);
+        assert_eq!(table_bits(3), 2);
+        assert_eq!(table_bits(16), 4);
+        assert_eq!(table_bits(17), 5);
+    }
+
+    #[test]
+    fn test_animation() {
+        let palette = Palette::new(0x000000).with('#', 0xffffff);
+        let mut gif = Animation::new(Vec::new());
+        let mut grid = Grid::from_rows(vec![vec!['.'; 4]; 3]);
+        gif.frame(&Image::from_grid(&grid, &palette), Duration::from_millis(100)).unwrap();
+        grid[(2, 1)] = '#';
+        gif.frame(&Image::from_grid(&grid, &palette), Duration::from_millis(100)).unwrap();
+
+        let data = gif.out.clone();
+        gif.finish().unwrap();
+        assert!(data.starts_with(b"GIF89a\x04\x00\x03\x00\xf0\x00\x00\x00\x00\x00\xff\xff\xff"));
+        // The second frame is just the pixel that changed
+        let second = data.windows(2).rposition(|w| w == [0x21, 0xf9]).unwrap();
+        assert_eq!(&data[second..second + 18], b"\x21\xf9\x04\x00\x0a\x00\x00\x00\x2c\x02\x00\x01\x00\x01\x00\x01\x00\x00");
+    }
+}
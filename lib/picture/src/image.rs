@@ -0,0 +1,96 @@
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use grid::Grid;
+
+use crate::palette::{Palette, Rgb};
+use crate::png;
+
+/// A picture made of colours from a palette
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    /// Index into `colours` of each pixel, row by row
+    pixels: Vec<u8>,
+    colours: Vec<Rgb>,
+}
+
+impl Image {
+    /// A pixel for each cell of `grid`, coloured by `palette`
+    pub fn from_grid<T: Eq + Hash>(grid: &Grid<T>, palette: &Palette<T>) -> Self {
+        let pixels = grid.rows().flatten().map(|cell| palette.index(cell)).collect();
+
+        Image { width: grid.width(), height: grid.height(), pixels, colours: palette.colours().to_vec() }
+    }
+
+    /// The same image with each pixel blown up into a `factor` × `factor` square
+    pub fn scale(&self, factor: usize) -> Self {
+        let width = self.width * factor;
+        let mut pixels = Vec::with_capacity(width * self.height * factor);
+        for row in self.rows() {
+            let scaled: Vec<u8> = row.iter().flat_map(|&p| vec![p; factor]).collect();
+            for _ in 0..factor {
+                pixels.extend_from_slice(&scaled);
+            }
+        }
+
+        Image { width, height: self.height * factor, pixels, colours: self.colours.clone() }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Colour index of the pixel at (`x`, `y`)
+    pub fn pixel(&self, x: usize, y: usize) -> u8 {
+        self.pixels[y * self.width + x]
+    }
+
+    /// Every colour the pixels may use, in order of index
+    pub fn colours(&self) -> &[Rgb] {
+        &self.colours
+    }
+
+    /// Colour indices of each row
+    pub fn rows(&self) -> impl Iterator<Item=&[u8]> {
+        self.pixels.chunks(self.width.max(1))
+    }
+
+    /// Save as a PNG file
+    pub fn save_png(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut out = BufWriter::new(File::create(path)?);
+        self.write_png(&mut out)?;
+        out.flush()
+    }
+
+    /// Write out in PNG format
+    pub fn write_png(&self, out: &mut impl Write) -> io::Result<()> {
+        png::write(self, out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scale() {
+        let grid = Grid::from_rows(vec![vec!['#', '.'], vec!['.', '#']]);
+        let image = Image::from_grid(&grid, &Palette::new(0x000000).with('#', 0xffffff)).scale(2);
+
+        assert_eq!((image.width(), image.height()), (4, 4));
+        assert_eq!(image.rows().collect::<Vec<_>>(), vec![
+            [1, 1, 0, 0],
+            [1, 1, 0, 0],
+            [0, 0, 1, 1],
+            [0, 0, 1, 1],
+        ]);
+    }
+}
@@ -0,0 +1,49 @@
+//! Saving puzzle visualisations as images.
+//!
+//! A [`Grid`](grid::Grid) of cells is turned into an [`Image`] by looking up the colour of each
+//! cell in a [`Palette`]. Images can be saved as PNGs, or as the frames of an animated GIF with
+//! [`Animation`].
+//!
+//! The encoders are written from scratch, so there's nothing to download or link against.
+//! They only go as far as needed for small, blocky images: PNGs are compressed with fixed Huffman
+//! codes and only look for repeats of the previous pixel or row.
+
+mod bits;
+mod gif;
+mod image;
+mod palette;
+mod png;
+
+pub use crate::gif::Animation;
+pub use crate::image::Image;
+pub use crate::palette::{Palette, Rgb};
+
+use std::path::PathBuf;
+use std::process;
+
+/// Take `option FILE` (e.g. `--png out.png`) out of command-line arguments
+///
+/// Exits with a usage message if `FILE` is missing.
+pub fn output_arg(args: &mut Vec<String>, option: &str) -> Option<PathBuf> {
+    let n = args.iter().position(|arg| arg == option)?;
+    args.remove(n);
+    if n == args.len() {
+        eprintln!("{} requires a FILE", option);
+        process::exit(2);
+    }
+
+    Some(PathBuf::from(args.remove(n)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_arg() {
+        let mut args = vec![String::from("--gif"), String::from("out.gif"), String::from("--png"), String::from("out.png")];
+        assert_eq!(output_arg(&mut args, "--png"), Some(PathBuf::from("out.png")));
+        assert_eq!(output_arg(&mut args, "--png"), None);
+        assert_eq!(args, vec![String::from("--gif"), String::from("out.gif")]);
+    }
+}
@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A colour written as `0xRRGGBB`
+pub type Rgb = u32;
+
+/// Most colours an image can have (GIFs can't have any more)
+const MAX_COLOURS: usize = 256;
+
+/// Colours for the cells of a grid
+///
+/// Cells that aren't in the palette are the background colour.
+#[derive(Clone, Debug)]
+pub struct Palette<T> {
+    colours: Vec<Rgb>,
+    indices: HashMap<T, u8>,
+}
+
+impl<T: Eq + Hash> Palette<T> {
+    pub fn new(background: Rgb) -> Self {
+        Palette { colours: vec![background], indices: HashMap::new() }
+    }
+
+    /// Draw `cell` in `colour`
+    ///
+    /// Panics if there are already 256 colours.
+    pub fn with(mut self, cell: T, colour: Rgb) -> Self {
+        let index = match self.colours.iter().position(|&c| c == colour) {
+            Some(index) => index,
+            None => {
+                assert!(self.colours.len() < MAX_COLOURS, "Too many colours");
+                self.colours.push(colour);
+                self.colours.len() - 1
+            },
+        };
+        self.indices.insert(cell, index as u8);
+        self
+    }
+
+    /// Every colour, in order of index
+    pub(crate) fn colours(&self) -> &[Rgb] {
+        &self.colours
+    }
+
+    /// Index of the colour for `cell`
+    pub(crate) fn index(&self, cell: &T) -> u8 {
+        self.indices.get(cell).copied().unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index() {
+        let palette = Palette::new(0x000000).with('#', 0xffffff).with('|', 0x0000ff).with('~', 0x0000ff);
+        assert_eq!(palette.colours(), &[0x000000, 0xffffff, 0x0000ff]);
+        assert_eq!(palette.index(&'#'), 1);
+        assert_eq!(palette.index(&'~'), 2);
+        assert_eq!(palette.index(&'.'), 0);
+    }
+}
@@ -0,0 +1,176 @@
+//! [PNG](https://www.w3.org/TR/png/) encoding of indexed-colour images.
+
+use std::io::{self, Write};
+
+use crate::bits::BitWriter;
+use crate::image::Image;
+
+const SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+/// Colour type for pixels that are indices into a palette
+const INDEXED: u8 = 3;
+
+/// Furthest back DEFLATE can refer to
+const WINDOW: usize = 32768;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+
+/// Base lengths of DEFLATE length codes 257 to 285, with their extra bits
+const LENGTHS: [(usize, u32); 29] = [
+    (3, 0), (4, 0), (5, 0), (6, 0), (7, 0), (8, 0), (9, 0), (10, 0),
+    (11, 1), (13, 1), (15, 1), (17, 1), (19, 2), (23, 2), (27, 2), (31, 2),
+    (35, 3), (43, 3), (51, 3), (59, 3), (67, 4), (83, 4), (99, 4), (115, 4),
+    (131, 5), (163, 5), (195, 5), (227, 5), (258, 0),
+];
+
+/// Base distances of DEFLATE distance codes 0 to 29, with their extra bits
+const DISTANCES: [(usize, u32); 30] = [
+    (1, 0), (2, 0), (3, 0), (4, 0), (5, 1), (7, 1), (9, 2), (13, 2),
+    (17, 3), (25, 3), (33, 4), (49, 4), (65, 5), (97, 5), (129, 6), (193, 6),
+    (257, 7), (385, 7), (513, 8), (769, 8), (1025, 9), (1537, 9), (2049, 10), (3073, 10),
+    (4097, 11), (6145, 11), (8193, 12), (12289, 12), (16385, 13), (24577, 13),
+];
+
+/// Write `image` as a PNG
+pub(crate) fn write(image: &Image, out: &mut impl Write) -> io::Result<()> {
+    out.write_all(SIGNATURE)?;
+
+    let mut header = Vec::new();
+    header.extend_from_slice(&(image.width() as u32).to_be_bytes());
+    header.extend_from_slice(&(image.height() as u32).to_be_bytes());
+    // 8 bits per pixel, then the default compression, filtering and (no) interlacing
+    header.extend_from_slice(&[8, INDEXED, 0, 0, 0]);
+    write_chunk(out, b"IHDR", &header)?;
+
+    let palette: Vec<u8> = image.colours().iter().flat_map(|&rgb| rgb.to_be_bytes()[1..].to_vec()).collect();
+    write_chunk(out, b"PLTE", &palette)?;
+
+    // Each row starts with its filter type, which is always none
+    let mut data = Vec::with_capacity((image.width() + 1) * image.height());
+    for row in image.rows() {
+        data.push(0);
+        data.extend_from_slice(row);
+    }
+    write_chunk(out, b"IDAT", &zlib(&data, image.width() + 1))?;
+
+    write_chunk(out, b"IEND", &[])
+}
+
+fn write_chunk(out: &mut impl Write, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    out.write_all(&(data.len() as u32).to_be_bytes())?;
+    out.write_all(kind)?;
+    out.write_all(data)?;
+
+    let crc = crc32(kind.iter().chain(data));
+    out.write_all(&crc.to_be_bytes())
+}
+
+/// Compress `data` in zlib format, looking for repeats of the previous byte or `stride` bytes back
+fn zlib(data: &[u8], stride: usize) -> Vec<u8> {
+    // Deflate with a 32K window and no preset dictionary
+    let mut out = vec![0x78, 0x01];
+    out.extend(deflate(data, stride));
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+
+    out
+}
+
+/// A single DEFLATE block using the fixed Huffman codes
+fn deflate(data: &[u8], stride: usize) -> Vec<u8> {
+    let mut bits = BitWriter::new();
+    // Final block, fixed codes
+    bits.write(1, 1);
+    bits.write(1, 2);
+
+    let mut i = 0;
+    while i < data.len() {
+        let (length, distance) = [1, stride].iter()
+            .filter(|&&distance| distance <= i && distance <= WINDOW)
+            .map(|&distance| (match_length(data, i, distance), distance))
+            .max()
+            .unwrap_or((0, 0));
+
+        if length >= MIN_MATCH {
+            write_length(&mut bits, length);
+            write_distance(&mut bits, distance);
+            i += length;
+        } else {
+            write_literal(&mut bits, data[i] as u32);
+            i += 1;
+        }
+    }
+    write_literal(&mut bits, 256);  // End of block
+
+    bits.finish()
+}
+
+/// How many bytes from `i` repeat those `distance` bytes before
+fn match_length(data: &[u8], i: usize, distance: usize) -> usize {
+    data[i..].iter().zip(&data[i - distance..])
+        .take(MAX_MATCH)
+        .take_while(|(a, b)| a == b)
+        .count()
+}
+
+/// Write a literal/length symbol using the fixed Huffman code
+fn write_literal(bits: &mut BitWriter, symbol: u32) {
+    match symbol {
+        0..=143 => bits.write_code(0x30 + symbol, 8),
+        144..=255 => bits.write_code(0x190 + symbol - 144, 9),
+        256..=279 => bits.write_code(symbol - 256, 7),
+        _ => bits.write_code(0xc0 + symbol - 280, 8),
+    }
+}
+
+fn write_length(bits: &mut BitWriter, length: usize) {
+    let code = LENGTHS.iter().rposition(|&(base, _)| base <= length).unwrap();
+    let (base, extra) = LENGTHS[code];
+    write_literal(bits, 257 + code as u32);
+    bits.write((length - base) as u32, extra);
+}
+
+fn write_distance(bits: &mut BitWriter, distance: usize) {
+    let code = DISTANCES.iter().rposition(|&(base, _)| base <= distance).unwrap();
+    let (base, extra) = DISTANCES[code];
+    bits.write_code(code as u32, 5);
+    bits.write((distance - base) as u32, extra);
+}
+
+fn crc32<'a>(bytes: impl IntoIterator<Item=&'a u8>) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xedb8_8320 } else { crc >> 1 };
+        }
+    }
+
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (a, b) = bytes.iter().fold((1u32, 0u32), |(a, b), &byte| {
+        let a = (a + byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn test_deflate() {
+        // A literal, then a run of the previous byte, then a repeat of the previous "row"
+        assert_eq!(deflate(b"aaaaab", 3), vec![0x4b, 0x04, 0x81, 0x24, 0x00]);
+        assert_eq!(deflate(b"abcabcabc", 3), vec![0x4b, 0x4c, 0x4a, 0x86, 0x20, 0x00]);
+    }
+}
@@ -0,0 +1,218 @@
+//! Expected answers for checking solutions against.
+//!
+//! Answers are kept in a text file with one answer per line, in the form `2020/day18 1 ANSWER`.
+//! A line of the form `2019/day13 args --turbo` gives the arguments to run a day with, and one of
+//! the form `2018/day25 skip 2 REASON` explains why a part has no answer to check.
+//! Blank lines and lines starting with `#` are ignored.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::Day;
+
+/// The expected answer of each part of each day
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    answers: BTreeMap<(String, u8), String>,
+    args: BTreeMap<String, Vec<String>>,
+    skipped: BTreeMap<(String, u8), String>,
+}
+
+impl Answers {
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let answers = fs::read_to_string(path)?;
+
+        answers.parse().map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    pub fn get(&self, day: &Day, part: u8) -> Option<&str> {
+        self.answers.get(&(day.to_string(), part)).map(String::as_str)
+    }
+
+    /// Why a part has no answer to check, if it deliberately doesn't
+    pub fn skipped(&self, day: &Day, part: u8) -> Option<&str> {
+        self.skipped.get(&(day.to_string(), part)).map(String::as_str)
+    }
+
+    /// Extra command-line arguments needed to run a day
+    pub fn args(&self, day: &Day) -> &[String] {
+        self.args.get(&day.to_string()).map_or(&[], Vec::as_slice)
+    }
+}
+
+impl FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        let mut answers = BTreeMap::new();
+        let mut args = BTreeMap::new();
+        let mut skipped = BTreeMap::new();
+        for (n, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.splitn(3, ' ');
+            let (day, part, answer) = match (fields.next(), fields.next(), fields.next()) {
+                (Some(day), Some(part), Some(answer)) => (day, part, answer.trim()),
+                _ => return Err(format!("line {}: expected `DAY PART ANSWER`", n + 1)),
+            };
+            if part == "args" {
+                args.insert(day.to_owned(), answer.split_whitespace().map(str::to_owned).collect());
+                continue;
+            }
+            if part == "skip" {
+                let (part, reason) = answer.split_once(' ')
+                    .ok_or_else(|| format!("line {}: expected `DAY skip PART REASON`", n + 1))?;
+                let part = part.parse().map_err(|_| format!("line {}: invalid part {:?}", n + 1, part))?;
+                skipped.insert((day.to_owned(), part), reason.trim().to_owned());
+                continue;
+            }
+
+            let part = part.parse().map_err(|_| format!("line {}: invalid part {:?}", n + 1, part))?;
+            answers.insert((day.to_owned(), part), answer.to_owned());
+        }
+
+        Ok(Answers { answers, args, skipped })
+    }
+}
+
+/// The result of checking one part of a day
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum Status {
+    Pass,
+    /// The expected answer and the line that was printed instead (if any)
+    Fail(String, Option<String>),
+    /// No expected answer is known
+    Missing,
+    /// The part deliberately has no answer (e.g. day 25 has no part 2)
+    Skipped,
+}
+
+impl Status {
+    /// Check the output of a day against its expected answer
+    pub fn check(expected: Option<&str>, output: &str, part: u8) -> Self {
+        let expected = match expected {
+            Some(expected) => expected,
+            None => return Status::Missing,
+        };
+
+        match part_line(output, part) {
+            Some(line) if contains_word(without_timing(line), expected) => Status::Pass,
+            line => Status::Fail(expected.to_owned(), line.map(str::to_owned)),
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Status::Pass => "pass",
+            Status::Fail(..) => "FAIL",
+            Status::Missing => "missing",
+            Status::Skipped => "skipped",
+        };
+
+        f.pad(s)
+    }
+}
+
+/// The text after `Part N:` on the line that prints a part's answer
+///
+/// The label may be preceded by terminal escape codes (e.g. from clearing the screen).
+fn part_line(output: &str, part: u8) -> Option<&str> {
+    let label = format!("Part {}:", part);
+
+    output.lines()
+        .find_map(|line| line.find(&label).map(|i| line[i + label.len()..].trim()))
+}
+
+/// An answer without the timing (e.g. ` (1.20ms)`) that [`crate::main`] prints after it
+fn without_timing(line: &str) -> &str {
+    let timing = line.strip_suffix(')')
+        .and_then(|rest| rest.rsplit_once(" ("))
+        .filter(|(_, time)| is_duration(time));
+
+    match timing {
+        Some((answer, _)) => answer.trim_end(),
+        None => line,
+    }
+}
+
+/// Is `s` a duration as formatted by `{:.2?}` (e.g. `1.20ms`)
+fn is_duration(s: &str) -> bool {
+    let number = s.trim_end_matches(char::is_alphabetic);
+
+    ["ns", "µs", "ms", "s"].contains(&&s[number.len()..]) && number.parse::<f64>().is_ok()
+}
+
+/// Does `word` appear in `s` without being part of a longer word or number
+fn contains_word(s: &str, word: &str) -> bool {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+
+    s.match_indices(word).any(|(i, _)| {
+        let before = s[..i].chars().next_back();
+        let after = s[i + word.len()..].chars().next();
+
+        !before.is_some_and(is_word) && !after.is_some_and(is_word)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn day(year: u16, n: u8) -> Day {
+        let name = format!("day{:02}", n);
+        Day { year, day: n, path: PathBuf::from(&name), name }
+    }
+
+    #[test]
+    fn test_from_str() {
+        let answers: Answers = "# Comment\n\n2020/day18 1 42\n2020/day21 2 a,b c\n2020/day21 args --fast -v\n"
+            .parse().unwrap();
+        assert_eq!(answers.get(&day(2020, 18), 1), Some("42"));
+        assert_eq!(answers.get(&day(2020, 18), 2), None);
+        assert_eq!(answers.get(&day(2020, 21), 2), Some("a,b c"));
+        assert_eq!(answers.args(&day(2020, 21)), ["--fast", "-v"]);
+        assert!(answers.args(&day(2020, 18)).is_empty());
+
+        let answers: Answers = "2018/day25 1 367\n2018/day25 skip 2 No part 2\n".parse().unwrap();
+        assert_eq!(answers.get(&day(2018, 25), 2), None);
+        assert_eq!(answers.skipped(&day(2018, 25), 2), Some("No part 2"));
+        assert_eq!(answers.skipped(&day(2018, 25), 1), None);
+        assert!("2018/day25 skip 2".parse::<Answers>().is_err());
+
+        assert!("2020/day18 1".parse::<Answers>().is_err());
+        assert!("2020/day18 one 42".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let output = "Part 1: Max thrust is 1234 ([1, 0, 2])\nPart 2: 567 (1.20ms)\n";
+        assert_eq!(Status::check(Some("1234"), output, 1), Status::Pass);
+        assert_eq!(Status::check(Some("567"), output, 2), Status::Pass);
+        assert_eq!(Status::check(Some("123"), output, 1),
+                   Status::Fail("123".to_owned(), Some("Max thrust is 1234 ([1, 0, 2])".to_owned())));
+        assert_eq!(Status::check(Some("1"), "Part 2: 1\n", 1), Status::Fail("1".to_owned(), None));
+        assert_eq!(Status::check(None, output, 1), Status::Missing);
+        assert_eq!(Status::check(Some("42"), "\x1bcPart 1: 42\n", 1), Status::Pass);
+    }
+
+    #[test]
+    fn test_check_timing() {
+        let output = "Part 1: 15 (5.00ms)\nPart 2: 5 (12.34µs)\n";
+        assert_eq!(Status::check(Some("5"), output, 1),
+                   Status::Fail("5".to_owned(), Some("15 (5.00ms)".to_owned())));
+        assert_eq!(Status::check(Some("15"), output, 1), Status::Pass);
+        assert_eq!(Status::check(Some("5"), output, 2), Status::Pass);
+        assert_eq!(Status::check(Some("12"), output, 2), Status::Fail("12".to_owned(), Some("5 (12.34µs)".to_owned())));
+        assert_eq!(without_timing("Max thrust is 1234 ([1, 0, 2])"), "Max thrust is 1234 ([1, 0, 2])");
+    }
+}
@@ -0,0 +1,172 @@
+//! Measuring how long solutions take.
+//!
+//! Baselines are kept in a JSON file mapping each day to the median time of each step in
+//! nanoseconds, e.g. `{"2020/day18": {"parse": 46540, "part1": 572100, "part2": 597200}}`.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use serde_json::{json, Map, Value};
+
+use crate::{run, Day, Solution};
+
+/// Slowdowns smaller than this are considered noise
+pub const MIN_SLOWDOWN: Duration = Duration::from_micros(100);
+
+/// How long each step of a solution takes
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    /// Names of each step, as used in the baseline file
+    pub const STEPS: [&'static str; 3] = ["parse", "part1", "part2"];
+
+    pub fn steps(&self) -> [Duration; 3] {
+        [self.parse, self.part1, self.part2]
+    }
+
+    pub fn to_json(&self) -> Value {
+        let steps = Timings::STEPS.iter().zip(self.steps().iter())
+            .map(|(&name, time)| (name.to_owned(), json!(time.as_nanos() as u64)))
+            .collect();
+
+        Value::Object(steps)
+    }
+
+    pub fn from_json(value: &Value) -> Option<Self> {
+        let step = |name| value.get(name).and_then(Value::as_u64).map(Duration::from_nanos);
+
+        Some(Timings { parse: step("parse")?, part1: step("part1")?, part2: step("part2")? })
+    }
+}
+
+/// Time a solution, taking the median of `reps` runs after `warmup` runs
+///
+/// Fails if the input can't be parsed or solved.
+pub fn bench<S: Solution>(input: &str, warmup: u32, reps: u32) -> Result<Timings, String> {
+    assert!(reps > 0, "Must run at least once");
+
+    for _ in 0..warmup {
+        run::<S>(input)?;
+    }
+
+    let mut times: [Vec<Duration>; 3] = Default::default();
+    for _ in 0..reps {
+        let run = run::<S>(input)?;
+        for (times, time) in times.iter_mut().zip(&[run.parse, run.part1.1, run.part2.1]) {
+            times.push(*time);
+        }
+    }
+
+    let [parse, part1, part2] = times.map(median);
+
+    Ok(Timings { parse, part1, part2 })
+}
+
+fn median(mut times: Vec<Duration>) -> Duration {
+    times.sort();
+
+    times[times.len() / 2]
+}
+
+/// How much slower `current` is than `baseline`, as a fraction (e.g. `0.5` is 50% slower)
+pub fn slowdown(baseline: Duration, current: Duration) -> f64 {
+    if baseline == Duration::default() {
+        return 0.0;
+    }
+
+    current.as_secs_f64() / baseline.as_secs_f64() - 1.0
+}
+
+/// Is `current` significantly slower than `baseline`
+pub fn is_slower(baseline: Duration, current: Duration, threshold: f64) -> bool {
+    current > baseline + MIN_SLOWDOWN && slowdown(baseline, current) > threshold
+}
+
+/// Stored timings to compare against
+#[derive(Clone, Debug, Default)]
+pub struct Baseline {
+    days: BTreeMap<String, Timings>,
+}
+
+impl Baseline {
+    /// Load a baseline, which is empty if the file doesn't exist yet
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let baseline = match fs::read_to_string(path) {
+            Ok(baseline) => baseline,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Baseline::default()),
+            Err(err) => return Err(err),
+        };
+
+        let value: Value = serde_json::from_str(&baseline)?;
+        let days = value.as_object()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Expected an object"))?
+            .iter()
+            .map(|(day, timings)| {
+                Timings::from_json(timings)
+                    .map(|timings| (day.clone(), timings))
+                    .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, format!("Invalid timings for {}", day)))
+            })
+            .collect::<io::Result<_>>()?;
+
+        Ok(Baseline { days })
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let days: Map<_, _> = self.days.iter()
+            .map(|(day, timings)| (day.clone(), timings.to_json()))
+            .collect();
+
+        fs::write(path, serde_json::to_string_pretty(&days)? + "\n")
+    }
+
+    pub fn get(&self, day: &Day) -> Option<&Timings> {
+        self.days.get(&day.to_string())
+    }
+
+    pub fn insert(&mut self, day: &Day, timings: Timings) {
+        self.days.insert(day.to_string(), timings);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json() {
+        let timings = Timings {
+            parse: Duration::from_nanos(1),
+            part1: Duration::from_micros(2),
+            part2: Duration::from_millis(3),
+        };
+        let value = timings.to_json();
+        assert_eq!(value, json!({"parse": 1, "part1": 2_000, "part2": 3_000_000}));
+        assert_eq!(Timings::from_json(&value), Some(timings));
+        assert_eq!(Timings::from_json(&json!({"parse": 1})), None);
+    }
+
+    #[test]
+    fn test_is_slower() {
+        let ms = Duration::from_millis;
+        assert!(is_slower(ms(10), ms(13), 0.2));
+        assert!(!is_slower(ms(10), ms(11), 0.2));
+        assert!(!is_slower(ms(10), ms(5), 0.2));
+
+        // Too small a difference to be more than noise
+        assert!(!is_slower(Duration::from_micros(10), Duration::from_micros(50), 0.2));
+    }
+
+    #[test]
+    fn test_median() {
+        let ms = Duration::from_millis;
+        assert_eq!(median(vec![ms(3), ms(1), ms(100)]), ms(3));
+    }
+}
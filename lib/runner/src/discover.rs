@@ -0,0 +1,93 @@
+//! Finding the solutions in the repository.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A day's crate, such as `2019/day04a`
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    /// Name of the crate's directory
+    pub name: String,
+    pub path: PathBuf,
+}
+
+impl Day {
+    /// Parse a directory name of the form `dayNN`, optionally with a suffix (e.g. `day04a`)
+    fn from_dir(year: u16, path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_str()?;
+        let digits = name.strip_prefix("day")?;
+        let day = digits.get(..2)?.parse().ok()?;
+        if !digits[2..].chars().all(|c| c.is_ascii_lowercase()) {
+            return None;
+        }
+
+        Some(Day { year, day, name: name.to_owned(), path: path.to_owned() })
+    }
+
+    /// Does this day implement `runner::Solution`
+    pub fn is_registered(&self) -> bool {
+        let manifest = fs::read_to_string(self.path.join("Cargo.toml")).unwrap_or_default();
+
+        manifest.lines()
+            .skip_while(|line| line.trim() != "[dependencies]")
+            .take_while(|line| !line.starts_with('[') || line.trim() == "[dependencies]")
+            .any(|line| line.split('=').next().map(str::trim) == Some("runner"))
+    }
+}
+
+impl fmt::Display for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.year, self.name)
+    }
+}
+
+/// All days in the repository at `root`, in order
+pub fn discover<P: AsRef<Path>>(root: P) -> io::Result<Vec<Day>> {
+    let mut days = Vec::new();
+    for entry in fs::read_dir(root)? {
+        let path = entry?.path();
+        let year = match path.file_name().and_then(|n| n.to_str()).and_then(|n| n.parse().ok()) {
+            Some(year) if path.is_dir() => year,
+            _ => continue,
+        };
+
+        for entry in fs::read_dir(&path)? {
+            let path = entry?.path();
+            if path.join("Cargo.toml").is_file() {
+                days.extend(Day::from_dir(year, &path));
+            }
+        }
+    }
+
+    days.sort();
+
+    Ok(days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_dir() {
+        let day = Day::from_dir(2019, Path::new("2019/day04a")).unwrap();
+        assert_eq!((day.year, day.day, day.name.as_str()), (2019, 4, "day04a"));
+        assert_eq!(day.to_string(), "2019/day04a");
+
+        assert!(Day::from_dir(2020, Path::new("2020/template")).is_none());
+        assert!(Day::from_dir(2020, Path::new("2020/day1")).is_none());
+    }
+
+    #[test]
+    fn test_discover() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        let days = discover(root).unwrap();
+        assert!(days.iter().any(|d| (d.year, d.day) == (2017, 1)));
+        assert!(days.iter().any(|d| d.name == "day04b"));
+        assert!(days.windows(2).all(|w| w[0] < w[1]));
+    }
+}
@@ -0,0 +1,238 @@
+//! Downloading puzzle inputs.
+//!
+//! Inputs are saved where the days read them from (see [`locator`]), which doubles as a cache:
+//! an input that has already been downloaded is only fetched again when forced, and then only
+//! if it has changed (using `If-Modified-Since`). Requests need the session cookie of a logged-in
+//! user and are spaced at least [`MIN_INTERVAL`] apart, even between runs.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Where puzzles are downloaded from
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// The shortest time allowed between requests
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Identifies us to the site, as requested by its maintainer
+const USER_AGENT: &str = concat!("aoc runner by ", env!("CARGO_PKG_AUTHORS"));
+
+/// What fetching an input did
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Fetched {
+    /// The input was already cached, so no request was made
+    Cached,
+    /// The cached input is still current
+    NotModified,
+    Downloaded,
+}
+
+/// Downloads puzzle inputs
+pub struct Fetcher {
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    /// File recording when the last request was made, in milliseconds since the Unix epoch
+    stamp: PathBuf,
+    agent: ureq::Agent,
+}
+
+impl Fetcher {
+    /// A fetcher for a site, authenticated with a `session` cookie
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Fetcher {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.to_owned(),
+            min_interval: MIN_INTERVAL,
+            stamp: std::env::temp_dir().join("aoc-last-fetch"),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Space requests at least `min_interval` apart, recording the time of the last request in `stamp`
+    pub fn rate_limit(self, min_interval: Duration, stamp: PathBuf) -> Self {
+        Fetcher { min_interval, stamp, ..self }
+    }
+
+    /// Fetch the input of a day into `path`, unless it's already there
+    ///
+    /// With `force`, the input is fetched again if it has changed since it was saved.
+    pub fn fetch(&self, year: u16, day: u8, path: &Path, force: bool) -> io::Result<Fetched> {
+        let modified = match fs::metadata(path) {
+            Ok(_) if !force => return Ok(Fetched::Cached),
+            Ok(metadata) => Some(metadata.modified()?),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+            Err(err) => return Err(err),
+        };
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let mut request = self.agent.get(&url)
+            .set("Cookie", &format!("session={}", self.session));
+        if let Some(modified) = modified {
+            request = request.set("If-Modified-Since", &httpdate::fmt_http_date(modified));
+        }
+
+        self.wait()?;
+        let response = match request.call() {
+            Ok(response) => response,
+            Err(ureq::Error::Status(status, response)) => {
+                let message = response.into_string().unwrap_or_default();
+                return Err(io::Error::other(format!("{} returned {}: {}", url, status, message.trim())));
+            },
+            Err(err) => return Err(io::Error::other(err)),
+        };
+
+        if response.status() == 304 {
+            return Ok(Fetched::NotModified);
+        }
+
+        let last_modified = response.header("Last-Modified")
+            .and_then(|date| httpdate::parse_http_date(date).ok());
+        let input = response.into_string()?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, input)?;
+
+        // Use the server's time, so that later requests compare like with like
+        if let Some(last_modified) = last_modified {
+            fs::File::options().write(true).open(path)?.set_modified(last_modified)?;
+        }
+
+        Ok(Fetched::Downloaded)
+    }
+
+    /// Wait until enough time has passed since the last request, then record a new one
+    fn wait(&self) -> io::Result<()> {
+        let last = fs::read_to_string(&self.stamp).ok()
+            .and_then(|stamp| stamp.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+        if let Some(last) = last {
+            let elapsed = SystemTime::now().duration_since(last).unwrap_or_default();
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+
+        // Round up, so the stamp is never earlier than the request and the next wait is never short
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        fs::write(&self.stamp, now.as_nanos().div_ceil(1_000_000).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::process;
+    use std::sync::{Arc, Mutex};
+    use std::time::Instant;
+
+    /// When the fixtures last changed, as far as the mock server is concerned
+    const FIXTURE_DATE: &str = "Tue, 01 Dec 2020 05:00:00 GMT";
+
+    /// Serve the files in `fixtures`, recording the headers of each request
+    ///
+    /// Like the real site, requests without a session cookie are refused.
+    fn mock_server() -> (String, Arc<Mutex<Vec<Vec<String>>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+
+        let log = Arc::clone(&requests);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let lines: Vec<String> = BufReader::new(&stream).lines()
+                    .map(Result::unwrap)
+                    .take_while(|line| !line.is_empty())
+                    .collect();
+
+                let path = lines[0].split(' ').nth(1).unwrap().trim_start_matches('/');
+                let header = |name: &str| lines.iter()
+                    .find_map(|line| line.strip_prefix(name).map(str::to_owned));
+
+                let (status, body) = match fs::read_to_string(fixtures.join(path)) {
+                    _ if header("Cookie: ") != Some(String::from("session=secret")) => ("400 Bad Request", String::from("Please log in")),
+                    Ok(_) if header("If-Modified-Since: ").as_deref() == Some(FIXTURE_DATE) => ("304 Not Modified", String::new()),
+                    Ok(body) => ("200 OK", body),
+                    Err(_) => ("404 Not Found", String::from("Not found")),
+                };
+                log.lock().unwrap().push(lines);
+
+                write!(stream, "HTTP/1.1 {}\r\nLast-Modified: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                       status, FIXTURE_DATE, body.len(), body).unwrap();
+            }
+        });
+
+        (url, requests)
+    }
+
+    fn scratch(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("runner-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        dir
+    }
+
+    #[test]
+    fn test_fetch() {
+        let (url, requests) = mock_server();
+        let dir = scratch("fetch");
+        let fetcher = Fetcher::new(&url, "secret").rate_limit(Duration::ZERO, dir.join("stamp"));
+        let path = dir.join("2020/day01/input.txt");
+
+        assert_eq!(fetcher.fetch(2020, 1, &path, false).unwrap(), Fetched::Downloaded);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1721\n979\n366\n299\n675\n1456\n");
+        assert_eq!(requests.lock().unwrap()[0][0], "GET /2020/day/1/input HTTP/1.1");
+
+        // Never refetched unless forced
+        assert_eq!(fetcher.fetch(2020, 1, &path, false).unwrap(), Fetched::Cached);
+        assert_eq!(requests.lock().unwrap().len(), 1);
+
+        assert_eq!(fetcher.fetch(2020, 1, &path, true).unwrap(), Fetched::NotModified);
+        assert_eq!(requests.lock().unwrap().len(), 2);
+        assert!(requests.lock().unwrap()[1].contains(&format!("If-Modified-Since: {}", FIXTURE_DATE)));
+
+        let err = fetcher.fetch(2020, 2, &dir.join("2020/day02/input.txt"), false).unwrap_err();
+        assert!(err.to_string().contains("404"));
+        assert!(!dir.join("2020/day02/input.txt").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_session() {
+        let (url, _) = mock_server();
+        let dir = scratch("session");
+        let fetcher = Fetcher::new(&url, "wrong").rate_limit(Duration::ZERO, dir.join("stamp"));
+
+        let err = fetcher.fetch(2020, 1, &dir.join("input.txt"), false).unwrap_err();
+        assert!(err.to_string().contains("Please log in"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let (url, _) = mock_server();
+        let dir = scratch("rate-limit");
+        let interval = Duration::from_millis(200);
+        let fetcher = Fetcher::new(&url, "secret").rate_limit(interval, dir.join("stamp"));
+
+        let start = Instant::now();
+        fetcher.fetch(2020, 1, &dir.join("a.txt"), false).unwrap();
+        fetcher.fetch(2020, 1, &dir.join("b.txt"), false).unwrap();
+        assert!(start.elapsed() >= interval);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,185 @@
+//! A common interface for running puzzle solutions.
+//!
+//! Each day implements [`Solution`] and calls [`main`] from its own `main` function, which
+//! handles finding and reading the input and printing the answer and timing of each part:
+//!
+//! ```ignore
+//! fn main() {
+//!     runner::main::<Day18>(runner::locator!());
+//! }
+//! ```
+//! The `aoc` binary finds every registered day in the repository with [`discover`].
+//!
+//! Running a day with `--bench REPS` instead prints the median [`bench::Timings`] as JSON.
+//!
+//! Solutions can also be run where there's no filesystem or [`Instant`] (e.g. in the browser)
+//! with [`run_with_clock`].
+
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+use std::process;
+
+pub mod answers;
+pub mod bench;
+pub mod discover;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod fetch;
+pub mod scaffold;
+
+pub use discover::{discover, Day};
+pub use locator::{locator, Input, Locator, INPUT};
+
+const USAGE: &str = "Usage: [--input FILE | --sample N] [--bench REPS [--warmup N]]";
+
+/// A solution to both parts of a puzzle
+pub trait Solution {
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    /// Parse the puzzle input, explaining what's wrong with it if it can't be
+    fn parse(input: &str) -> Result<Self::Input, String>;
+
+    /// Solve part 1, explaining why if the input has no answer
+    fn part1(input: &Self::Input) -> Result<Self::Part1, String>;
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, String>;
+}
+
+/// The answers to a puzzle and how long each step took
+#[derive(Clone, Debug)]
+pub struct Run {
+    pub parse: Duration,
+    pub part1: (String, Duration),
+    pub part2: (String, Duration),
+}
+
+/// Parse an input and solve both parts
+pub fn run<S: Solution>(input: &str) -> Result<Run, String> {
+    let start = Instant::now();
+    run_with_clock::<S>(input, || start.elapsed())
+}
+
+/// Parse an input and solve both parts, timing each step with `clock`
+pub fn run_with_clock<S: Solution>(input: &str, clock: impl Fn() -> Duration) -> Result<Run, String> {
+    let (input, parse) = timed(&clock, || S::parse(input));
+    let input = input?;
+    let (part1, part1_time) = timed(&clock, || S::part1(&input));
+    let part1 = part1.map_err(|err| format!("Part 1: {}", err))?.to_string();
+    let (part2, part2_time) = timed(&clock, || S::part2(&input));
+    let part2 = part2.map_err(|err| format!("Part 2: {}", err))?.to_string();
+
+    Ok(Run { parse, part1: (part1, part1_time), part2: (part2, part2_time) })
+}
+
+/// Read and parse an input
+///
+/// Panics if the input can't be read or parsed.
+pub fn read_input<S: Solution>(input: impl Into<Input>) -> S::Input {
+    S::parse(&read_to_string(&input.into())).unwrap_or_else(|err| panic!("Failed to parse input: {}", err))
+}
+
+fn read_to_string(input: &Input) -> String {
+    input.read_to_string().unwrap_or_else(|err| panic!("Failed to read input: {}", err))
+}
+
+fn timed<T>(clock: impl Fn() -> Duration, f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = clock();
+    let result = f();
+
+    (result, clock() - start)
+}
+
+/// Run a solution on the input chosen on the command line (`input.txt` by default)
+pub fn main<S: Solution>(locator: Locator) {
+    let (input, args) = locator.split_args();
+    let mut reps = None;
+    let mut warmup = 1;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--bench" => reps = Some(number_arg(args.next())),
+            "--warmup" => warmup = number_arg(args.next()),
+            _ => usage(),
+        }
+    }
+
+    if reps == Some(0) {
+        usage();
+    }
+
+    let input = read_to_string(&input);
+
+    let result = match reps {
+        Some(reps) => bench::bench::<S>(&input, warmup, reps).map(|timings| println!("{}", timings.to_json())),
+        None => run::<S>(&input).map(|run| {
+            println!("Parsed input ({:.2?})", run.parse);
+            println!("Part 1: {} ({:.2?})", run.part1.0, run.part1.1);
+            println!("Part 2: {} ({:.2?})", run.part2.0, run.part2.1);
+        }),
+    };
+
+    if let Err(err) = result {
+        eprintln!("Error: {}", err);
+        process::exit(1);
+    }
+}
+
+fn number_arg(arg: Option<String>) -> u32 {
+    arg.and_then(|arg| arg.parse().ok()).unwrap_or_else(|| usage())
+}
+
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+        type Part1 = u32;
+        type Part2 = String;
+
+        fn parse(input: &str) -> Result<Self::Input, String> {
+            input.lines().map(|line| line.parse().map_err(|_| format!("Not a number: {:?}", line))).collect()
+        }
+
+        fn part1(input: &Self::Input) -> Result<u32, String> {
+            input.iter().try_fold(0u32, |sum, &n| sum.checked_add(n)).ok_or_else(|| String::from("Sum overflowed"))
+        }
+
+        fn part2(input: &Self::Input) -> Result<String, String> {
+            Ok(format!("{:?}", input))
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        assert_eq!(run::<Sum>("1\nx\n").unwrap_err(), "Not a number: \"x\"");
+        assert_eq!(run::<Sum>("1\n4294967295\n").unwrap_err(), "Part 1: Sum overflowed");
+
+        let run = run::<Sum>("1\n2\n3\n").unwrap();
+        assert_eq!(run.part1.0, "6");
+        assert_eq!(run.part2.0, "[1, 2, 3]");
+    }
+
+    #[test]
+    fn test_run_with_clock() {
+        // A clock that ticks once each time it's read
+        let ticks = std::cell::Cell::new(0);
+        let clock = || {
+            ticks.set(ticks.get() + 1);
+            Duration::from_millis(ticks.get())
+        };
+
+        let run = run_with_clock::<Sum>("1\n2\n", clock).unwrap();
+        assert_eq!(run.part1.0, "3");
+        assert_eq!((run.parse, run.part1.1, run.part2.1), (Duration::from_millis(1), Duration::from_millis(1), Duration::from_millis(1)));
+    }
+}
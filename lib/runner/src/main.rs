@@ -0,0 +1,380 @@
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::convert::TryFrom;
+use std::str::FromStr;
+use std::process::{self, Command, Stdio};
+use std::{env, fs};
+
+use runner::answers::{Answers, Status};
+use runner::bench::{self, Baseline, Timings};
+use runner::fetch::{self, Fetched, Fetcher};
+use runner::{Day, Input, Locator};
+
+/// The expected answers, relative to the root of the repository
+const ANSWERS: &str = "answers.txt";
+
+/// The baseline timings, relative to the root of the repository
+const BENCHMARKS: &str = "benchmarks.json";
+
+const USAGE: &str = "\
+Usage: aoc list
+       aoc run [YEAR [DAY]] [--input FILE | --sample N]
+       aoc test [YEAR [DAY]]
+       aoc bench [YEAR [DAY]] [--reps N] [--warmup N] [--threshold PERCENT] [--save]
+       aoc new YEAR DAY [--title TITLE]
+       aoc fetch YEAR DAY [--force]";
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let command = args.next().unwrap_or_else(|| usage());
+
+    let mut filter = Vec::new();
+    let mut options = Options::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => options.input = Some(value(&mut args)),
+            "--sample" => options.sample = Some(value(&mut args)),
+            "--reps" => options.reps = Some(value(&mut args)).filter(|&n| n > 0).or_else(|| usage()),
+            "--warmup" => options.warmup = Some(value(&mut args)),
+            "--threshold" => options.threshold = Some(value(&mut args)),
+            "--save" => options.save = true,
+            "--title" => options.title = Some(value(&mut args)),
+            "--force" => options.force = true,
+            "-h" | "--help" => usage(),
+            _ => filter.push(arg.parse::<u16>().unwrap_or_else(|_| usage())),
+        }
+    }
+
+    if filter.len() > 2 {
+        usage();
+    }
+
+    let days: Vec<_> = days().into_iter()
+        .filter(|day| filter.first().is_none_or(|&year| day.year == year))
+        .filter(|day| filter.get(1).is_none_or(|&n| u16::from(day.day) == n))
+        .collect();
+    let registered: Vec<_> = days.iter().filter(|day| day.is_registered()).cloned().collect();
+
+    let bench_options = options.reps.is_some() || options.warmup.is_some() || options.threshold.is_some() || options.save;
+    if options.title.is_some() && command != "new" {
+        usage();
+    }
+    if options.force && command != "fetch" {
+        usage();
+    }
+    if options.sample.is_some() && (command != "run" || options.input.is_some()) {
+        usage();
+    }
+
+    match command.as_str() {
+        "new" if options.input.is_none() && !bench_options => match filter[..] {
+            [year, day] => new(year, day, options.title.as_deref()),
+            _ => usage(),
+        },
+        "fetch" if options.input.is_none() && !bench_options => match filter[..] {
+            [year, day] => fetch(year, day, &days, options.force),
+            _ => usage(),
+        },
+        "list" if options.input.is_none() && !bench_options => list(&registered),
+        "test" if options.input.is_none() && !bench_options => test(&days),
+        "run" if !bench_options => {
+            let days = registered;
+
+            // An input file only makes sense for a single puzzle
+            if options.input.is_some() && days.len() > 1 {
+                eprintln!("--input requires a YEAR and DAY");
+                process::exit(2);
+            }
+
+            let input_args: Vec<OsString> = match (options.input, options.sample) {
+                (Some(path), _) if path == "-" => vec!["--input".into(), path.into()],
+                (Some(path), _) => vec!["--input".into(), canonicalize(path).into()],
+                (None, Some(n)) => vec!["--sample".into(), n.to_string().into()],
+                (None, None) => vec![],
+            };
+
+            run(&days, &input_args);
+        },
+        "bench" if options.input.is_none() => bench(&registered, &options),
+        _ => usage(),
+    }
+}
+
+/// Options that only some commands accept
+#[derive(Default)]
+struct Options {
+    input: Option<String>,
+    sample: Option<u32>,
+    reps: Option<u32>,
+    warmup: Option<u32>,
+    /// Percentage slowdown to flag
+    threshold: Option<f64>,
+    save: bool,
+    title: Option<String>,
+    force: bool,
+}
+
+/// Parse the value of an option
+fn value<T: FromStr>(args: &mut impl Iterator<Item=String>) -> T {
+    args.next().and_then(|arg| arg.parse().ok()).unwrap_or_else(|| usage())
+}
+
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
+
+/// The root of the repository
+///
+/// Can be overridden with the `AOC_ROOT` environment variable.
+fn root() -> PathBuf {
+    let root = env::var_os("AOC_ROOT")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("../.."));
+
+    fs::canonicalize(&root).unwrap_or(root)
+}
+
+fn days() -> Vec<Day> {
+    let root = root();
+
+    runner::discover(&root).unwrap_or_else(|err| panic!("Failed to read {}: {}", root.display(), err))
+}
+
+/// Check a day given on the command line is a puzzle day
+fn day_number(day: u16) -> u8 {
+    match u8::try_from(day) {
+        Ok(day) if (1..=25).contains(&day) => day,
+        _ => {
+            eprintln!("DAY must be between 1 and 25");
+            process::exit(2);
+        },
+    }
+}
+
+/// Create a new day from the template
+fn new(year: u16, day: u16, title: Option<&str>) {
+    let day = day_number(day);
+
+    match runner::scaffold::new_day(&root(), year, day, title) {
+        Ok(path) => println!("Created {}", path.display()),
+        Err(err) => {
+            eprintln!("Failed to create {}/day{:02}: {}", year, day, err);
+            process::exit(1);
+        },
+    }
+}
+
+/// Download a day's input to where the day reads it from
+///
+/// Needs the session cookie of a logged-in user in `AOC_SESSION`. The site can be changed with `AOC_BASE_URL`.
+fn fetch(year: u16, day: u16, days: &[Day], force: bool) {
+    let day = day_number(day);
+    let session = env::var("AOC_SESSION").unwrap_or_else(|_| {
+        eprintln!("AOC_SESSION must be set to the session cookie of a logged-in user");
+        process::exit(2);
+    });
+    let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| String::from(fetch::BASE_URL));
+
+    // The day may not have been created yet
+    let dir = match days.first() {
+        Some(day) => day.path.clone(),
+        None => root().join(year.to_string()).join(format!("day{:02}", day)),
+    };
+    let path = match Locator::new(dir).input() {
+        Input::File(path) => path,
+        Input::Stdin => unreachable!("A day's input is always a file"),
+    };
+
+    match Fetcher::new(&base_url, &session).fetch(year, day, &path, force) {
+        Ok(Fetched::Downloaded) => println!("Downloaded {}", path.display()),
+        Ok(Fetched::NotModified) => println!("{} is up to date", path.display()),
+        Ok(Fetched::Cached) => println!("{} already exists (use --force to check for changes)", path.display()),
+        Err(err) => {
+            eprintln!("Failed to fetch {}/day{:02}: {}", year, day, err);
+            process::exit(1);
+        },
+    }
+}
+
+/// Input paths are relative to where `aoc` was run, not the day's directory
+fn canonicalize(path: String) -> PathBuf {
+    fs::canonicalize(&path).unwrap_or_else(|err| panic!("Failed to read {}: {}", path, err))
+}
+
+fn list(days: &[Day]) {
+    for day in days {
+        println!("{}", day);
+    }
+}
+
+/// Run each day, passing on the arguments that choose its input
+fn run(days: &[Day], input_args: &[OsString]) {
+    check_not_empty(days);
+
+    let mut failed = 0;
+    for day in days {
+        println!("== {} ==", day);
+
+        let status = Command::new("cargo")
+            .args(["run", "--release", "--quiet", "--"])
+            .args(input_args)
+            .current_dir(&day.path)
+            .status()
+            .expect("Failed to run cargo");
+        if !status.success() {
+            eprintln!("{} failed ({})", day, status);
+            failed += 1;
+        }
+    }
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+/// Check every day's answers against the expected answers
+fn test(days: &[Day]) {
+    check_not_empty(days);
+
+    let path = root().join(ANSWERS);
+    let answers = Answers::load(&path)
+        .unwrap_or_else(|err| panic!("Failed to read {}: {}", path.display(), err));
+
+    let mut failures = Vec::new();
+    let mut counts = [0; 4];
+
+    println!("{:<12} {:<8} PART 2", "DAY", "PART 1");
+    for day in days {
+        let output = Command::new("cargo")
+            .args(["run", "--release", "--quiet", "--"])
+            .args(answers.args(day))
+            .current_dir(&day.path)
+            .stdin(Stdio::null())
+            .output()
+            .expect("Failed to run cargo");
+
+        // A day that crashes only fails the parts that have an expected answer
+        let stdout = if output.status.success() { String::from_utf8_lossy(&output.stdout) } else { "".into() };
+
+        let statuses: Vec<_> = (1..=2)
+            .map(|part| match answers.skipped(day, part) {
+                Some(_) => Status::Skipped,
+                None => Status::check(answers.get(day, part), &stdout, part),
+            })
+            .collect();
+        println!("{:<12} {:<8} {}", day.to_string(), statuses[0], statuses[1]);
+
+        for (part, status) in (1..=2).zip(statuses) {
+            match status {
+                Status::Pass => counts[0] += 1,
+                Status::Fail(..) => counts[1] += 1,
+                Status::Missing => counts[2] += 1,
+                Status::Skipped => counts[3] += 1,
+            }
+
+            if let Status::Fail(expected, line) = status {
+                let actual = match line {
+                    Some(line) => format!("got {:?}", line),
+                    None if output.status.success() => String::from("no answer"),
+                    None => format!("exited with {}", output.status),
+                };
+                failures.push(format!("{} part {}: expected {}, {}", day, part, expected, actual));
+            }
+        }
+    }
+
+    println!();
+    for failure in &failures {
+        println!("{}", failure);
+    }
+    println!("{} passed, {} failed, {} missing, {} skipped", counts[0], counts[1], counts[2], counts[3]);
+
+    if !failures.is_empty() {
+        process::exit(1);
+    }
+}
+
+fn check_not_empty(days: &[Day]) {
+    if days.is_empty() {
+        eprintln!("No matching days");
+        process::exit(1);
+    }
+}
+
+/// Time every day and compare against the baseline
+fn bench(days: &[Day], options: &Options) {
+    check_not_empty(days);
+
+    let reps = options.reps.unwrap_or(10);
+    let warmup = options.warmup.unwrap_or(1);
+    let threshold = options.threshold.unwrap_or(20.0) / 100.0;
+
+    let path = root().join(BENCHMARKS);
+    let mut baseline = Baseline::load(&path)
+        .unwrap_or_else(|err| panic!("Failed to read {}: {}", path.display(), err));
+
+    let mut slower = Vec::new();
+    let mut failed = 0;
+
+    println!("{:<12} {:<22} {:<22} PART 2", "DAY", "PARSE", "PART 1");
+    for day in days {
+        let output = Command::new("cargo")
+            .args(["run", "--release", "--quiet", "--"])
+            .args(["--bench", &reps.to_string(), "--warmup", &warmup.to_string()])
+            .current_dir(&day.path)
+            .stdin(Stdio::null())
+            .output()
+            .expect("Failed to run cargo");
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let timings = stdout.lines().last()
+            .and_then(|line| serde_json::from_str(line).ok())
+            .and_then(|value| Timings::from_json(&value));
+        let timings = match timings {
+            Some(timings) if output.status.success() => timings,
+            _ => {
+                println!("{:<12} failed ({})", day.to_string(), output.status);
+                failed += 1;
+                continue;
+            },
+        };
+
+        let previous = baseline.get(day).copied();
+        let mut columns = Vec::new();
+        for (i, (&step, &time)) in Timings::STEPS.iter().zip(timings.steps().iter()).enumerate() {
+            let column = match previous.map(|previous| previous.steps()[i]) {
+                Some(previous) => {
+                    let flag = if bench::is_slower(previous, time, threshold) {
+                        slower.push(format!("{} {}: {:.2?} -> {:.2?}", day, step, previous, time));
+                        " !"
+                    } else {
+                        ""
+                    };
+
+                    format!("{:.2?} ({:+.0}%){}", time, 100.0 * bench::slowdown(previous, time), flag)
+                },
+                None => format!("{:.2?}", time),
+            };
+            columns.push(column);
+        }
+        println!("{:<12} {:<22} {:<22} {}", day.to_string(), columns[0], columns[1], columns[2]);
+
+        if options.save {
+            baseline.insert(day, timings);
+        }
+    }
+
+    if options.save {
+        baseline.save(&path).unwrap_or_else(|err| panic!("Failed to write {}: {}", path.display(), err));
+    }
+
+    println!();
+    for slowdown in &slower {
+        println!("Slower than baseline: {}", slowdown);
+    }
+
+    if failed > 0 || !slower.is_empty() {
+        process::exit(1);
+    }
+}
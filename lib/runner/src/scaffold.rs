@@ -0,0 +1,224 @@
+//! Creating new days from the template.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The crate new days are copied from, relative to the root of the repository
+pub const TEMPLATE: &str = "2020/template";
+
+/// Files in the template that are never copied
+const IGNORED: [&str; 3] = ["target", "Cargo.lock", "input.txt"];
+
+/// Files created empty for filling in by hand
+const PLACEHOLDERS: [&str; 1] = ["sample1.txt"];
+
+/// Create a new day from the template and add it to the year's README (and the year to the workspace)
+///
+/// Fails with [`io::ErrorKind::AlreadyExists`] if the day already exists.
+pub fn new_day(root: &Path, year: u16, day: u8, title: Option<&str>) -> io::Result<PathBuf> {
+    let name = format!("day{:02}", day);
+    let path = root.join(year.to_string()).join(&name);
+    if path.exists() {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("{} already exists", path.display())));
+    }
+
+    copy_template(&root.join(TEMPLATE), &path, year, day)?;
+    for placeholder in &PLACEHOLDERS {
+        fs::write(path.join(placeholder), "")?;
+    }
+
+    let title = match title {
+        Some(title) => format!("Day {}: {}", day, title),
+        None => format!("Day {}", day),
+    };
+    add_to_readme(&root.join(year.to_string()).join("README.md"), year, day, &format!("- [{}]({})", title, name))?;
+    add_to_workspace(&root.join("Cargo.toml"), year)?;
+
+    Ok(path)
+}
+
+/// Copy the template, replacing the `YYYY` and `XX` placeholders with the year and day number
+fn copy_template(from: &Path, to: &Path, year: u16, day: u8) -> io::Result<()> {
+    fs::create_dir_all(to)?;
+
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let file_name = entry.file_name();
+        if IGNORED.iter().any(|&ignored| file_name == ignored) {
+            continue;
+        }
+
+        let target = to.join(&file_name);
+        if entry.file_type()?.is_dir() {
+            copy_template(&entry.path(), &target, year, day)?;
+        } else {
+            let contents = fs::read_to_string(entry.path())?;
+            let contents = contents.replace("YYYY", &year.to_string()).replace("XX", &format!("{:02}", day));
+            fs::write(target, contents)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Add an entry to the solutions list of a year's README, keeping the days in order
+fn add_to_readme(path: &Path, year: u16, day: u8, entry: &str) -> io::Result<()> {
+    let readme = match fs::read_to_string(path) {
+        Ok(readme) => readme,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => format!(
+            "# Advent of Code {0}\n\nMy solutions to [Advent of Code {0}](https://adventofcode.com/{0}) puzzles in [Rust](https://www.rust-lang.org/).\n",
+            year),
+        Err(err) => return Err(err),
+    };
+
+    let mut lines: Vec<&str> = readme.lines().collect();
+    match lines.iter().position(|&line| line == "## Solutions") {
+        Some(heading) => {
+            // Insert before the first later day, or at the end of the list
+            let list = lines[heading + 1..].iter()
+                .take_while(|line| line.starts_with("- "))
+                .count();
+            let index = lines[heading + 1..heading + 1 + list].iter()
+                .position(|line| entry_day(line).is_some_and(|n| n > day))
+                .unwrap_or(list);
+
+            lines.insert(heading + 1 + index, entry);
+        },
+        None => {
+            if lines.last().is_some_and(|line| !line.is_empty()) {
+                lines.push("");
+            }
+            lines.push("## Solutions");
+            lines.push(entry);
+        },
+    }
+
+    fs::write(path, lines.join("\n") + "\n")
+}
+
+/// Add the days of a year to the workspace's members, if they aren't already, keeping the years in order
+fn add_to_workspace(path: &Path, year: u16) -> io::Result<()> {
+    let manifest = fs::read_to_string(path)?;
+    let member = format!("    \"{}/day*\",", year);
+
+    let mut lines: Vec<&str> = manifest.lines().collect();
+    let start = lines.iter().position(|&line| line == "members = [")
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "No workspace members"))? + 1;
+    let end = start + lines[start..].iter().position(|&line| line == "]")
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Unterminated workspace members"))?;
+    if lines[start..end].contains(&member.as_str()) {
+        return Ok(());
+    }
+
+    // Insert before the first later year, or after the last earlier one
+    let years: Vec<_> = (start..end).filter_map(|i| member_year(lines[i]).map(|y| (i, y))).collect();
+    let index = match years.iter().find(|&&(_, y)| y > year) {
+        Some(&(i, _)) => i,
+        None => years.last().map_or(end, |&(i, _)| i + 1),
+    };
+    lines.insert(index, &member);
+
+    fs::write(path, lines.join("\n") + "\n")
+}
+
+/// The year of a workspace member of the form `"2019/day*",`
+fn member_year(line: &str) -> Option<u16> {
+    let rest = line.trim().strip_prefix('"')?;
+
+    rest.get(..4)?.parse().ok().filter(|_| rest[4..].starts_with('/'))
+}
+
+/// The day of an entry of the form `- [Day 4: Title](day04)`
+fn entry_day(line: &str) -> Option<u8> {
+    let rest = line.strip_prefix("- [Day ")?;
+    let end = rest.find(|c: char| !c.is_ascii_digit())?;
+
+    rest[..end].parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::process;
+
+    const WORKSPACE: &str = "[workspace]\nmembers = [\n    \"lib/*\",\n    \"2019/day*\",\n    \"2019/intcode\",\n    \"2022/day*\",\n]\n";
+
+    /// A scratch repository containing just the template and the workspace manifest
+    fn scratch(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("runner-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("Cargo.toml"), WORKSPACE).unwrap();
+
+        let template = root.join(TEMPLATE);
+        fs::create_dir_all(template.join("src")).unwrap();
+        fs::create_dir_all(template.join("target")).unwrap();
+        fs::write(template.join("Cargo.toml"), "[package]\nname = \"aocYYYY-dayXX\"\n").unwrap();
+        fs::write(template.join("src/main.rs"), "struct DayXX;\n").unwrap();
+        fs::write(template.join("input.txt"), "1\n").unwrap();
+
+        root
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = scratch("new-day");
+        let path = new_day(&root, 2021, 3, Some("Binary Diagnostic")).unwrap();
+
+        assert_eq!(path, root.join("2021/day03"));
+        assert_eq!(fs::read_to_string(path.join("Cargo.toml")).unwrap(), "[package]\nname = \"aoc2021-day03\"\n");
+        assert_eq!(fs::read_to_string(path.join("src/main.rs")).unwrap(), "struct Day03;\n");
+        assert!(path.join("sample1.txt").exists());
+        assert!(!path.join("input.txt").exists());
+        assert!(!path.join("target").exists());
+
+        let readme = fs::read_to_string(root.join("2021/README.md")).unwrap();
+        assert!(readme.starts_with("# Advent of Code 2021\n"));
+        assert!(readme.ends_with("\n## Solutions\n- [Day 3: Binary Diagnostic](day03)\n"));
+
+        // 2021 wasn't in the workspace
+        let members = "members = [\n    \"lib/*\",\n    \"2019/day*\",\n    \"2019/intcode\",\n    \"2021/day*\",\n    \"2022/day*\",\n]\n";
+        assert!(fs::read_to_string(root.join("Cargo.toml")).unwrap().ends_with(members));
+        new_day(&root, 2021, 4, None).unwrap();
+        assert!(fs::read_to_string(root.join("Cargo.toml")).unwrap().ends_with(members));
+        new_day(&root, 2023, 1, None).unwrap();
+        assert!(fs::read_to_string(root.join("Cargo.toml")).unwrap().ends_with("    \"2022/day*\",\n    \"2023/day*\",\n]\n"));
+
+        let err = new_day(&root, 2021, 3, None).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_readme_order() {
+        let root = scratch("readme-order");
+        let readme = root.join("2021/README.md");
+        fs::create_dir_all(root.join("2021")).unwrap();
+        fs::write(&readme, "# 2021\n\n## Solutions\n- [Day 1: A](day01)\n- [Day 5: B](day05)\n\n## Other\n- x\n").unwrap();
+
+        new_day(&root, 2021, 3, None).unwrap();
+        new_day(&root, 2021, 7, Some("C")).unwrap();
+        assert_eq!(fs::read_to_string(&readme).unwrap(),
+                   "# 2021\n\n## Solutions\n- [Day 1: A](day01)\n- [Day 3](day03)\n- [Day 5: B](day05)\n- [Day 7: C](day07)\n\n## Other\n- x\n");
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_member_year() {
+        assert_eq!(member_year("    \"2019/day*\","), Some(2019));
+        assert_eq!(member_year("    \"2019/intcode\","), Some(2019));
+        assert_eq!(member_year("    \"lib/*\","), None);
+    }
+
+    #[test]
+    fn test_entry_day() {
+        assert_eq!(entry_day("- [Day 4: Secure Container](day04a) \\[[Alternate version](day04b)\\]"), Some(4));
+        assert_eq!(entry_day("- [Day 25](day25)"), Some(25));
+        assert_eq!(entry_day("- [Intcode interpreter](intcode)"), None);
+    }
+}
@@ -0,0 +1,88 @@
+use crate::Style;
+
+/// A character and how it's displayed (`None` to use the palette)
+pub type Cell = (char, Option<Style>);
+
+const BLANK: Cell = (' ', None);
+
+/// A screenful of characters to be displayed
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+}
+
+impl Frame {
+    /// A blank frame
+    pub fn new(width: usize, height: usize) -> Self {
+        Frame { width, height, cells: vec![BLANK; width * height] }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cell at (`x`, `y`)
+    pub fn get(&self, x: usize, y: usize) -> Cell {
+        self.cells[y * self.width + x]
+    }
+
+    /// Put a character at (`x`, `y`), styled by the palette
+    ///
+    /// Characters outside of the frame are ignored.
+    pub fn put(&mut self, x: usize, y: usize, c: char) {
+        self.set(x, y, (c, None));
+    }
+
+    /// Put a character at (`x`, `y`) in a particular style
+    pub fn put_styled(&mut self, x: usize, y: usize, c: char, style: Style) {
+        self.set(x, y, (c, Some(style)));
+    }
+
+    /// Write text starting at (`x`, `y`), cut off at the edge of the frame
+    pub fn print(&mut self, x: usize, y: usize, text: &str, style: Option<Style>) {
+        for (n, c) in text.chars().enumerate() {
+            self.set(x + n, y, (c, style));
+        }
+    }
+
+    /// Blank the whole frame
+    pub fn clear(&mut self) {
+        self.cells.iter_mut().for_each(|cell| *cell = BLANK);
+    }
+
+    /// The characters of each row, without any styles
+    pub fn lines(&self) -> impl Iterator<Item=String> + '_ {
+        self.cells.chunks(self.width.max(1))
+            .map(|row| row.iter().map(|&(c, _)| c).collect())
+    }
+
+    fn set(&mut self, x: usize, y: usize, cell: Cell) {
+        if x < self.width && y < self.height {
+            self.cells[y * self.width + x] = cell;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_print() {
+        let mut frame = Frame::new(4, 2);
+        frame.print(1, 0, "abcdef", None);
+        frame.put(0, 1, '#');
+        frame.put(9, 9, '#');
+
+        assert_eq!(frame.lines().collect::<Vec<_>>(), vec![" abc", "#   "]);
+
+        frame.clear();
+        assert_eq!(frame, Frame::new(4, 2));
+    }
+}
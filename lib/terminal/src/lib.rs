@@ -0,0 +1,442 @@
+//! Animating solutions in a terminal.
+//!
+//! A solution draws each [`Frame`] of its animation, then shows it with [`Terminal::present`].
+//! On a terminal, only the characters that changed since the last frame are redrawn,
+//! using the styles from a [`Palette`], with a status line below the frame.
+//! Anywhere else (e.g. piped into a file or a CI log) frames are written out as plain text.
+//!
+//! The cursor is hidden while animating and is shown again when the [`Terminal`] is dropped,
+//! even if the solution panics.
+//!
+//! Animations can also be recorded as an asciicast (see [`Terminal::record`]) to play back with
+//! [asciinema](https://asciinema.org), usually chosen with `--record FILE` (see [`record_arg`]).
+
+mod frame;
+mod record;
+mod style;
+
+pub use frame::{Cell, Frame};
+pub use style::{Colour, Palette, Style};
+
+use record::Recording;
+
+use std::io::{self, IsTerminal, Write};
+use std::path::PathBuf;
+use std::{panic, process};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Once;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Show the cursor and go back to the default style
+const RESTORE: &str = "\x1B[0m\x1B[?25h";
+
+/// How far apart frames are in a recording, if the frame rate isn't limited
+const RECORDING_FRAME_TIME: Duration = Duration::from_millis(20);
+
+/// Whether stdout needs restoring if we panic
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Displays frames of an animation
+pub struct Terminal {
+    out: Box<dyn Write>,
+    tty: bool,
+    /// Whether this is stdout, which the panic hook restores
+    stdout: bool,
+    palette: Palette,
+    /// The frame being drawn
+    frame: Frame,
+    /// The frame on screen (`None` if it needs to be redrawn from scratch)
+    shown: Option<Frame>,
+    status: String,
+    shown_status: Option<String>,
+    frame_time: Option<Duration>,
+    last_present: Option<Instant>,
+    plain_every: usize,
+    frames: usize,
+    /// A plain frame has been presented but not written
+    pending: bool,
+    /// The cursor is hidden
+    active: bool,
+    recording: Option<Recording>,
+}
+
+impl Terminal {
+    /// Animate on stdout, as plain text if it isn't a terminal
+    pub fn new(width: usize, height: usize) -> Self {
+        let tty = io::stdout().is_terminal();
+        let mut terminal = Terminal::with_output(Box::new(io::stdout()), tty, width, height);
+        terminal.stdout = true;
+
+        terminal
+    }
+
+    /// Animate on any output, as plain text unless `tty`
+    pub fn with_output(out: Box<dyn Write>, tty: bool, width: usize, height: usize) -> Self {
+        Terminal {
+            out,
+            tty,
+            stdout: false,
+            palette: Palette::new(),
+            frame: Frame::new(width, height),
+            shown: None,
+            status: String::new(),
+            shown_status: None,
+            frame_time: None,
+            last_present: None,
+            plain_every: 1,
+            frames: 0,
+            pending: false,
+            active: false,
+            recording: None,
+        }
+    }
+
+    /// Style characters that don't have their own style with `palette`
+    pub fn palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Limit the frame rate on a terminal (`None` for as fast as possible)
+    ///
+    /// Plain frames are never delayed.
+    pub fn fps(mut self, fps: Option<u64>) -> Self {
+        self.frame_time = fps.map(|fps| Duration::from_micros(1_000_000 / fps.max(1)));
+        self
+    }
+
+    /// Only write every `n`th frame when not on a terminal (the last frame is always written)
+    pub fn plain_every(mut self, n: usize) -> Self {
+        self.plain_every = n.max(1);
+        self
+    }
+
+    /// Record frames as an asciicast at `path` instead of displaying them (if there's a `path`)
+    ///
+    /// Frames are spaced by the frame rate and not delayed, so recording is quick and repeatable.
+    pub fn record(mut self, path: Option<PathBuf>) -> Self {
+        if let Some(path) = path {
+            self.recording = Some(Recording::new(path));
+            self.out = Box::new(io::sink());
+            self.tty = true;
+            self.stdout = false;
+        }
+        self
+    }
+
+    /// Is this a terminal, rather than plain text?
+    pub fn is_tty(&self) -> bool {
+        self.tty
+    }
+
+    /// The frame being drawn, which starts as a copy of the last one
+    pub fn frame(&mut self) -> &mut Frame {
+        &mut self.frame
+    }
+
+    /// Change the size of the frame, which blanks it
+    pub fn resize(&mut self, width: usize, height: usize) {
+        if (width, height) != (self.frame.width(), self.frame.height()) {
+            self.frame = Frame::new(width, height);
+        }
+    }
+
+    /// Set the line shown below the frame
+    pub fn set_status(&mut self, status: &str) {
+        self.status = status.to_owned();
+    }
+
+    /// Display the frame
+    pub fn present(&mut self) -> io::Result<()> {
+        self.frames += 1;
+        if self.tty {
+            self.redraw()?;
+            self.wait();
+        } else if (self.frames - 1).is_multiple_of(self.plain_every) {
+            self.write_plain()?;
+        } else {
+            self.pending = true;
+        }
+
+        Ok(())
+    }
+
+    /// Write out any frame that hasn't been yet, restore the terminal and save any recording
+    pub fn finish(&mut self) -> io::Result<()> {
+        if self.pending {
+            self.write_plain()?;
+        }
+        self.restore()
+    }
+
+    /// Redraw the characters that changed since the last frame
+    fn redraw(&mut self) -> io::Result<()> {
+        let mut output = String::new();
+        if !self.active {
+            output.push_str("\x1B[?25l");  // Hide cursor
+            self.active = true;
+            if self.stdout {
+                restore_on_panic();
+            }
+        }
+
+        let resized = self.shown.as_ref()
+            .map(|shown| (shown.width(), shown.height()) != (self.frame.width(), self.frame.height()));
+        if resized != Some(false) {
+            output.push_str("\x1B[2J");  // Clear screen
+            self.shown = None;
+            self.shown_status = None;
+        }
+
+        let mut cursor = None;
+        let mut style = None;
+        for y in 0..self.frame.height() {
+            for x in 0..self.frame.width() {
+                let cell = self.frame.get(x, y);
+                if self.shown.as_ref().map(|shown| shown.get(x, y)) == Some(cell) {
+                    continue;
+                }
+
+                if cursor != Some((x, y)) {
+                    output.push_str(&format!("\x1B[{};{}H", y + 1, x + 1));
+                }
+                let (c, cell_style) = cell;
+                let cell_style = cell_style.unwrap_or_else(|| self.palette.style(c));
+                if style != Some(cell_style) {
+                    output.push_str(&cell_style.sgr());
+                    style = Some(cell_style);
+                }
+                output.push(c);
+                cursor = Some((x + 1, y));
+            }
+        }
+
+        if self.shown_status.as_ref() != Some(&self.status) {
+            // The status line is just below the frame, after a blank line
+            output.push_str(&format!("\x1B[{}H\x1B[0m\x1B[2K{}", self.frame.height() + 2, self.status));
+            style = None;
+            self.shown_status = Some(self.status.clone());
+        }
+        if style.is_some() {
+            output.push_str("\x1B[0m");
+        }
+
+        self.emit(&output)?;
+        self.shown = Some(self.frame.clone());
+
+        Ok(())
+    }
+
+    /// Write output for the terminal (or record it)
+    fn emit(&mut self, output: &str) -> io::Result<()> {
+        if let Some(recording) = &mut self.recording {
+            let width = self.frame.width().max(self.status.chars().count());
+            recording.output(output, width, self.frame.height() + 2);
+            return Ok(());
+        }
+
+        self.out.write_all(output.as_bytes())?;
+        self.out.flush()
+    }
+
+    /// Wait until it's time for the next frame
+    fn wait(&mut self) {
+        if let Some(recording) = &mut self.recording {
+            recording.advance(self.frame_time.unwrap_or(RECORDING_FRAME_TIME));
+            return;
+        }
+
+        if let (Some(frame_time), Some(last)) = (self.frame_time, self.last_present) {
+            let elapsed = last.elapsed();
+            if elapsed < frame_time {
+                thread::sleep(frame_time - elapsed);
+            }
+        }
+        self.last_present = Some(Instant::now());
+    }
+
+    /// Write the frame and status as text
+    fn write_plain(&mut self) -> io::Result<()> {
+        let mut output = String::new();
+        for line in self.frame.lines() {
+            output.push_str(line.trim_end());
+            output.push('\n');
+        }
+        if !self.status.is_empty() {
+            output.push_str(&self.status);
+            output.push('\n');
+        }
+        output.push('\n');
+
+        self.pending = false;
+        self.out.write_all(output.as_bytes())?;
+        self.out.flush()
+    }
+
+    /// Show the cursor again, leaving it below the status line, then save any recording
+    fn restore(&mut self) -> io::Result<()> {
+        if self.active {
+            self.active = false;
+
+            // Unless the panic hook got there first (while unwinding)
+            let restored = self.stdout && !ACTIVE.swap(false, Ordering::SeqCst);
+            if !restored {
+                self.emit(&format!("{}\x1B[{}H\n", RESTORE, self.frame.height() + 2))?;
+            }
+        }
+
+        match &mut self.recording {
+            Some(recording) => recording.save(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        let _ = self.restore();
+    }
+}
+
+/// Take `--record FILE` out of command-line arguments
+///
+/// Exits with a usage message if `FILE` is missing.
+pub fn record_arg(args: &mut Vec<String>) -> Option<PathBuf> {
+    let n = args.iter().position(|arg| arg == "--record")?;
+    args.remove(n);
+    if n == args.len() {
+        eprintln!("--record requires a FILE");
+        process::exit(2);
+    }
+
+    Some(PathBuf::from(args.remove(n)))
+}
+
+/// Make sure a panic doesn't leave stdout without a cursor
+fn restore_on_panic() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if ACTIVE.swap(false, Ordering::SeqCst) {
+                println!("{}", RESTORE);
+            }
+            hook(info);
+        }));
+    });
+    ACTIVE.store(true, Ordering::SeqCst);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// Output that can be inspected while the terminal still has it
+    #[derive(Clone, Default)]
+    struct Capture(Rc<RefCell<Vec<u8>>>);
+
+    impl Capture {
+        fn take(&self) -> String {
+            String::from_utf8(self.0.borrow_mut().drain(..).collect()).unwrap()
+        }
+    }
+
+    impl Write for Capture {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_redraw() {
+        let capture = Capture::default();
+        let palette = Palette::new().with('#', Style::fg(Colour::Red));
+        let mut terminal = Terminal::with_output(Box::new(capture.clone()), true, 3, 2).palette(palette);
+
+        terminal.frame().print(0, 0, "#.", None);
+        terminal.present().unwrap();
+        assert_eq!(capture.take(), "\x1B[?25l\x1B[2J\x1B[1;1H\x1B[0;31m#\x1B[0m. \x1B[2;1H   \x1B[4H\x1B[0m\x1B[2K");
+
+        // Only changes are redrawn
+        terminal.frame().put(1, 0, '#');
+        terminal.frame().put_styled(2, 1, '@', Style::fg(Colour::Green).bold());
+        terminal.set_status("Hello");
+        terminal.present().unwrap();
+        assert_eq!(capture.take(), "\x1B[1;2H\x1B[0;31m#\x1B[2;3H\x1B[0;1;32m@\x1B[4H\x1B[0m\x1B[2KHello");
+
+        terminal.present().unwrap();
+        assert_eq!(capture.take(), "");
+
+        terminal.finish().unwrap();
+        assert_eq!(capture.take(), "\x1B[0m\x1B[?25h\x1B[4H\n");
+    }
+
+    #[test]
+    fn test_restored_by_panic_hook() {
+        let capture = Capture::default();
+        let mut terminal = Terminal::with_output(Box::new(capture.clone()), true, 1, 1);
+        terminal.stdout = true;
+        terminal.present().unwrap();
+        capture.take();
+
+        // As if the panic hook had restored stdout, before the terminal is dropped
+        assert!(ACTIVE.swap(false, Ordering::SeqCst));
+        drop(terminal);
+        assert_eq!(capture.take(), "");
+    }
+
+    #[test]
+    fn test_record() {
+        let path = std::env::temp_dir().join(format!("terminal-{}.cast", process::id()));
+        let mut terminal = Terminal::with_output(Box::new(io::sink()), false, 2, 1)
+            .fps(Some(4))
+            .record(Some(path.clone()));
+
+        terminal.frame().put(0, 0, '#');
+        terminal.present().unwrap();
+        terminal.set_status("Done");
+        terminal.present().unwrap();
+        drop(terminal);
+
+        let cast = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(cast.lines().collect::<Vec<_>>(), vec![
+            r#"{"version": 2, "width": 4, "height": 3}"#,
+            r#"[0.000000, "o", "\u001b[?25l\u001b[2J\u001b[1;1H\u001b[0m# \u001b[3H\u001b[0m\u001b[2K"]"#,
+            r#"[0.250000, "o", "\u001b[3H\u001b[0m\u001b[2KDone"]"#,
+            r#"[0.500000, "o", "\u001b[0m\u001b[?25h\u001b[3H\n"]"#,
+        ]);
+    }
+
+    #[test]
+    fn test_record_arg() {
+        let mut args = vec![String::from("--turbo"), String::from("--record"), String::from("out.cast")];
+        assert_eq!(record_arg(&mut args), Some(PathBuf::from("out.cast")));
+        assert_eq!(args, vec![String::from("--turbo")]);
+        assert_eq!(record_arg(&mut args), None);
+    }
+
+    #[test]
+    fn test_plain() {
+        let capture = Capture::default();
+        let mut terminal = Terminal::with_output(Box::new(capture.clone()), false, 3, 2).plain_every(2);
+
+        for c in "abc".chars() {
+            terminal.frame().put(0, 0, c);
+            terminal.set_status(&format!("Frame {}", c));
+            terminal.present().unwrap();
+        }
+        terminal.frame().put(0, 1, 'd');
+        terminal.present().unwrap();
+        terminal.finish().unwrap();
+
+        assert_eq!(capture.take(), "a\n\nFrame a\n\nc\n\nFrame c\n\nc\nd\nFrame c\n\n");
+    }
+}
@@ -0,0 +1,101 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Output recorded in [asciicast v2](https://docs.asciinema.org/manual/asciicast/v2/) format
+///
+/// Events are timed by frame rather than by the clock, so recordings are reproducible.
+/// Nothing is written until the recording is saved, since the header needs the size of the screen.
+#[derive(Clone, Debug)]
+pub(crate) struct Recording {
+    path: PathBuf,
+    events: Vec<(Duration, String)>,
+    time: Duration,
+    width: usize,
+    height: usize,
+    saved: bool,
+}
+
+impl Recording {
+    pub(crate) fn new(path: PathBuf) -> Self {
+        Recording { path, events: Vec::new(), time: Duration::ZERO, width: 0, height: 0, saved: false }
+    }
+
+    /// Record output at the current time, which needs a screen of at least `width` by `height`
+    pub(crate) fn output(&mut self, data: &str, width: usize, height: usize) {
+        self.width = self.width.max(width);
+        self.height = self.height.max(height);
+        if !data.is_empty() {
+            self.events.push((self.time, data.to_owned()));
+        }
+    }
+
+    /// Move on to the time of the next frame
+    pub(crate) fn advance(&mut self, duration: Duration) {
+        self.time += duration;
+    }
+
+    /// Write out the recording (only once)
+    pub(crate) fn save(&mut self) -> io::Result<()> {
+        if self.saved {
+            return Ok(());
+        }
+        self.saved = true;
+
+        let mut out = io::BufWriter::new(fs::File::create(&self.path)?);
+        self.write(&mut out)?;
+        out.flush()
+    }
+
+    fn write(&self, out: &mut impl Write) -> io::Result<()> {
+        writeln!(out, r#"{{"version": 2, "width": {}, "height": {}}}"#, self.width, self.height)?;
+        for (time, data) in &self.events {
+            writeln!(out, r#"[{:.6}, "o", {}]"#, time.as_secs_f64(), json_string(data))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Quote a string for JSON
+fn json_string(s: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write() {
+        let mut recording = Recording::new(PathBuf::from("test.cast"));
+        recording.output("\x1B[2J#", 3, 4);
+        recording.advance(Duration::from_millis(250));
+        recording.output("", 5, 2);
+        recording.advance(Duration::from_millis(250));
+        recording.output("\"a\\b\"\n", 1, 1);
+
+        let mut out = Vec::new();
+        recording.write(&mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), concat!(
+            "{\"version\": 2, \"width\": 5, \"height\": 4}\n",
+            "[0.000000, \"o\", \"\\u001b[2J#\"]\n",
+            "[0.500000, \"o\", \"\\\"a\\\\b\\\"\\n\"]\n",
+        ));
+    }
+}
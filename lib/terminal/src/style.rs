@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use std::io::{self, IsTerminal};
+
+/// One of the 8 standard terminal colours
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Colour {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Colour {
+    /// All the colours, in SGR order
+    pub const ALL: [Colour; 8] = [
+        Colour::Black, Colour::Red, Colour::Green, Colour::Yellow,
+        Colour::Blue, Colour::Magenta, Colour::Cyan, Colour::White,
+    ];
+
+    /// SGR parameter for using this as the foreground colour
+    fn sgr(self) -> u8 {
+        30 + self as u8
+    }
+}
+
+/// How a character is displayed
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Style {
+    pub fg: Option<Colour>,
+    pub bold: bool,
+}
+
+impl Style {
+    /// The terminal's default style
+    pub const PLAIN: Style = Style { fg: None, bold: false };
+
+    /// Text in a colour
+    pub const fn fg(colour: Colour) -> Self {
+        Style { fg: Some(colour), bold: false }
+    }
+
+    /// The same style, but bold (usually brighter)
+    pub const fn bold(self) -> Self {
+        Style { bold: true, ..self }
+    }
+
+    /// Escape sequence that switches to this style
+    pub fn sgr(self) -> String {
+        let mut params = vec![String::from("0")];
+        if self.bold {
+            params.push(String::from("1"));
+        }
+        if let Some(fg) = self.fg {
+            params.push(fg.sgr().to_string());
+        }
+
+        format!("\x1B[{}m", params.join(";"))
+    }
+}
+
+/// Styles for particular characters
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct Palette {
+    styles: HashMap<char, Style>,
+}
+
+impl Palette {
+    pub fn new() -> Self {
+        Palette::default()
+    }
+
+    /// Display `c` in `style`
+    pub fn with(mut self, c: char, style: Style) -> Self {
+        self.styles.insert(c, style);
+        self
+    }
+
+    /// The style for `c`
+    pub fn style(&self, c: char) -> Style {
+        self.styles.get(&c).copied().unwrap_or_default()
+    }
+
+    /// `text` with each character in its style, for printing without a [`crate::Terminal`]
+    ///
+    /// Styles are left out unless stdout is a terminal (e.g. when it's redirected to a file).
+    pub fn paint(&self, text: &str) -> String {
+        self.paint_for(text, io::stdout().is_terminal())
+    }
+
+    /// `text` with each character in its style, as plain text unless `tty`
+    pub fn paint_for(&self, text: &str, tty: bool) -> String {
+        if !tty {
+            return text.to_owned();
+        }
+
+        let mut output = String::new();
+        let mut style = Style::PLAIN;
+        for c in text.chars() {
+            let c_style = self.style(c);
+            if c_style != style {
+                output.push_str(&c_style.sgr());
+                style = c_style;
+            }
+            output.push(c);
+        }
+        if style != Style::PLAIN {
+            output.push_str(&Style::PLAIN.sgr());
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sgr() {
+        assert_eq!(Style::PLAIN.sgr(), "\x1B[0m");
+        assert_eq!(Style::fg(Colour::Red).sgr(), "\x1B[0;31m");
+        assert_eq!(Style::fg(Colour::Magenta).bold().sgr(), "\x1B[0;1;35m");
+    }
+
+    #[test]
+    fn test_paint() {
+        let palette = Palette::new().with('E', Style::fg(Colour::Green));
+        assert_eq!(palette.paint_for("..E.EE", true), "..\x1B[0;32mE\x1B[0m.\x1B[0;32mEE\x1B[0m");
+        assert_eq!(palette.paint_for("...", true), "...");
+        assert_eq!(palette.paint_for("..E.EE", false), "..E.EE");
+    }
+}